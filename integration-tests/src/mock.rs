@@ -12,7 +12,7 @@ use frame_support::{
     parameter_types,
     traits::Everything,
 };
-use frame_support::traits::ConstU32;
+use frame_support::traits::{ConstU32, ConstU64};
 use frame_system as system;
 
 use pallet_permissions::{
@@ -120,6 +120,12 @@ impl pallet_posts::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
     type MaxCommentDepth = MaxCommentDepth;
     type IsPostBlocked = MockModeration;
+    type BeforePostDeleted = Reactions;
+    type MaxPostContentHistory = ConstU32<10>;
+    type MaxPinnedPostsPerSpace = ConstU32<10>;
+    type MaxScheduledPostsPerBlock = ConstU32<100>;
+    type MaxPollOptions = ConstU32<10>;
+    type PostByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -132,6 +138,8 @@ impl pallet_profiles::Config for TestRuntime {
 
 impl pallet_reactions::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
+    type ReactionByteDeposit = ConstU64<0>;
+    type MaxCustomReactionKinds = ConstU32<10>;
     type WeightInfo = ();
 }
 
@@ -146,6 +154,9 @@ impl pallet_roles::Config for TestRuntime {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = MockModeration;
     type IsContentBlocked = MockModeration;
+    type MaxInlineContentLen = ConstU32<512>;
+    type Currency = Balances;
+    type RoleByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -156,7 +167,6 @@ impl pallet_space_follows::Config for TestRuntime {
 
 impl pallet_space_ownership::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
-    type ProfileManager = Profiles;
     type WeightInfo = ();
 }
 
@@ -166,7 +176,17 @@ impl pallet_spaces::Config for TestRuntime {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = MockModeration;
     type IsContentBlocked = MockModeration;
+    type IsSpaceBlocked = MockModeration;
+    type MaxInlineContentLen = ConstU32<512>;
+    type ProfileManager = Profiles;
+    type BeforeSpaceDeleted = (Posts, SpaceFollows, SpaceOwnership, Reactions, Roles);
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<5>;
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type MaxSpaceContentHistory = ConstU32<10>;
+    type Currency = Balances;
+    type SpaceByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -16,7 +16,7 @@ fn create_comment_should_work() {
         assert_ok!(_create_default_comment()); // PostId 2 by ACCOUNT1 which is permitted by default
 
         // Check storages
-        assert_eq!(Posts::reply_ids_by_post_id(POST1), vec![POST2]);
+        assert_eq!(reply_ids_by_post_id(POST1), vec![POST2]);
 
         // Check whether data stored correctly
         let comment = Posts::post_by_id(POST2).unwrap();
@@ -51,12 +51,12 @@ fn create_comment_should_work_when_comment_has_parents() {
         // except the last parent.
         for comment_id in first_comment_id..parent_id_by_one {
             // All of comments has 1 reply as they respond to each other.
-            assert_eq!(Posts::reply_ids_by_post_id(comment_id), vec![comment_id + 1]);
+            assert_eq!(reply_ids_by_post_id(comment_id), vec![comment_id + 1]);
         }
 
-        assert_eq!(Posts::reply_ids_by_post_id(parent_id), vec![last_comment_id]);
+        assert_eq!(reply_ids_by_post_id(parent_id), vec![last_comment_id]);
 
-        assert!(Posts::reply_ids_by_post_id(last_comment_id).is_empty());
+        assert!(reply_ids_by_post_id(last_comment_id).is_empty());
     });
 }
 
@@ -184,11 +184,11 @@ fn update_comment_hidden_should_work_when_comment_has_parents() {
         // except the last before hidden.
         for comment_id in first_comment_id..should_hide_by_one_id {
             // All of comments has 1 replies as they reply to each other.
-            assert_eq!(Posts::reply_ids_by_post_id(comment_id), vec![comment_id + 1]);
+            assert_eq!(reply_ids_by_post_id(comment_id), vec![comment_id + 1]);
         }
 
-        assert_eq!(Posts::reply_ids_by_post_id(should_hide_by_one_id), vec![should_hide_by_one_id + 1]);
-        assert_eq!(Posts::reply_ids_by_post_id(should_hide_id), vec![should_hide_id + 1]);
+        assert_eq!(reply_ids_by_post_id(should_hide_by_one_id), vec![should_hide_by_one_id + 1]);
+        assert_eq!(reply_ids_by_post_id(should_hide_id), vec![should_hide_id + 1]);
     });
 }
 
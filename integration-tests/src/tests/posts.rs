@@ -102,7 +102,7 @@ fn create_post_should_work() {
         assert_ok!(_create_default_post()); // PostId 1 by ACCOUNT1 which is permitted by default
 
         // Check storages
-        assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1]);
+        assert_eq!(post_ids_by_space_id(SPACE1), vec![POST1]);
         assert_eq!(Posts::next_post_id(), POST2);
 
         // Check whether data stored correctly
@@ -242,11 +242,11 @@ fn move_post_should_work() {
         check_if_post_moved_correctly(moved_post_id, expected_new_space_id);
 
         // Check that there are no posts ids in the old space
-        assert!(Posts::post_ids_by_space_id(old_space_id).is_empty());
+        assert!(post_ids_by_space_id(old_space_id).is_empty());
 
         // Check that there is the post id in the new space
         assert_eq!(
-            Posts::post_ids_by_space_id(expected_new_space_id),
+            post_ids_by_space_id(expected_new_space_id),
             vec![moved_post_id]
         );
     });
@@ -265,11 +265,11 @@ fn move_post_should_work_when_space_id_none() {
         check_if_post_moved_correctly(moved_post_id, expected_new_space_id);
 
         // Check that there are no posts ids in the old space
-        assert!(Posts::post_ids_by_space_id(old_space_id).is_empty());
+        assert!(post_ids_by_space_id(old_space_id).is_empty());
 
         // Check that there is the post id in the new space
         assert_eq!(
-            Posts::post_ids_by_space_id(expected_new_space_id),
+            post_ids_by_space_id(expected_new_space_id),
             vec![moved_post_id]
         );
     });
@@ -294,11 +294,11 @@ fn move_hidden_post_should_work() {
         check_if_post_moved_correctly(moved_post_id, expected_new_space_id);
 
         // Check that there are no posts ids in the old space
-        assert!(Posts::post_ids_by_space_id(old_space_id).is_empty());
+        assert!(post_ids_by_space_id(old_space_id).is_empty());
 
         // Check that there is the post id in the new space
         assert_eq!(
-            Posts::post_ids_by_space_id(expected_new_space_id),
+            post_ids_by_space_id(expected_new_space_id),
             vec![moved_post_id]
         );
     });
@@ -417,7 +417,7 @@ fn update_any_post_should_work_when_account_has_default_permission() {
 fn update_any_post_should_work_when_one_of_roles_is_permitted() {
     ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateAnyPost]).execute_with(
         || {
-            let post_update = post_update(None, Some(updated_post_content()), Some(true));
+            let post_update = post_update(None, Some(updated_post_content()), None);
             assert_ok!(_create_default_post()); // PostId 1
 
             // Post update with ID 1 should be fine
@@ -9,6 +9,7 @@ use crate::utils::*;
 use crate::utils::posts_utils::*;
 use crate::utils::spaces_utils::*;
 use crate::utils::reactions_utils::*;
+use crate::utils::moderation_utils::*;
 
 #[test]
 fn create_post_reaction_should_work_upvote() {
@@ -115,3 +116,25 @@ fn create_post_reaction_should_fail_when_trying_to_react_on_hidden_post() {
         );
     });
 }
+
+#[test]
+fn tip_post_should_fail_when_account_is_blocked() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        block_account_2_in_space_1();
+        assert_noop!(
+            _tip_default_post(10),
+            ModerationError::AccountIsBlocked,
+        );
+    });
+}
+
+#[test]
+fn create_space_reaction_should_fail_when_account_is_blocked() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        block_account_2_in_space_1();
+        assert_noop!(
+            _create_default_space_reaction(),
+            ModerationError::AccountIsBlocked,
+        );
+    });
+}
@@ -26,12 +26,12 @@ fn share_post_should_work() {
         )); // Share PostId 1 on SpaceId 2 by ACCOUNT2 which is permitted by default in both spaces
 
         // Check storages
-        assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1]);
-        assert_eq!(Posts::post_ids_by_space_id(SPACE2), vec![POST2]);
+        assert_eq!(post_ids_by_space_id(SPACE1), vec![POST1]);
+        assert_eq!(post_ids_by_space_id(SPACE2), vec![POST2]);
         assert_eq!(Posts::next_post_id(), POST3);
 
         assert_eq!(
-            Posts::shared_post_ids_by_original_post_id(POST1),
+            shared_post_ids_by_original_post_id(POST1),
             vec![POST2]
         );
 
@@ -81,11 +81,11 @@ fn share_post_should_work_for_share_own_post_in_same_own_space() {
         )); // Share PostId 1
 
         // Check storages
-        assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1, POST2]);
+        assert_eq!(post_ids_by_space_id(SPACE1), vec![POST1, POST2]);
         assert_eq!(Posts::next_post_id(), POST3);
 
         assert_eq!(
-            Posts::shared_post_ids_by_original_post_id(POST1),
+            shared_post_ids_by_original_post_id(POST1),
             vec![POST2]
         );
 
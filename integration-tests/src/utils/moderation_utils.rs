@@ -9,7 +9,7 @@ use subsocial_support::{
 };
 
 use crate::mock::*;
-use crate::utils::{ACCOUNT1, SPACE1};
+use crate::utils::{ACCOUNT1, ACCOUNT2, SPACE1};
 
 // Moderation pallet mocks
 
@@ -31,6 +31,8 @@ impl Hash for EntityId {
                 Content::None => 0.hash(state),
                 Content::Other(content) => content.hash(state),
                 Content::IPFS(content) => content.hash(state),
+                Content::Arweave(content) => content.hash(state),
+                Content::Inline(content) => content.hash(state),
             },
             EntityId::Account(account) => account.hash(state),
             EntityId::Space(space) => space.hash(state),
@@ -124,6 +126,14 @@ pub(crate) fn block_account_in_space_1() {
     );
 }
 
+pub(crate) fn block_account_2_in_space_1() {
+    MockModeration::set_entity_status(
+        EntityId::Account(ACCOUNT2),
+        SPACE1,
+        EntityStatus::Blocked,
+    );
+}
+
 pub(crate) fn block_content_in_space_1() {
     MockModeration::set_entity_status(
         EntityId::Content(valid_content_ipfs()),
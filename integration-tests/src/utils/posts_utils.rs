@@ -14,6 +14,28 @@ pub(crate) fn updated_post_content() -> Content {
     Content::IPFS(b"bafyreifw4omlqpr3nqm32bueugbodkrdne7owlkxgg7ul2qkvgrnkt3g3u".to_vec())
 }
 
+/// All root post ids in a given space, sorted, since paginated getters don't keep the order.
+pub(crate) fn post_ids_by_space_id(space_id: SpaceId) -> Vec<PostId> {
+    let mut post_ids = Posts::get_post_ids_by_space_id(space_id, None, u32::MAX);
+    post_ids.sort_unstable();
+    post_ids
+}
+
+/// All direct reply ids of a given post, sorted.
+pub(crate) fn reply_ids_by_post_id(post_id: PostId) -> Vec<PostId> {
+    let mut reply_ids = Posts::get_reply_ids_by_post_id(post_id, None, u32::MAX);
+    reply_ids.sort_unstable();
+    reply_ids
+}
+
+/// All ids of posts that shared a given post, sorted.
+pub(crate) fn shared_post_ids_by_original_post_id(post_id: PostId) -> Vec<PostId> {
+    let mut shared_post_ids =
+        Posts::get_shared_post_ids_by_original_post_id(post_id, None, u32::MAX);
+    shared_post_ids.sort_unstable();
+    shared_post_ids
+}
+
 pub(crate) fn post_update(
     space_id: Option<SpaceId>,
     content: Option<Content>,
@@ -23,6 +45,8 @@ pub(crate) fn post_update(
         space_id,
         content,
         hidden,
+        permissions: None,
+        subscribers_only: None,
     }
 }
 
@@ -61,6 +85,7 @@ pub(crate) fn _create_post(
         space_id_opt.unwrap_or(Some(SPACE1)),
         extension.unwrap_or_else(extension_regular_post),
         content.unwrap_or_else(post_content_ipfs),
+        None,
     )
 }
 
@@ -1,10 +1,10 @@
 use frame_support::pallet_prelude::*;
 
 use pallet_reactions::{ReactionId, ReactionKind};
-use subsocial_support::PostId;
+use subsocial_support::{PostId, SpaceId};
 
 use crate::mock::*;
-use crate::utils::{ACCOUNT1, POST1};
+use crate::utils::{ACCOUNT1, ACCOUNT2, POST1, SPACE1};
 
 pub(crate) fn reaction_upvote() -> ReactionKind {
     ReactionKind::Upvote
@@ -85,3 +85,35 @@ pub(crate) fn _delete_comment_reaction(
 ) -> DispatchResult {
     _delete_post_reaction(origin, Some(post_id.unwrap_or(2)), reaction_id)
 }
+
+pub(crate) fn _tip_default_post(amount: u64) -> DispatchResult {
+    _tip_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None, amount)
+}
+
+pub(crate) fn _tip_post(
+    origin: Option<RuntimeOrigin>,
+    post_id: Option<PostId>,
+    amount: u64,
+) -> DispatchResult {
+    Reactions::tip_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        post_id.unwrap_or(POST1),
+        amount,
+    )
+}
+
+pub(crate) fn _create_default_space_reaction() -> DispatchResult {
+    _create_space_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)
+}
+
+pub(crate) fn _create_space_reaction(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    kind: Option<ReactionKind>,
+) -> DispatchResult {
+    Reactions::create_space_reaction(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        kind.unwrap_or_else(reaction_upvote),
+    )
+}
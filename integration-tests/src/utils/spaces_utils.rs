@@ -12,7 +12,7 @@ pub(crate) fn space_content_ipfs() -> Content {
 }
 
 pub(crate) fn another_space_content_ipfs() -> Content {
-    Content::IPFS(b"bafyrelt3cif35x4ribisxgq7unhpun525l54eib3mgbou4xln42qqcgj6q".to_vec())
+    Content::IPFS(b"bafyreia2dve77krat5lsuqdep6xi6ing5hp5xfd6euqehvbvszg27v6he4".to_vec())
 }
 
 pub(crate) fn updated_space_content() -> Content {
@@ -31,6 +31,7 @@ pub(crate) fn space_update(
 ) -> SpaceUpdate {
     SpaceUpdate {
         content,
+        handle: None,
         hidden,
         permissions: None,
     }
@@ -48,7 +49,8 @@ pub(crate) fn _create_space(
     Spaces::create_space(
         origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
         content.unwrap_or_else(space_content_ipfs),
-        permissions.unwrap_or_default()
+        permissions.unwrap_or_default(),
+        None,
     )
 }
 
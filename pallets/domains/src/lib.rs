@@ -80,6 +80,10 @@ pub mod pallet {
         #[pallet::constant]
         type OuterValueByteDeposit: Get<BalanceOf<Self>>;
 
+        /// Max length of an inline content, in bytes.
+        #[pallet::constant]
+        type MaxInlineContentLen: Get<u32>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -307,7 +311,7 @@ pub mod pallet {
             Self::ensure_allowed_to_update_domain(&meta, &sender)?;
 
             ensure!(meta.content != new_content, Error::<T>::DomainContentNotChanged);
-            ensure_content_is_valid(new_content.clone())?;
+            ensure_content_is_valid::<T::MaxInlineContentLen>(new_content.clone())?;
 
             meta.content = new_content;
             RegisteredDomains::<T>::insert(&domain_lc, meta);
@@ -374,7 +378,7 @@ pub mod pallet {
                 expires_in <= T::RegistrationPeriodLimit::get(),
                 Error::<T>::TooBigRegistrationPeriod,
             );
-            ensure_content_is_valid(content.clone())?;
+            ensure_content_is_valid::<T::MaxInlineContentLen>(content.clone())?;
 
             // Note that while upper and lower case letters are allowed in domain
             // names, domain names are not case-sensitive. That is, two names with
@@ -121,6 +121,7 @@ impl pallet_domains::Config for Test {
     type MaxOuterValueLength = MaxOuterValueLength;
     type BaseDomainDeposit = BaseDomainDeposit;
     type OuterValueByteDeposit = OuterValueByteDeposit;
+    type MaxInlineContentLen = frame_support::traits::ConstU32<512>;
     type WeightInfo = ();
 }
 
@@ -0,0 +1,163 @@
+//! Moderation pallet benchmarking.
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{assert_ok, ensure, sp_runtime::traits::Bounded, traits::Currency};
+use frame_system::RawOrigin;
+
+use pallet_spaces::{types::BalanceOf, NextSpaceId, Pallet as Spaces, SpaceById};
+use subsocial_support::mock_functions::valid_content_ipfs;
+
+use super::*;
+
+fn create_dummy_space<T: Config>(owner: T::AccountId) -> SpaceId {
+    <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+        &owner,
+        BalanceOf::<T>::max_value(),
+    );
+    let space_id = NextSpaceId::<T>::get();
+    assert_ok!(Spaces::<T>::create_space(
+        RawOrigin::Signed(owner).into(),
+        Content::None,
+        None,
+        None
+    ));
+    space_id
+}
+
+fn create_dummy_report<T: Config>(
+    reporter: T::AccountId,
+    entity: EntityIdOf<T>,
+    space_id: SpaceId,
+) -> ReportId {
+    <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+        &reporter,
+        BalanceOf::<T>::max_value(),
+    );
+    let report_id = NextReportId::<T>::get();
+    assert_ok!(Pallet::<T>::report_entity(
+        RawOrigin::Signed(reporter).into(),
+        entity,
+        space_id,
+        valid_content_ipfs(),
+    ));
+    report_id
+}
+
+benchmarks! {
+    update_entity_status {
+        let moderator = whitelisted_caller::<T::AccountId>();
+        let space_id = create_dummy_space::<T>(moderator.clone());
+
+        let entity = EntityId::Account(account::<T::AccountId>("blocked", 1, 0));
+        let status_opt = Some(EntityStatus::Blocked);
+
+        let reporter = account::<T::AccountId>("reporter", 2, 0);
+        let report_id = create_dummy_report::<T>(reporter, entity.clone(), space_id);
+    }: _(RawOrigin::Signed(moderator), entity.clone(), space_id, status_opt)
+    verify {
+        ensure!(
+            StatusByEntityInSpace::<T>::get(entity, space_id) == Some(EntityStatus::Blocked),
+            "Entity should be blocked in space"
+        );
+        let report = ReportById::<T>::get(report_id).unwrap();
+        ensure!(
+            Pallet::<T>::report_status(&report) == ReportStatus::Resolved,
+            "Report should be resolved"
+        );
+    }
+
+    // The worst case is when a resolved report of the same account is removed first.
+    report_entity {
+        let owner = account::<T::AccountId>("owner", 2, 0);
+        let space_id = create_dummy_space::<T>(owner.clone());
+
+        let reporter = whitelisted_caller::<T::AccountId>();
+        let entity = EntityId::Account(account::<T::AccountId>("reported", 1, 0));
+        let reason = valid_content_ipfs();
+
+        let old_report_id = create_dummy_report::<T>(reporter.clone(), entity.clone(), space_id);
+        assert_ok!(Pallet::<T>::update_entity_status(
+            RawOrigin::Signed(owner).into(),
+            entity.clone(),
+            space_id,
+            Some(EntityStatus::Blocked),
+        ));
+
+        let report_id = NextReportId::<T>::get();
+    }: _(RawOrigin::Signed(reporter), entity, space_id, reason)
+    verify {
+        ensure!(ReportById::<T>::get(report_id).is_some(), "Report should be created");
+        ensure!(ReportById::<T>::get(old_report_id).is_none(), "Old report should be removed");
+    }
+
+    suggest_entity_status {
+        let moderator = whitelisted_caller::<T::AccountId>();
+        let space_id = create_dummy_space::<T>(moderator.clone());
+
+        let entity = EntityId::Account(account::<T::AccountId>("reported", 1, 0));
+        let reporter = account::<T::AccountId>("reporter", 2, 0);
+        let report_id = create_dummy_report::<T>(reporter, entity.clone(), space_id);
+    }: _(RawOrigin::Signed(moderator), entity.clone(), space_id, Some(EntityStatus::Blocked), Some(report_id))
+    verify {
+        ensure!(
+            SuggestedStatusesByEntityInSpace::<T>::get(entity, space_id).len() == 1,
+            "Entity status should be suggested"
+        );
+        ensure!(
+            ReportById::<T>::get(report_id).unwrap().status == ReportStatus::UnderReview,
+            "Report should be under review"
+        );
+    }
+
+    dismiss_report {
+        let moderator = whitelisted_caller::<T::AccountId>();
+        let space_id = create_dummy_space::<T>(moderator.clone());
+
+        let entity = EntityId::Account(account::<T::AccountId>("reported", 1, 0));
+        let reporter = account::<T::AccountId>("reporter", 2, 0);
+        let report_id = create_dummy_report::<T>(reporter, entity, space_id);
+    }: _(RawOrigin::Signed(moderator), report_id)
+    verify {
+        ensure!(ReportById::<T>::get(report_id).is_none(), "Report should be removed");
+    }
+
+    remove_space_moderation_data {
+        let n in 0 .. 100;
+
+        let caller = whitelisted_caller::<T::AccountId>();
+        let space_id = create_dummy_space::<T>(caller.clone());
+
+        for i in 0..n {
+            let reporter = account::<T::AccountId>("reporter", i, 0);
+            let entity = EntityId::Account(account::<T::AccountId>("reported", i, 0));
+            create_dummy_report::<T>(reporter, entity, space_id);
+        }
+        SpaceById::<T>::remove(space_id);
+    }: _(RawOrigin::Signed(caller), space_id, n)
+    verify {
+        ensure!(
+            ModeratedEntitiesBySpaceId::<T>::iter_key_prefix(space_id).next().is_none(),
+            "Moderated entities should be removed"
+        );
+    }
+
+    remove_resolved_report {
+        let moderator = account::<T::AccountId>("moderator", 1, 0);
+        let space_id = create_dummy_space::<T>(moderator.clone());
+
+        let entity = EntityId::Account(account::<T::AccountId>("reported", 1, 0));
+        let reporter = account::<T::AccountId>("reporter", 2, 0);
+        let report_id = create_dummy_report::<T>(reporter, entity.clone(), space_id);
+        assert_ok!(Pallet::<T>::update_entity_status(
+            RawOrigin::Signed(moderator).into(),
+            entity,
+            space_id,
+            Some(EntityStatus::Blocked),
+        ));
+
+        let caller = whitelisted_caller::<T::AccountId>();
+    }: _(RawOrigin::Signed(caller), report_id)
+    verify {
+        ensure!(ReportById::<T>::get(report_id).is_none(), "Report should be removed");
+    }
+}
@@ -0,0 +1,261 @@
+use codec::Encode;
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get};
+use sp_runtime::{traits::Saturating, DispatchError};
+
+use pallet_posts::Pallet as Posts;
+use pallet_spaces::{
+    types::{BalanceOf, Space},
+    Pallet as Spaces,
+};
+use sp_std::vec::Vec;
+use subsocial_support::{
+    ensure_content_is_some, ensure_content_is_valid, new_who_and_when, refund_storage_deposit,
+    traits::{IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked},
+    update_storage_deposit,
+};
+
+use super::*;
+
+impl<T: Config> Report<T> {
+    pub fn new(
+        id: ReportId,
+        reporter: T::AccountId,
+        entity: EntityIdOf<T>,
+        space_id: SpaceId,
+        reason: Content,
+    ) -> Self {
+        Self {
+            id,
+            created: new_who_and_when::<T>(reporter),
+            entity,
+            space_id,
+            reason,
+            status: ReportStatus::Pending,
+        }
+    }
+}
+
+impl<T: Config> SuggestedStatus<T> {
+    pub fn new(
+        who: T::AccountId,
+        status: Option<EntityStatus>,
+        report_id: Option<ReportId>,
+    ) -> Self {
+        Self { suggested: new_who_and_when::<T>(who), status, report_id }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn is_entity_blocked(entity: EntityIdOf<T>, space_id: SpaceId) -> bool {
+        Self::status_by_entity_in_space(entity, space_id) == Some(EntityStatus::Blocked)
+    }
+
+    pub fn is_entity_allowed(entity: EntityIdOf<T>, space_id: SpaceId) -> bool {
+        !Self::is_entity_blocked(entity, space_id)
+    }
+
+    /// Check that a given entity exists or, in case of content, that it's valid.
+    pub fn ensure_entity_exists(entity: &EntityIdOf<T>) -> DispatchResult {
+        match entity {
+            EntityId::Content(content) => {
+                ensure_content_is_some(content)?;
+                ensure_content_is_valid::<T::MaxInlineContentLen>(content.clone())
+            },
+            EntityId::Account(_) => Ok(()),
+            EntityId::Space(space_id) => Spaces::<T>::ensure_space_exists(*space_id),
+            EntityId::Post(post_id) => Posts::<T>::ensure_post_exists(*post_id),
+        }
+    }
+
+    /// Get `Report` by id from the storage or return `ReportNotFound` error.
+    pub fn require_report(report_id: ReportId) -> Result<Report<T>, DispatchError> {
+        Ok(Self::report_by_id(report_id).ok_or(Error::<T>::ReportNotFound)?)
+    }
+
+    /// Get the current stage of a report in the moderation workflow.
+    /// A report is resolved, if the status of its entity was updated after it was filed.
+    pub fn report_status(report: &Report<T>) -> ReportStatus {
+        if report.id < Self::reports_resolved_before(&report.entity, report.space_id) {
+            ReportStatus::Resolved
+        } else {
+            report.status
+        }
+    }
+
+    /// Whether a report is pending or under review.
+    pub fn is_report_open(report: &Report<T>) -> bool {
+        matches!(Self::report_status(report), ReportStatus::Pending | ReportStatus::UnderReview)
+    }
+
+    /// Check that a reported post belongs to the space, within which it's reported.
+    pub(crate) fn ensure_entity_in_scope(
+        entity: &EntityIdOf<T>,
+        space_id: SpaceId,
+    ) -> DispatchResult {
+        if let EntityId::Post(post_id) = entity {
+            let post = Posts::<T>::require_post(*post_id)?;
+            ensure!(post.try_get_space_id() == Some(space_id), Error::<T>::EntityNotInScope);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn set_report_status(report: &mut Report<T>, status: ReportStatus) {
+        report.status = status;
+        ReportById::<T>::insert(report.id, report.clone());
+
+        Self::deposit_event(Event::ReportStatusUpdated { report_id: report.id, status });
+    }
+
+    pub(crate) fn add_report_to_space(space_id: SpaceId, report_id: ReportId) {
+        ReportIdsBySpaceId::<T>::insert(space_id, report_id, ());
+        ReportCountBySpaceId::<T>::mutate(space_id, |count| *count = count.saturating_add(1));
+    }
+
+    /// Get up to `limit` ids of reports filed within a given space, starting after `start_after`.
+    /// Ids are not returned in the order they were added.
+    pub fn get_report_ids_by_space_id(
+        space_id: SpaceId,
+        start_after: Option<ReportId>,
+        limit: u32,
+    ) -> Vec<ReportId> {
+        let iter = match start_after {
+            Some(report_id) => ReportIdsBySpaceId::<T>::iter_key_prefix_from(
+                space_id,
+                ReportIdsBySpaceId::<T>::hashed_key_for(space_id, report_id),
+            ),
+            None => ReportIdsBySpaceId::<T>::iter_key_prefix(space_id),
+        };
+        iter.take(limit as usize).collect()
+    }
+
+    /// Reserve a deposit for storing a new report from its reporter.
+    pub(crate) fn reserve_report_deposit(report: &Report<T>) -> DispatchResult {
+        let amount = T::ReportByteDeposit::get()
+            .saturating_mul(BalanceOf::<T>::from(report.encoded_size() as u32));
+
+        let deposit = update_storage_deposit::<_, <T as pallet_spaces::Config>::Currency>(
+            None,
+            report.created.account.clone(),
+            amount,
+        )?;
+        DepositByReportId::<T>::insert(report.id, deposit);
+        Ok(())
+    }
+
+    /// Remove a report from the storage, letting its reporter report the same entity again,
+    /// and return its deposit to the reporter.
+    pub(crate) fn remove_report(report: &Report<T>) {
+        let reporter = &report.created.account;
+        let report_key = (report.entity.clone(), report.space_id, reporter.clone());
+        if Self::report_id_by_account(&report_key) == Some(report.id) {
+            ReportIdByAccount::<T>::remove(report_key);
+        }
+
+        if ReportIdsBySpaceId::<T>::take(report.space_id, report.id).is_some() {
+            ReportCountBySpaceId::<T>::mutate(report.space_id, |count| {
+                *count = count.saturating_sub(1)
+            });
+        }
+        ReportCountByAccount::<T>::mutate(reporter, |count| *count = count.saturating_sub(1));
+        ReportById::<T>::remove(report.id);
+
+        if let Some(deposit) = DepositByReportId::<T>::take(report.id) {
+            refund_storage_deposit::<_, <T as pallet_spaces::Config>::Currency>(deposit);
+        }
+    }
+
+    /// Remove up to `limit` moderated entities and reports of a deleted space.
+    /// Returns the number of removed items and whether nothing is left to remove.
+    pub(crate) fn do_remove_space_moderation_data(space_id: SpaceId, limit: u32) -> (u32, bool) {
+        let mut removed_count = 0;
+
+        let entities: Vec<_> = ModeratedEntitiesBySpaceId::<T>::iter_key_prefix(space_id)
+            .take(limit as usize)
+            .collect();
+        for entity in entities {
+            StatusByEntityInSpace::<T>::remove(&entity, space_id);
+            SuggestedStatusesByEntityInSpace::<T>::remove(&entity, space_id);
+            ReportsResolvedBefore::<T>::remove(&entity, space_id);
+            ModeratedEntitiesBySpaceId::<T>::remove(space_id, &entity);
+            removed_count += 1;
+        }
+
+        let report_ids: Vec<_> = ReportIdsBySpaceId::<T>::iter_key_prefix(space_id)
+            .take(limit.saturating_sub(removed_count) as usize)
+            .collect();
+        for report_id in report_ids {
+            match Self::report_by_id(report_id) {
+                Some(report) => Self::remove_report(&report),
+                None => ReportIdsBySpaceId::<T>::remove(space_id, report_id),
+            }
+            removed_count += 1;
+        }
+
+        if ReportIdsBySpaceId::<T>::iter_key_prefix(space_id).next().is_none() {
+            ReportCountBySpaceId::<T>::remove(space_id);
+        }
+
+        let completed = ModeratedEntitiesBySpaceId::<T>::iter_key_prefix(space_id).next().is_none() &&
+            ReportIdsBySpaceId::<T>::iter_key_prefix(space_id).next().is_none();
+        (removed_count, completed)
+    }
+
+    /// Check that blocking a given entity won't lock the space out of its own owner or content.
+    pub(crate) fn ensure_entity_can_be_blocked(
+        entity: &EntityIdOf<T>,
+        space: &Space<T>,
+    ) -> DispatchResult {
+        match entity {
+            EntityId::Account(account) => {
+                ensure!(!space.is_owner(account), Error::<T>::CannotBlockSpaceOwner);
+            },
+            EntityId::Space(space_id) => {
+                ensure!(*space_id != space.id, Error::<T>::CannotBlockSpaceWithinItself);
+            },
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Config> IsAccountBlocked<T::AccountId> for Pallet<T> {
+    fn is_blocked_account(account: T::AccountId, scope: SpaceId) -> bool {
+        Self::is_entity_blocked(EntityId::Account(account), scope)
+    }
+
+    fn is_allowed_account(account: T::AccountId, scope: SpaceId) -> bool {
+        Self::is_entity_allowed(EntityId::Account(account), scope)
+    }
+}
+
+impl<T: Config> IsSpaceBlocked for Pallet<T> {
+    fn is_blocked_space(space_id: SpaceId, scope: SpaceId) -> bool {
+        Self::is_entity_blocked(EntityId::Space(space_id), scope)
+    }
+
+    fn is_allowed_space(space_id: SpaceId, scope: SpaceId) -> bool {
+        Self::is_entity_allowed(EntityId::Space(space_id), scope)
+    }
+}
+
+impl<T: Config> IsPostBlocked<PostId> for Pallet<T> {
+    fn is_blocked_post(post_id: PostId, scope: SpaceId) -> bool {
+        Self::is_entity_blocked(EntityId::Post(post_id), scope)
+    }
+
+    fn is_allowed_post(post_id: PostId, scope: SpaceId) -> bool {
+        Self::is_entity_allowed(EntityId::Post(post_id), scope)
+    }
+}
+
+impl<T: Config> IsContentBlocked for Pallet<T> {
+    fn is_blocked_content(content: Content, scope: SpaceId) -> bool {
+        Self::is_entity_blocked(EntityId::Content(content), scope)
+    }
+
+    fn is_allowed_content(content: Content, scope: SpaceId) -> bool {
+        Self::is_entity_allowed(EntityId::Content(content), scope)
+    }
+}
@@ -0,0 +1,538 @@
+//! # Moderation Module
+//!
+//! This module allows space moderators to block or allow different entities (accounts, spaces,
+//! posts and content) within the scope of a given space.
+//!
+//! Other pallets consult the status of an entity through the `IsAccountBlocked`,
+//! `IsSpaceBlocked`, `IsPostBlocked` and `IsContentBlocked` traits, implemented by this module.
+//! For example, an account blocked within a space can no longer create posts or comments there.
+//!
+//! Any account can report an entity within a space, explaining the reason with a content.
+//! Accounts with `SuggestEntityStatus` permission can suggest a status for the reported entity,
+//! and accounts with `UpdateEntityStatus` permission make the final decision: they either update
+//! the entity status, which resolves all open reports on it, or dismiss a report.
+//!
+//! A deposit is reserved from the reporter for every report. It's returned when the report is
+//! dismissed, or when a resolved report is removed by anyone with `remove_resolved_report`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use subsocial_support::{Content, PostId, SpaceId, WhoAndWhenOf};
+
+pub use pallet::*;
+pub mod functions;
+
+pub mod types;
+pub use types::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    use pallet_spaces::types::{BalanceOf, StorageDepositOf};
+
+    use pallet_permissions::SpacePermission;
+    use pallet_spaces::Pallet as Spaces;
+    use subsocial_support::{
+        ensure_content_is_some, ensure_content_is_valid, traits::IsAccountBlocked,
+        ModerationError,
+    };
+
+    use crate::weights::WeightInfo;
+
+    #[pallet::config]
+    pub trait Config:
+        frame_system::Config
+        + pallet_posts::Config
+        + pallet_spaces::Config
+        + pallet_timestamp::Config
+    {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Max number of reports filed by an account, that are not removed yet.
+        #[pallet::constant]
+        type MaxReportsPerAccount: Get<u32>;
+
+        /// Max number of statuses suggested for an entity within a space,
+        /// since the last time its status was updated.
+        #[pallet::constant]
+        type MaxSuggestedStatusesPerEntity: Get<u32>;
+
+        /// The deposit reserved from a reporter per byte of a stored report.
+        #[pallet::constant]
+        type ReportByteDeposit: Get<BalanceOf<Self>>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    #[pallet::type_value]
+    pub fn DefaultForNextReportId() -> ReportId {
+        FIRST_REPORT_ID
+    }
+
+    /// The next report id.
+    #[pallet::storage]
+    #[pallet::getter(fn next_report_id)]
+    pub type NextReportId<T: Config> =
+        StorageValue<_, ReportId, ValueQuery, DefaultForNextReportId>;
+
+    /// Get the details of a report by its' id. Dismissed reports are removed right away,
+    /// while resolved reports are kept until they're removed with `remove_resolved_report`.
+    #[pallet::storage]
+    #[pallet::getter(fn report_by_id)]
+    pub type ReportById<T: Config> = StorageMap<_, Twox64Concat, ReportId, Report<T>>;
+
+    /// The deposit held for storing a report, by the report id.
+    #[pallet::storage]
+    #[pallet::getter(fn deposit_by_report_id)]
+    pub type DepositByReportId<T: Config> =
+        StorageMap<_, Twox64Concat, ReportId, StorageDepositOf<T>>;
+
+    /// The number of reports filed by an account, that are not removed yet.
+    #[pallet::storage]
+    #[pallet::getter(fn report_count_by_account)]
+    pub type ReportCountByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Get the id of the last report filed by a given account against an entity within a space,
+    /// unless that report is removed.
+    #[pallet::storage]
+    #[pallet::getter(fn report_id_by_account)]
+    pub type ReportIdByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, (EntityIdOf<T>, SpaceId, T::AccountId), ReportId>;
+
+    /// All reports filed within a space, by the space id and the report id.
+    /// See `get_report_ids_by_space_id` to page through them.
+    #[pallet::storage]
+    pub type ReportIdsBySpaceId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Twox64Concat, ReportId, ()>;
+
+    /// The number of reports filed within a space, by the space id.
+    #[pallet::storage]
+    #[pallet::getter(fn report_count_by_space_id)]
+    pub type ReportCountBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, u32, ValueQuery>;
+
+    /// Reports against an entity within a space with ids lower than this one
+    /// were resolved by the last update of the entity status.
+    #[pallet::storage]
+    #[pallet::getter(fn reports_resolved_before)]
+    pub type ReportsResolvedBefore<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        EntityIdOf<T>,
+        Twox64Concat,
+        SpaceId,
+        ReportId,
+        ValueQuery,
+    >;
+
+    /// Get the statuses suggested by moderators for an entity within a space, since the last
+    /// time its status was updated.
+    #[pallet::storage]
+    #[pallet::getter(fn suggested_statuses)]
+    pub type SuggestedStatusesByEntityInSpace<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        EntityIdOf<T>,
+        Twox64Concat,
+        SpaceId,
+        SuggestedStatuses<T>,
+        ValueQuery,
+    >;
+
+    /// Get the status of an entity (whether it's blocked or allowed) within a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn status_by_entity_in_space)]
+    pub type StatusByEntityInSpace<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        EntityIdOf<T>,
+        Twox64Concat,
+        SpaceId,
+        EntityStatus,
+    >;
+
+    /// Entities that have been reported, suggested a status or got a status within a space,
+    /// by the space id. Used to clean up the moderation data of a deleted space.
+    #[pallet::storage]
+    pub type ModeratedEntitiesBySpaceId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Blake2_128Concat, EntityIdOf<T>, ()>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The status of an entity was set or removed (if `status` is `None`) within a space.
+        EntityStatusUpdated {
+            account: T::AccountId,
+            entity: EntityIdOf<T>,
+            space_id: SpaceId,
+            status: Option<EntityStatus>,
+        },
+        /// An account reported an entity within a space.
+        EntityReported {
+            account: T::AccountId,
+            entity: EntityIdOf<T>,
+            space_id: SpaceId,
+            report_id: ReportId,
+        },
+        /// A moderator suggested a status for an entity within a space.
+        EntityStatusSuggested {
+            account: T::AccountId,
+            entity: EntityIdOf<T>,
+            space_id: SpaceId,
+            status: Option<EntityStatus>,
+            report_id: Option<ReportId>,
+        },
+        /// A report moved to the next stage of the moderation workflow.
+        ReportStatusUpdated { report_id: ReportId, status: ReportStatus },
+        /// A resolved report was removed and its deposit was returned to the reporter.
+        ReportRemoved { report_id: ReportId },
+        /// All moderation data of a deleted space was removed.
+        SpaceModerationDataRemoved { space_id: SpaceId, removed_count: u32 },
+        /// A part of moderation data of a deleted space was removed, and some of it is left.
+        SpaceModerationDataPartiallyRemoved { space_id: SpaceId, removed_count: u32 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account has no permission to update entity statuses in this space.
+        NoPermissionToUpdateEntityStatus,
+        /// New entity status is the same as the old one.
+        EntityStatusNotChanged,
+        /// Cannot block the owner of a space within this space.
+        CannotBlockSpaceOwner,
+        /// Cannot block a space within itself.
+        CannotBlockSpaceWithinItself,
+
+        // Report related errors:
+        /// Report was not found by id.
+        ReportNotFound,
+        /// Account has already reported this entity within this space.
+        AlreadyReportedEntity,
+        /// Reported post doesn't belong to this space.
+        EntityNotInScope,
+        /// The report was filed against another entity or within another space.
+        ReportDoesNotMatchEntity,
+        /// The report is already resolved or dismissed.
+        ReportIsAlreadyClosed,
+        /// Account has no permission to suggest entity statuses in this space.
+        NoPermissionToSuggestEntityStatus,
+        /// Account has already suggested a status for this entity within this space.
+        AlreadySuggestedEntityStatus,
+        /// Account has too many reports, that are not removed yet.
+        TooManyReportsByAccount,
+        /// There are too many statuses suggested for this entity within this space.
+        TooManySuggestedStatuses,
+        /// Only a resolved report can be removed.
+        ReportIsNotResolved,
+        /// Moderation data can be removed only after the space is deleted.
+        SpaceIsNotDeleted,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the status (blocked or allowed) of an entity within a given space.
+        /// If `status_opt` is `None`, then the status of the entity is removed.
+        ///
+        /// All open reports against the entity within the space become resolved,
+        /// and all statuses suggested for it are cleared.
+        ///
+        /// Only a user with `UpdateEntityStatus` permission in the space can call this dispatch.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::update_entity_status())]
+        pub fn update_entity_status(
+            origin: OriginFor<T>,
+            entity: EntityIdOf<T>,
+            space_id: SpaceId,
+            status_opt: Option<EntityStatus>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Spaces::<T>::require_space(space_id)?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Spaces::<T>::ensure_account_has_space_permission(
+                who.clone(),
+                &space,
+                SpacePermission::UpdateEntityStatus,
+                Error::<T>::NoPermissionToUpdateEntityStatus.into(),
+            )?;
+
+            Self::ensure_entity_exists(&entity)?;
+
+            if status_opt == Some(EntityStatus::Blocked) {
+                Self::ensure_entity_can_be_blocked(&entity, &space)?;
+            }
+
+            ensure!(
+                Self::status_by_entity_in_space(&entity, space_id) != status_opt,
+                Error::<T>::EntityStatusNotChanged
+            );
+
+            match status_opt {
+                Some(status) => {
+                    StatusByEntityInSpace::<T>::insert(&entity, space_id, status);
+                    ModeratedEntitiesBySpaceId::<T>::insert(space_id, &entity, ());
+                },
+                None => StatusByEntityInSpace::<T>::remove(&entity, space_id),
+            }
+
+            ReportsResolvedBefore::<T>::insert(&entity, space_id, Self::next_report_id());
+            SuggestedStatusesByEntityInSpace::<T>::remove(&entity, space_id);
+
+            Self::deposit_event(Event::EntityStatusUpdated {
+                account: who,
+                entity,
+                space_id,
+                status: status_opt,
+            });
+            Ok(())
+        }
+
+        /// Report an entity within a given space, explaining the `reason` with a content.
+        /// A post can only be reported within the space it belongs to.
+        ///
+        /// A previous report of the same account against the entity, if it's resolved,
+        /// is removed to make room for the new one.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::report_entity())]
+        pub fn report_entity(
+            origin: OriginFor<T>,
+            entity: EntityIdOf<T>,
+            space_id: SpaceId,
+            reason: Content,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+
+            ensure_content_is_some(&reason)?;
+            ensure_content_is_valid::<T::MaxInlineContentLen>(reason.clone())?;
+
+            Spaces::<T>::ensure_space_exists(space_id)?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(reporter.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Self::ensure_entity_exists(&entity)?;
+            Self::ensure_entity_in_scope(&entity, space_id)?;
+
+            let report_key = (entity.clone(), space_id, reporter.clone());
+            if let Some(old_report) =
+                Self::report_id_by_account(&report_key).and_then(Self::report_by_id)
+            {
+                ensure!(!Self::is_report_open(&old_report), Error::<T>::AlreadyReportedEntity);
+                Self::remove_report(&old_report);
+                Self::deposit_event(Event::ReportRemoved { report_id: old_report.id });
+            }
+
+            ensure!(
+                Self::report_count_by_account(&reporter) < T::MaxReportsPerAccount::get(),
+                Error::<T>::TooManyReportsByAccount
+            );
+
+            let report_id = Self::next_report_id();
+            let new_report =
+                Report::<T>::new(report_id, reporter.clone(), entity.clone(), space_id, reason);
+
+            Self::reserve_report_deposit(&new_report)?;
+            ReportById::<T>::insert(report_id, new_report);
+            ReportIdByAccount::<T>::insert(report_key, report_id);
+            ReportCountByAccount::<T>::mutate(&reporter, |count| *count = count.saturating_add(1));
+            Self::add_report_to_space(space_id, report_id);
+            ModeratedEntitiesBySpaceId::<T>::insert(space_id, &entity, ());
+            NextReportId::<T>::mutate(|n| *n += 1);
+
+            Self::deposit_event(Event::EntityReported {
+                account: reporter,
+                entity,
+                space_id,
+                report_id,
+            });
+            Ok(())
+        }
+
+        /// Suggest a status for an entity within a given space, optionally based on a report.
+        /// A pending report, referenced by `report_id_opt`, moves under review.
+        ///
+        /// Only a user with `SuggestEntityStatus` permission in the space can call this dispatch.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::suggest_entity_status())]
+        pub fn suggest_entity_status(
+            origin: OriginFor<T>,
+            entity: EntityIdOf<T>,
+            space_id: SpaceId,
+            status: Option<EntityStatus>,
+            report_id_opt: Option<ReportId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Spaces::<T>::require_space(space_id)?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Spaces::<T>::ensure_account_has_space_permission(
+                who.clone(),
+                &space,
+                SpacePermission::SuggestEntityStatus,
+                Error::<T>::NoPermissionToSuggestEntityStatus.into(),
+            )?;
+
+            Self::ensure_entity_exists(&entity)?;
+
+            let mut report_opt = None;
+            if let Some(report_id) = report_id_opt {
+                let report = Self::require_report(report_id)?;
+
+                ensure!(
+                    report.entity == entity && report.space_id == space_id,
+                    Error::<T>::ReportDoesNotMatchEntity
+                );
+                ensure!(Self::is_report_open(&report), Error::<T>::ReportIsAlreadyClosed);
+
+                report_opt = Some(report);
+            }
+
+            let mut suggestions = Self::suggested_statuses(&entity, space_id);
+            ensure!(
+                !suggestions.iter().any(|suggestion| suggestion.suggested.account == who),
+                Error::<T>::AlreadySuggestedEntityStatus
+            );
+
+            suggestions
+                .try_push(SuggestedStatus::<T>::new(who.clone(), status, report_id_opt))
+                .map_err(|_| Error::<T>::TooManySuggestedStatuses)?;
+            SuggestedStatusesByEntityInSpace::<T>::insert(&entity, space_id, suggestions);
+            ModeratedEntitiesBySpaceId::<T>::insert(space_id, &entity, ());
+
+            if let Some(mut report) = report_opt {
+                if report.status == ReportStatus::Pending {
+                    Self::set_report_status(&mut report, ReportStatus::UnderReview);
+                }
+            }
+
+            Self::deposit_event(Event::EntityStatusSuggested {
+                account: who,
+                entity,
+                space_id,
+                status,
+                report_id: report_id_opt,
+            });
+            Ok(())
+        }
+
+        /// Dismiss an open report without updating the status of the reported entity.
+        /// The report is removed and its deposit is returned to the reporter.
+        ///
+        /// Only a user with `UpdateEntityStatus` permission in the space, within which
+        /// the entity was reported, can call this dispatch.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::dismiss_report())]
+        pub fn dismiss_report(origin: OriginFor<T>, report_id: ReportId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let report = Self::require_report(report_id)?;
+            ensure!(Self::is_report_open(&report), Error::<T>::ReportIsAlreadyClosed);
+
+            let space = Spaces::<T>::require_space(report.space_id)?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space.id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Spaces::<T>::ensure_account_has_space_permission(
+                who,
+                &space,
+                SpacePermission::UpdateEntityStatus,
+                Error::<T>::NoPermissionToUpdateEntityStatus.into(),
+            )?;
+
+            Self::remove_report(&report);
+            Self::deposit_event(Event::ReportStatusUpdated {
+                report_id,
+                status: ReportStatus::Dismissed,
+            });
+            Ok(())
+        }
+
+        /// Remove the moderation data (statuses, suggestions and reports) of a deleted space.
+        /// Anyone can call this dispatch.
+        ///
+        /// At most `limit` items are removed in one call. If there are more of them,
+        /// call this again with the same `space_id` to continue where the previous call stopped.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_space_moderation_data(*limit))]
+        pub fn remove_space_moderation_data(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            ensure!(
+                Spaces::<T>::ensure_space_exists(space_id).is_err(),
+                Error::<T>::SpaceIsNotDeleted
+            );
+
+            let (removed_count, completed) = Self::do_remove_space_moderation_data(space_id, limit);
+
+            if completed {
+                Self::deposit_event(Event::SpaceModerationDataRemoved { space_id, removed_count });
+            } else {
+                Self::deposit_event(Event::SpaceModerationDataPartiallyRemoved {
+                    space_id,
+                    removed_count,
+                });
+            }
+
+            let actual_weight =
+                <T as Config>::WeightInfo::remove_space_moderation_data(removed_count);
+            Ok(Some(actual_weight).into())
+        }
+
+        /// Remove a report, that was resolved by an update of the entity status,
+        /// and return its deposit to the reporter. Anyone can call this dispatch.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_resolved_report())]
+        pub fn remove_resolved_report(origin: OriginFor<T>, report_id: ReportId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let report = Self::require_report(report_id)?;
+            ensure!(
+                Self::report_status(&report) == ReportStatus::Resolved,
+                Error::<T>::ReportIsNotResolved
+            );
+
+            Self::remove_report(&report);
+            Self::deposit_event(Event::ReportRemoved { report_id });
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use frame_support::BoundedVec;
+
+use super::*;
+
+pub type EntityIdOf<T> = EntityId<<T as frame_system::Config>::AccountId>;
+
+/// An entity that can be blocked or allowed within a given space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum EntityId<AccountId> {
+    /// Any content stored off-chain, e.g. by its IPFS CID.
+    Content(Content),
+    Account(AccountId),
+    Space(SpaceId),
+    Post(PostId),
+}
+
+/// The moderation status of an entity within a given space.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum EntityStatus {
+    Allowed,
+    Blocked,
+}
+
+pub type ReportId = u64;
+
+pub const FIRST_REPORT_ID: u64 = 1;
+
+/// A report filed by an account against an entity within a given space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct Report<T: Config> {
+    /// Unique sequential identifier of a report. Examples of report ids: `1`, `2`, `3`,
+    /// and so on.
+    pub id: ReportId,
+
+    pub created: WhoAndWhenOf<T>,
+
+    /// An entity that was reported.
+    pub entity: EntityIdOf<T>,
+
+    /// An id of a space within which the entity was reported.
+    pub space_id: SpaceId,
+
+    /// Content that explains why the entity was reported.
+    pub reason: Content,
+
+    /// The stage of this report in the moderation workflow, unless the report was resolved
+    /// by an update of the entity status. See `Pallet::report_status` for the current one.
+    pub status: ReportStatus,
+}
+
+/// The stage of a report in the moderation workflow.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ReportStatus {
+    /// No moderator has suggested a status for the reported entity yet.
+    Pending,
+    /// At least one moderator has suggested a status for the reported entity.
+    UnderReview,
+    /// The status of the reported entity was updated by a moderator.
+    Resolved,
+    /// The report was dismissed by a moderator without updating the entity status.
+    Dismissed,
+}
+
+pub(crate) type SuggestedStatuses<T> =
+    BoundedVec<SuggestedStatus<T>, <T as Config>::MaxSuggestedStatusesPerEntity>;
+
+/// A status of an entity suggested by a moderator within a given space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct SuggestedStatus<T: Config> {
+    pub suggested: WhoAndWhenOf<T>,
+
+    /// `None` means that a moderator suggests to remove the current status of the entity.
+    pub status: Option<EntityStatus>,
+
+    /// An optional report, on which this suggestion is based.
+    pub report_id: Option<ReportId>,
+}
@@ -0,0 +1,228 @@
+//! Weights for pallet_moderation
+//!
+//! These weights are estimates, based on the weights of similar extrinsics in other pallets,
+//! and were not produced by the benchmark CLI. They should be replaced by the output of
+//! `benchmark pallet --pallet pallet_moderation` once the benchmarks are run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(non_snake_case)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_moderation.
+pub trait WeightInfo {
+    fn update_entity_status() -> Weight;
+    fn report_entity() -> Weight;
+    fn suggest_entity_status() -> Weight;
+    fn dismiss_report() -> Weight;
+    fn remove_space_moderation_data(n: u32, ) -> Weight;
+    fn remove_resolved_report() -> Weight;
+}
+
+/// Weights for pallet_moderation using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+        impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByEntityInSpace (r:2 w:1)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Moderation NextReportId (r:1 w:0)
+            // Storage: Moderation ReportsResolvedBefore (r:0 w:1)
+            // Storage: Moderation SuggestedStatusesByEntityInSpace (r:0 w:1)
+            // Storage: Moderation ModeratedEntitiesBySpaceId (r:0 w:1)
+        fn update_entity_status() -> Weight {
+        // Minimum execution time: 28_000 nanoseconds.
+        Weight::from_ref_time(29_130_000)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: Moderation ReportIdByAccount (r:2 w:2)
+            // Storage: Moderation ReportById (r:1 w:2)
+            // Storage: Moderation ReportsResolvedBefore (r:1 w:0)
+            // Storage: Moderation ReportIdsBySpaceId (r:1 w:2)
+            // Storage: Moderation ReportCountBySpaceId (r:1 w:2)
+            // Storage: Moderation ReportCountByAccount (r:2 w:2)
+            // Storage: Moderation DepositByReportId (r:1 w:2)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Moderation NextReportId (r:1 w:1)
+            // Storage: Moderation ModeratedEntitiesBySpaceId (r:0 w:1)
+        fn report_entity() -> Weight {
+        // Minimum execution time: 68_000 nanoseconds.
+        Weight::from_ref_time(69_874_000)
+            .saturating_add(T::DbWeight::get().reads(13))
+            .saturating_add(T::DbWeight::get().writes(15))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Moderation ReportById (r:1 w:1)
+            // Storage: Moderation ReportsResolvedBefore (r:1 w:0)
+            // Storage: Moderation SuggestedStatusesByEntityInSpace (r:1 w:1)
+            // Storage: Moderation ModeratedEntitiesBySpaceId (r:0 w:1)
+        fn suggest_entity_status() -> Weight {
+        // Minimum execution time: 45_000 nanoseconds.
+        Weight::from_ref_time(46_012_000)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(3))
+        }
+            // Storage: Moderation ReportById (r:1 w:1)
+            // Storage: Moderation ReportsResolvedBefore (r:1 w:0)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Moderation ReportIdByAccount (r:1 w:1)
+            // Storage: Moderation ReportIdsBySpaceId (r:1 w:1)
+            // Storage: Moderation ReportCountBySpaceId (r:1 w:1)
+            // Storage: Moderation ReportCountByAccount (r:1 w:1)
+            // Storage: Moderation DepositByReportId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn dismiss_report() -> Weight {
+        // Minimum execution time: 44_000 nanoseconds.
+        Weight::from_ref_time(45_361_000)
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(7))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation ModeratedEntitiesBySpaceId (r:2 w:1)
+            // Storage: Moderation StatusByEntityInSpace (r:0 w:1)
+            // Storage: Moderation SuggestedStatusesByEntityInSpace (r:0 w:1)
+            // Storage: Moderation ReportsResolvedBefore (r:0 w:1)
+            // Storage: Moderation ReportIdsBySpaceId (r:2 w:1)
+            // Storage: Moderation ReportById (r:1 w:1)
+            // Storage: Moderation ReportIdByAccount (r:1 w:1)
+            // Storage: Moderation ReportCountBySpaceId (r:1 w:1)
+            // Storage: Moderation ReportCountByAccount (r:1 w:1)
+            // Storage: Moderation DepositByReportId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            /// The range of component `n` is `[0, 100]`.
+        fn remove_space_moderation_data(n: u32, ) -> Weight {
+        // Minimum execution time: 22_000 nanoseconds.
+        Weight::from_ref_time(23_410_000)
+            // Standard Error: 6_000
+            .saturating_add(Weight::from_ref_time(14_205_000).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+        }
+            // Storage: Moderation ReportById (r:1 w:1)
+            // Storage: Moderation ReportsResolvedBefore (r:1 w:0)
+            // Storage: Moderation ReportIdByAccount (r:1 w:1)
+            // Storage: Moderation ReportIdsBySpaceId (r:1 w:1)
+            // Storage: Moderation ReportCountBySpaceId (r:1 w:1)
+            // Storage: Moderation ReportCountByAccount (r:1 w:1)
+            // Storage: Moderation DepositByReportId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn remove_resolved_report() -> Weight {
+        // Minimum execution time: 38_000 nanoseconds.
+        Weight::from_ref_time(39_086_000)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(7))
+        }
+    }
+
+    // For backwards compatibility and tests
+    impl WeightInfo for () {
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByEntityInSpace (r:2 w:1)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Moderation NextReportId (r:1 w:0)
+            // Storage: Moderation ReportsResolvedBefore (r:0 w:1)
+            // Storage: Moderation SuggestedStatusesByEntityInSpace (r:0 w:1)
+            // Storage: Moderation ModeratedEntitiesBySpaceId (r:0 w:1)
+        fn update_entity_status() -> Weight {
+        // Minimum execution time: 28_000 nanoseconds.
+        Weight::from_ref_time(29_130_000)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(4))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: Moderation ReportIdByAccount (r:2 w:2)
+            // Storage: Moderation ReportById (r:1 w:2)
+            // Storage: Moderation ReportsResolvedBefore (r:1 w:0)
+            // Storage: Moderation ReportIdsBySpaceId (r:1 w:2)
+            // Storage: Moderation ReportCountBySpaceId (r:1 w:2)
+            // Storage: Moderation ReportCountByAccount (r:2 w:2)
+            // Storage: Moderation DepositByReportId (r:1 w:2)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Moderation NextReportId (r:1 w:1)
+            // Storage: Moderation ModeratedEntitiesBySpaceId (r:0 w:1)
+        fn report_entity() -> Weight {
+        // Minimum execution time: 68_000 nanoseconds.
+        Weight::from_ref_time(69_874_000)
+            .saturating_add(RocksDbWeight::get().reads(13))
+            .saturating_add(RocksDbWeight::get().writes(15))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Moderation ReportById (r:1 w:1)
+            // Storage: Moderation ReportsResolvedBefore (r:1 w:0)
+            // Storage: Moderation SuggestedStatusesByEntityInSpace (r:1 w:1)
+            // Storage: Moderation ModeratedEntitiesBySpaceId (r:0 w:1)
+        fn suggest_entity_status() -> Weight {
+        // Minimum execution time: 45_000 nanoseconds.
+        Weight::from_ref_time(46_012_000)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(3))
+        }
+            // Storage: Moderation ReportById (r:1 w:1)
+            // Storage: Moderation ReportsResolvedBefore (r:1 w:0)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Moderation ReportIdByAccount (r:1 w:1)
+            // Storage: Moderation ReportIdsBySpaceId (r:1 w:1)
+            // Storage: Moderation ReportCountBySpaceId (r:1 w:1)
+            // Storage: Moderation ReportCountByAccount (r:1 w:1)
+            // Storage: Moderation DepositByReportId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn dismiss_report() -> Weight {
+        // Minimum execution time: 44_000 nanoseconds.
+        Weight::from_ref_time(45_361_000)
+            .saturating_add(RocksDbWeight::get().reads(11))
+            .saturating_add(RocksDbWeight::get().writes(7))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation ModeratedEntitiesBySpaceId (r:2 w:1)
+            // Storage: Moderation StatusByEntityInSpace (r:0 w:1)
+            // Storage: Moderation SuggestedStatusesByEntityInSpace (r:0 w:1)
+            // Storage: Moderation ReportsResolvedBefore (r:0 w:1)
+            // Storage: Moderation ReportIdsBySpaceId (r:2 w:1)
+            // Storage: Moderation ReportById (r:1 w:1)
+            // Storage: Moderation ReportIdByAccount (r:1 w:1)
+            // Storage: Moderation ReportCountBySpaceId (r:1 w:1)
+            // Storage: Moderation ReportCountByAccount (r:1 w:1)
+            // Storage: Moderation DepositByReportId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            /// The range of component `n` is `[0, 100]`.
+        fn remove_space_moderation_data(n: u32, ) -> Weight {
+        // Minimum execution time: 22_000 nanoseconds.
+        Weight::from_ref_time(23_410_000)
+            // Standard Error: 6_000
+            .saturating_add(Weight::from_ref_time(14_205_000).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+        }
+            // Storage: Moderation ReportById (r:1 w:1)
+            // Storage: Moderation ReportsResolvedBefore (r:1 w:0)
+            // Storage: Moderation ReportIdByAccount (r:1 w:1)
+            // Storage: Moderation ReportIdsBySpaceId (r:1 w:1)
+            // Storage: Moderation ReportCountBySpaceId (r:1 w:1)
+            // Storage: Moderation ReportCountByAccount (r:1 w:1)
+            // Storage: Moderation DepositByReportId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn remove_resolved_report() -> Weight {
+        // Minimum execution time: 38_000 nanoseconds.
+        Weight::from_ref_time(39_086_000)
+            .saturating_add(RocksDbWeight::get().reads(8))
+            .saturating_add(RocksDbWeight::get().writes(7))
+        }
+    }
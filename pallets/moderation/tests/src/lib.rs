@@ -0,0 +1,6 @@
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+#[cfg(test)]
+mod tests_utils;
@@ -0,0 +1,576 @@
+use frame_support::{assert_noop, assert_ok};
+
+use pallet_moderation::{EntityId, EntityStatus, Error as ModerationError, Event, ReportStatus};
+use pallet_posts::Error as PostsError;
+use pallet_spaces::Error as SpacesError;
+use subsocial_support::{
+    traits::{IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked},
+    Content, ContentError, ModerationError as SupportModerationError,
+};
+
+use crate::{mock::*, tests_utils::*};
+
+#[test]
+fn update_entity_status_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_block_account_2_in_space_1());
+
+        assert_eq!(
+            Moderation::status_by_entity_in_space(EntityId::Account(ACCOUNT2), SPACE1),
+            Some(EntityStatus::Blocked)
+        );
+        assert!(Moderation::is_blocked_account(ACCOUNT2, SPACE1));
+        assert!(!Moderation::is_allowed_account(ACCOUNT2, SPACE1));
+
+        System::assert_last_event(
+            Event::<Test>::EntityStatusUpdated {
+                account: ACCOUNT1,
+                entity: EntityId::Account(ACCOUNT2),
+                space_id: SPACE1,
+                status: Some(EntityStatus::Blocked),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_remove_status_when_none_provided() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_block_account_2_in_space_1());
+        assert_ok!(_update_entity_status(None, None, None, Some(None)));
+
+        assert_eq!(Moderation::status_by_entity_in_space(EntityId::Account(ACCOUNT2), SPACE1), None);
+        assert!(Moderation::is_allowed_account(ACCOUNT2, SPACE1));
+    });
+}
+
+#[test]
+fn entity_status_should_be_scoped_to_space() {
+    ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+        assert_ok!(_update_entity_status(None, Some(EntityId::Post(POST1)), Some(SPACE2), None));
+        assert_ok!(_update_entity_status(
+            None,
+            Some(EntityId::Space(SPACE2)),
+            Some(SPACE1),
+            None
+        ));
+        assert_ok!(_update_entity_status(
+            None,
+            Some(EntityId::Content(another_space_content_ipfs())),
+            Some(SPACE1),
+            None
+        ));
+
+        assert!(Moderation::is_blocked_post(POST1, SPACE2));
+        assert!(Moderation::is_allowed_post(POST1, SPACE1));
+
+        assert!(Moderation::is_blocked_space(SPACE2, SPACE1));
+        assert!(Moderation::is_allowed_space(SPACE1, SPACE2));
+
+        assert!(Moderation::is_blocked_content(another_space_content_ipfs(), SPACE1));
+        assert!(Moderation::is_allowed_content(another_space_content_ipfs(), SPACE2));
+    });
+}
+
+#[test]
+fn update_entity_status_should_fail_when_space_not_found() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_block_account_2_in_space_1(), SpacesError::<Test>::SpaceNotFound);
+    });
+}
+
+#[test]
+fn update_entity_status_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _update_entity_status(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                Some(EntityId::Account(ACCOUNT3)),
+                None,
+                None
+            ),
+            ModerationError::<Test>::NoPermissionToUpdateEntityStatus
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_fail_when_post_not_found() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _update_entity_status(None, Some(EntityId::Post(POST1)), None, None),
+            PostsError::<Test>::PostNotFound
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_fail_when_blocking_space_owner() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _update_entity_status(None, Some(EntityId::Account(ACCOUNT1)), None, None),
+            ModerationError::<Test>::CannotBlockSpaceOwner
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_fail_when_blocking_space_within_itself() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _update_entity_status(None, Some(EntityId::Space(SPACE1)), None, None),
+            ModerationError::<Test>::CannotBlockSpaceWithinItself
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_fail_when_status_not_changed() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_block_account_2_in_space_1());
+        assert_noop!(
+            _block_account_2_in_space_1(),
+            ModerationError::<Test>::EntityStatusNotChanged
+        );
+    });
+}
+
+#[test]
+fn blocked_account_should_not_be_able_to_create_post() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_block_account_2_in_space_1());
+        assert_noop!(
+            _create_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            SupportModerationError::AccountIsBlocked
+        );
+    });
+}
+
+#[test]
+fn blocked_content_should_not_be_allowed_in_space() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_entity_status(
+            None,
+            Some(EntityId::Content(another_space_content_ipfs())),
+            None,
+            None
+        ));
+        assert_noop!(
+            _update_space_content(another_space_content_ipfs()),
+            SupportModerationError::ContentIsBlocked
+        );
+    });
+}
+
+#[test]
+fn blocked_post_should_not_be_moved_to_space() {
+    ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+        assert_ok!(_update_entity_status(None, Some(EntityId::Post(POST1)), Some(SPACE2), None));
+        assert_noop!(_move_post_1_to_space_2(), SupportModerationError::PostIsBlocked);
+    });
+}
+
+// Report-and-review workflow
+
+#[test]
+fn report_entity_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+
+        let report = Moderation::report_by_id(REPORT1).unwrap();
+        assert_eq!(report.created.account, ACCOUNT3);
+        assert_eq!(report.entity, EntityId::Account(ACCOUNT2));
+        assert_eq!(report.space_id, SPACE1);
+        assert_eq!(report.reason, report_reason());
+        assert_eq!(report.status, ReportStatus::Pending);
+
+        assert_eq!(Moderation::next_report_id(), REPORT1 + 1);
+        assert_eq!(Moderation::get_report_ids_by_space_id(SPACE1, None, 10), vec![REPORT1]);
+        assert_eq!(Moderation::report_count_by_space_id(SPACE1), 1);
+        assert_eq!(Moderation::report_count_by_account(ACCOUNT3), 1);
+        assert_eq!(
+            Moderation::report_id_by_account((EntityId::Account(ACCOUNT2), SPACE1, ACCOUNT3)),
+            Some(REPORT1)
+        );
+
+        System::assert_last_event(
+            Event::<Test>::EntityReported {
+                account: ACCOUNT3,
+                entity: EntityId::Account(ACCOUNT2),
+                space_id: SPACE1,
+                report_id: REPORT1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn report_entity_should_reserve_deposit() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        ReportByteDeposit::set(1);
+        assert_ok!(_report_account_2_in_space_1());
+
+        let deposit = Moderation::deposit_by_report_id(REPORT1).unwrap();
+        assert_eq!(deposit.depositor, ACCOUNT3);
+        assert_eq!(deposit.amount, expected_report_deposit(REPORT1));
+        assert_eq!(Balances::reserved_balance(ACCOUNT3), deposit.amount);
+
+        assert_ok!(_dismiss_report(None, None));
+
+        assert!(Moderation::deposit_by_report_id(REPORT1).is_none());
+        assert_eq!(Balances::reserved_balance(ACCOUNT3), 0);
+    });
+}
+
+#[test]
+fn report_entity_should_fail_when_reason_is_empty() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _report_entity(None, None, None, Some(Content::None)),
+            ContentError::ContentIsEmpty
+        );
+    });
+}
+
+#[test]
+fn report_entity_should_fail_when_space_not_found() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_report_account_2_in_space_1(), SpacesError::<Test>::SpaceNotFound);
+    });
+}
+
+#[test]
+fn report_entity_should_fail_when_reporter_is_blocked() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_entity_status(None, Some(EntityId::Account(ACCOUNT3)), None, None));
+
+        assert_noop!(_report_account_2_in_space_1(), SupportModerationError::AccountIsBlocked);
+    });
+}
+
+#[test]
+fn report_entity_should_fail_when_post_is_in_another_space() {
+    ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+        assert_noop!(
+            _report_entity(None, Some(EntityId::Post(POST1)), Some(SPACE2), None),
+            ModerationError::<Test>::EntityNotInScope
+        );
+    });
+}
+
+#[test]
+fn report_entity_should_fail_when_already_reported() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+
+        assert_noop!(
+            _report_account_2_in_space_1(),
+            ModerationError::<Test>::AlreadyReportedEntity
+        );
+    });
+}
+
+#[test]
+fn report_entity_should_fail_when_reporter_has_too_many_reports() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+        assert_ok!(_report_entity(None, Some(EntityId::Account(ACCOUNT1)), None, None));
+
+        assert_noop!(
+            _report_entity(None, Some(EntityId::Space(SPACE1)), None, None),
+            ModerationError::<Test>::TooManyReportsByAccount
+        );
+
+        // Other accounts can still report the same entities.
+        assert_ok!(_report_entity(Some(RuntimeOrigin::signed(ACCOUNT1)), None, None, None));
+    });
+}
+
+#[test]
+fn suggest_entity_status_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+        assert_ok!(_suggest_blocking_account_2_in_space_1());
+
+        let suggestions = Moderation::suggested_statuses(EntityId::Account(ACCOUNT2), SPACE1);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].suggested.account, ACCOUNT1);
+        assert_eq!(suggestions[0].status, Some(EntityStatus::Blocked));
+        assert_eq!(suggestions[0].report_id, Some(REPORT1));
+
+        assert_eq!(Moderation::report_by_id(REPORT1).unwrap().status, ReportStatus::UnderReview);
+        System::assert_has_event(
+            Event::<Test>::ReportStatusUpdated {
+                report_id: REPORT1,
+                status: ReportStatus::UnderReview,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::<Test>::EntityStatusSuggested {
+                account: ACCOUNT1,
+                entity: EntityId::Account(ACCOUNT2),
+                space_id: SPACE1,
+                status: Some(EntityStatus::Blocked),
+                report_id: Some(REPORT1),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn suggest_entity_status_should_work_without_report() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_suggest_entity_status(None, None, None, None, Some(None)));
+
+        let suggestions = Moderation::suggested_statuses(EntityId::Account(ACCOUNT2), SPACE1);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].report_id, None);
+    });
+}
+
+#[test]
+fn suggest_entity_status_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+
+        assert_noop!(
+            _suggest_entity_status(Some(RuntimeOrigin::signed(ACCOUNT3)), None, None, None, None),
+            ModerationError::<Test>::NoPermissionToSuggestEntityStatus
+        );
+    });
+}
+
+#[test]
+fn suggest_entity_status_should_fail_when_report_not_found() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _suggest_blocking_account_2_in_space_1(),
+            ModerationError::<Test>::ReportNotFound
+        );
+    });
+}
+
+#[test]
+fn suggest_entity_status_should_fail_when_report_does_not_match_entity() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+
+        assert_noop!(
+            _suggest_entity_status(None, Some(EntityId::Account(ACCOUNT3)), None, None, None),
+            ModerationError::<Test>::ReportDoesNotMatchEntity
+        );
+    });
+}
+
+#[test]
+fn suggest_entity_status_should_fail_when_already_suggested() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+        assert_ok!(_suggest_blocking_account_2_in_space_1());
+
+        assert_noop!(
+            _suggest_blocking_account_2_in_space_1(),
+            ModerationError::<Test>::AlreadySuggestedEntityStatus
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_resolve_open_reports() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+        assert_ok!(_suggest_blocking_account_2_in_space_1());
+
+        assert_ok!(_block_account_2_in_space_1());
+
+        let report = Moderation::report_by_id(REPORT1).unwrap();
+        assert_eq!(Moderation::report_status(&report), ReportStatus::Resolved);
+        assert!(!Moderation::is_report_open(&report));
+        assert!(Moderation::suggested_statuses(EntityId::Account(ACCOUNT2), SPACE1).is_empty());
+
+        // Resolved report cannot be used for new suggestions.
+        assert_noop!(
+            _suggest_blocking_account_2_in_space_1(),
+            ModerationError::<Test>::ReportIsAlreadyClosed
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_not_resolve_reports_filed_after_it() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_block_account_2_in_space_1());
+        assert_ok!(_report_account_2_in_space_1());
+
+        let report = Moderation::report_by_id(REPORT1).unwrap();
+        assert_eq!(Moderation::report_status(&report), ReportStatus::Pending);
+    });
+}
+
+#[test]
+fn report_entity_should_remove_resolved_report_of_same_account() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        ReportByteDeposit::set(1);
+        assert_ok!(_report_account_2_in_space_1());
+        assert_ok!(_block_account_2_in_space_1());
+
+        assert_ok!(_report_account_2_in_space_1()); // ReportId 2
+
+        assert!(Moderation::report_by_id(REPORT1).is_none());
+        assert!(Moderation::deposit_by_report_id(REPORT1).is_none());
+        assert_eq!(
+            Moderation::report_id_by_account((EntityId::Account(ACCOUNT2), SPACE1, ACCOUNT3)),
+            Some(REPORT2)
+        );
+        assert_eq!(Moderation::report_count_by_account(ACCOUNT3), 1);
+        assert_eq!(Moderation::get_report_ids_by_space_id(SPACE1, None, 10), vec![REPORT2]);
+        assert_eq!(Balances::reserved_balance(ACCOUNT3), expected_report_deposit(REPORT2));
+        System::assert_has_event(Event::<Test>::ReportRemoved { report_id: REPORT1 }.into());
+    });
+}
+
+#[test]
+fn remove_resolved_report_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        ReportByteDeposit::set(1);
+        assert_ok!(_report_account_2_in_space_1());
+        assert_ok!(_block_account_2_in_space_1());
+
+        assert_ok!(_remove_resolved_report(None));
+
+        assert!(Moderation::report_by_id(REPORT1).is_none());
+        assert!(Moderation::deposit_by_report_id(REPORT1).is_none());
+        assert_eq!(
+            Moderation::report_id_by_account((EntityId::Account(ACCOUNT2), SPACE1, ACCOUNT3)),
+            None
+        );
+        assert_eq!(Moderation::report_count_by_account(ACCOUNT3), 0);
+        assert_eq!(Moderation::report_count_by_space_id(SPACE1), 0);
+        assert!(Moderation::get_report_ids_by_space_id(SPACE1, None, 10).is_empty());
+        assert_eq!(Balances::reserved_balance(ACCOUNT3), 0);
+
+        System::assert_last_event(Event::<Test>::ReportRemoved { report_id: REPORT1 }.into());
+    });
+}
+
+#[test]
+fn remove_resolved_report_should_fail_when_report_is_open() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+
+        assert_noop!(_remove_resolved_report(None), ModerationError::<Test>::ReportIsNotResolved);
+    });
+}
+
+#[test]
+fn dismiss_report_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+        assert_ok!(_dismiss_report(None, None));
+
+        assert!(Moderation::report_by_id(REPORT1).is_none());
+        assert_eq!(Moderation::status_by_entity_in_space(EntityId::Account(ACCOUNT2), SPACE1), None);
+        assert!(Moderation::get_report_ids_by_space_id(SPACE1, None, 10).is_empty());
+        assert_eq!(Moderation::report_count_by_account(ACCOUNT3), 0);
+
+        System::assert_last_event(
+            Event::<Test>::ReportStatusUpdated {
+                report_id: REPORT1,
+                status: ReportStatus::Dismissed,
+            }
+            .into(),
+        );
+
+        // The same account can report the entity again after its report was dismissed.
+        assert_ok!(_report_account_2_in_space_1());
+    });
+}
+
+#[test]
+fn dismiss_report_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+
+        assert_noop!(
+            _dismiss_report(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            ModerationError::<Test>::NoPermissionToUpdateEntityStatus
+        );
+    });
+}
+
+#[test]
+fn dismiss_report_should_fail_when_report_is_closed() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+        assert_ok!(_block_account_2_in_space_1());
+
+        assert_noop!(_dismiss_report(None, None), ModerationError::<Test>::ReportIsAlreadyClosed);
+    });
+}
+
+#[test]
+fn remove_space_moderation_data_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        ReportByteDeposit::set(1);
+        assert_ok!(_report_account_2_in_space_1());
+        assert_ok!(_block_account_2_in_space_1());
+        assert_ok!(_delete_space_1());
+
+        assert_ok!(_remove_space_1_moderation_data(10));
+
+        assert!(
+            Moderation::status_by_entity_in_space(EntityId::Account(ACCOUNT2), SPACE1).is_none()
+        );
+        assert!(Moderation::report_by_id(REPORT1).is_none());
+        assert!(Moderation::get_report_ids_by_space_id(SPACE1, None, 10).is_empty());
+        assert_eq!(Moderation::report_count_by_space_id(SPACE1), 0);
+        assert_eq!(Moderation::report_count_by_account(ACCOUNT3), 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT3), 0);
+
+        System::assert_last_event(
+            Event::<Test>::SpaceModerationDataRemoved { space_id: SPACE1, removed_count: 2 }.into(),
+        );
+    });
+}
+
+#[test]
+fn remove_space_moderation_data_should_continue_where_previous_call_stopped() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+        assert_ok!(_delete_space_1());
+
+        assert_ok!(_remove_space_1_moderation_data(1));
+        System::assert_last_event(
+            Event::<Test>::SpaceModerationDataPartiallyRemoved {
+                space_id: SPACE1,
+                removed_count: 1,
+            }
+            .into(),
+        );
+        assert!(Moderation::report_by_id(REPORT1).is_some());
+
+        assert_ok!(_remove_space_1_moderation_data(1));
+        System::assert_last_event(
+            Event::<Test>::SpaceModerationDataRemoved { space_id: SPACE1, removed_count: 1 }.into(),
+        );
+        assert!(Moderation::report_by_id(REPORT1).is_none());
+        assert!(Moderation::report_id_by_account((EntityId::Account(ACCOUNT2), SPACE1, ACCOUNT3))
+            .is_none());
+    });
+}
+
+#[test]
+fn remove_space_moderation_data_should_fail_when_space_is_not_deleted() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_report_account_2_in_space_1());
+
+        assert_noop!(
+            _remove_space_1_moderation_data(10),
+            ModerationError::<Test>::SpaceIsNotDeleted
+        );
+    });
+}
@@ -0,0 +1,220 @@
+use frame_support::{assert_ok, pallet_prelude::*};
+use sp_core::storage::Storage;
+use sp_io::TestExternalities;
+
+use pallet_moderation::{EntityId, EntityStatus, ReportId};
+use pallet_posts::PostExtension;
+use pallet_spaces::types::SpaceUpdate;
+use subsocial_support::{Content, PostId, SpaceId};
+
+use crate::mock::*;
+
+////// Ext Builder
+
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+    fn configure_storages(storage: &mut Storage) {
+        let mut accounts = Vec::new();
+        for account in ACCOUNT1..=ACCOUNT3 {
+            accounts.push(account);
+        }
+
+        let _ = pallet_balances::GenesisConfig::<Test> {
+            balances: accounts.iter().cloned().map(|k| (k, 100)).collect(),
+        }
+        .assimilate_storage(storage);
+    }
+
+    /// Default ext configuration with BlockNumber 1
+    pub fn build() -> TestExternalities {
+        let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+        Self::configure_storages(&mut storage);
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| System::set_block_number(1));
+
+        ext
+    }
+
+    fn add_default_space() {
+        assert_ok!(_create_space(None, None));
+    }
+
+    fn add_post() {
+        Self::add_default_space();
+        assert_ok!(_create_default_post());
+    }
+
+    /// Custom ext configuration with SpaceId 1 and BlockNumber 1
+    pub fn build_with_space() -> TestExternalities {
+        let mut ext = Self::build();
+        ext.execute_with(Self::add_default_space);
+        ext
+    }
+
+    /// Custom ext configuration with SpaceId 1-2, PostId 1 where BlockNumber 1
+    pub fn build_with_post_and_two_spaces() -> TestExternalities {
+        let mut ext = Self::build();
+        ext.execute_with(|| {
+            Self::add_post();
+            assert_ok!(_create_space(None, Some(another_space_content_ipfs())));
+        });
+        ext
+    }
+}
+
+////// Consts
+
+pub(crate) const ACCOUNT1: AccountId = 1;
+pub(crate) const ACCOUNT2: AccountId = 2;
+pub(crate) const ACCOUNT3: AccountId = 3;
+
+pub(crate) const SPACE1: SpaceId = 1001;
+pub(crate) const SPACE2: SpaceId = 1002;
+
+pub(crate) const POST1: PostId = 1;
+
+///////////// Space Utils
+
+pub(crate) fn space_content_ipfs() -> Content {
+    Content::IPFS(b"bafyreib3mgbou4xln42qqcgj6qlt3cif35x4ribisxgq7unhpun525l54e".to_vec())
+}
+
+pub(crate) fn another_space_content_ipfs() -> Content {
+    Content::IPFS(b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".to_vec())
+}
+
+pub(crate) fn _create_space(origin: Option<RuntimeOrigin>, content: Option<Content>) -> DispatchResult {
+    Spaces::create_space(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        content.unwrap_or_else(space_content_ipfs),
+        None,
+        None,
+    )
+}
+
+pub(crate) fn _update_space_content(content: Content) -> DispatchResult {
+    Spaces::update_space(
+        RuntimeOrigin::signed(ACCOUNT1),
+        SPACE1,
+        SpaceUpdate { content: Some(content), handle: None, hidden: None, permissions: None },
+    )
+}
+
+///////////// Post Utils
+
+pub(crate) fn post_content_ipfs() -> Content {
+    Content::IPFS(b"bafyreidzue2dtxpj6n4x5mktrt7las5wz5diqma47zr25uau743dhe76we".to_vec())
+}
+
+pub(crate) fn _create_default_post() -> DispatchResult {
+    _create_post(None, None)
+}
+
+pub(crate) fn _create_post(origin: Option<RuntimeOrigin>, space_id: Option<SpaceId>) -> DispatchResult {
+    Posts::create_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        Some(space_id.unwrap_or(SPACE1)),
+        PostExtension::RegularPost,
+        post_content_ipfs(),
+        None,
+    )
+}
+
+pub(crate) fn _move_post_1_to_space_2() -> DispatchResult {
+    Posts::move_post(RuntimeOrigin::signed(ACCOUNT1), POST1, Some(SPACE2))
+}
+
+pub(crate) fn _delete_space_1() -> DispatchResult {
+    Spaces::delete_space(RuntimeOrigin::signed(ACCOUNT1), SPACE1)
+}
+
+///////////// Moderation Utils
+
+pub(crate) fn _block_account_2_in_space_1() -> DispatchResult {
+    _update_entity_status(None, Some(EntityId::Account(ACCOUNT2)), None, None)
+}
+
+pub(crate) fn _update_entity_status(
+    origin: Option<RuntimeOrigin>,
+    entity: Option<EntityId<AccountId>>,
+    space_id: Option<SpaceId>,
+    status_opt: Option<Option<EntityStatus>>,
+) -> DispatchResult {
+    Moderation::update_entity_status(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        entity.unwrap_or(EntityId::Account(ACCOUNT2)),
+        space_id.unwrap_or(SPACE1),
+        status_opt.unwrap_or(Some(EntityStatus::Blocked)),
+    )
+}
+
+pub(crate) const REPORT1: ReportId = 1;
+pub(crate) const REPORT2: ReportId = 2;
+
+pub(crate) fn report_reason() -> Content {
+    Content::IPFS(b"QmYA2fn8cMbVWo4v95RwcwJVyQsNtnEwHerfWR8UNtEwoE".to_vec())
+}
+
+pub(crate) fn _report_account_2_in_space_1() -> DispatchResult {
+    _report_entity(None, None, None, None)
+}
+
+pub(crate) fn _report_entity(
+    origin: Option<RuntimeOrigin>,
+    entity: Option<EntityId<AccountId>>,
+    space_id: Option<SpaceId>,
+    reason: Option<Content>,
+) -> DispatchResult {
+    Moderation::report_entity(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT3)),
+        entity.unwrap_or(EntityId::Account(ACCOUNT2)),
+        space_id.unwrap_or(SPACE1),
+        reason.unwrap_or_else(report_reason),
+    )
+}
+
+pub(crate) fn _suggest_blocking_account_2_in_space_1() -> DispatchResult {
+    _suggest_entity_status(None, None, None, None, None)
+}
+
+pub(crate) fn _suggest_entity_status(
+    origin: Option<RuntimeOrigin>,
+    entity: Option<EntityId<AccountId>>,
+    space_id: Option<SpaceId>,
+    status: Option<Option<EntityStatus>>,
+    report_id_opt: Option<Option<ReportId>>,
+) -> DispatchResult {
+    Moderation::suggest_entity_status(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        entity.unwrap_or(EntityId::Account(ACCOUNT2)),
+        space_id.unwrap_or(SPACE1),
+        status.unwrap_or(Some(EntityStatus::Blocked)),
+        report_id_opt.unwrap_or(Some(REPORT1)),
+    )
+}
+
+pub(crate) fn _dismiss_report(origin: Option<RuntimeOrigin>, report_id: Option<ReportId>) -> DispatchResult {
+    Moderation::dismiss_report(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        report_id.unwrap_or(REPORT1),
+    )
+}
+
+pub(crate) fn _remove_resolved_report(report_id: Option<ReportId>) -> DispatchResult {
+    Moderation::remove_resolved_report(
+        RuntimeOrigin::signed(ACCOUNT1),
+        report_id.unwrap_or(REPORT1),
+    )
+}
+
+pub(crate) fn expected_report_deposit(report_id: ReportId) -> Balance {
+    let report = Moderation::report_by_id(report_id).unwrap();
+    report.encoded_size() as Balance * ReportByteDeposit::get()
+}
+
+pub(crate) fn _remove_space_1_moderation_data(limit: u32) -> DispatchResultWithPostInfo {
+    Moderation::remove_space_moderation_data(RuntimeOrigin::signed(ACCOUNT3), SPACE1, limit)
+}
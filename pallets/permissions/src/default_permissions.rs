@@ -26,6 +26,9 @@ parameter_types! {
       SP::Upvote,
       SP::Downvote,
       SP::Share,
+
+      SP::VoteInPolls,
+      SP::ReactWithCustomKinds,
     ].into_iter().collect()),
 
     // Followers can do everything that everyone else can.
@@ -56,6 +59,8 @@ parameter_types! {
       SP::UpdateEntityStatus,
 
       SP::UpdateSpaceSettings,
+
+      SP::PinPosts,
     ].into_iter().collect()),
   };
 }
@@ -78,6 +78,29 @@ pub mod pallet {
             None
         }
 
+        /// Merge two sets of permission overrides role by role:
+        /// the `primary` ones are resolved before the `secondary` ones.
+        pub fn merge_overrides(
+            primary: Option<SpacePermissions>,
+            secondary: Option<SpacePermissions>,
+        ) -> Option<SpacePermissions> {
+            let primary = match primary {
+                Some(primary) => primary,
+                None => return secondary,
+            };
+            let secondary = secondary.unwrap_or_default();
+
+            Some(SpacePermissions {
+                none: Self::get_overrides_or_defaults(primary.none, secondary.none),
+                everyone: Self::get_overrides_or_defaults(primary.everyone, secondary.everyone),
+                follower: Self::get_overrides_or_defaults(primary.follower, secondary.follower),
+                space_owner: Self::get_overrides_or_defaults(
+                    primary.space_owner,
+                    secondary.space_owner,
+                ),
+            })
+        }
+
         pub fn override_permissions(mut overrides: SpacePermissions) -> SpacePermissions {
             overrides.none = overrides.none.map(|mut none_permissions_set| {
                 none_permissions_set
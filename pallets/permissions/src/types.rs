@@ -75,6 +75,16 @@ pub enum SpacePermission {
     // Related to space settings:
     /// Allows to update space settings across different pallets.
     UpdateSpaceSettings,
+
+    /// Pin and unpin root posts in this space.
+    PinPosts,
+
+    /// Vote in any poll in this space.
+    VoteInPolls,
+
+    /// React to any post or comment in this space with one of the custom reaction kinds
+    /// allowed in this space.
+    ReactWithCustomKinds,
 }
 
 pub type SpacePermissionSet = BTreeSet<SpacePermission>;
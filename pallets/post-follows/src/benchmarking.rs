@@ -3,11 +3,13 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use frame_benchmarking::{account, benchmarks};
-use frame_support::{dispatch::DispatchError, ensure};
+use frame_support::{
+    dispatch::DispatchError, ensure, sp_runtime::traits::Bounded, traits::Currency,
+};
 use frame_system::RawOrigin;
 
 use pallet_posts::{types::Post, PostExtension, PostById, NextPostId};
-use pallet_spaces::types::Space;
+use pallet_spaces::types::{BalanceOf, Space};
 use subsocial_support::{Content, SpaceId};
 
 use super::*;
@@ -15,9 +17,16 @@ use super::*;
 fn create_dummy_space<T: Config>(
     origin: RawOrigin<T::AccountId>,
 ) -> Result<Space<T>, DispatchError> {
+    if let RawOrigin::Signed(owner) = &origin {
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            owner,
+            BalanceOf::<T>::max_value(),
+        );
+    }
+
     let space_id = pallet_spaces::NextSpaceId::<T>::get();
 
-    pallet_spaces::Pallet::<T>::create_space(origin.into(), Content::None, None)?;
+    pallet_spaces::Pallet::<T>::create_space(origin.into(), Content::None, None, None)?;
 
     let space = pallet_spaces::SpaceById::<T>::get(space_id)
         .ok_or(DispatchError::Other("Space not found"))?;
@@ -36,6 +45,7 @@ fn create_dummy_post<T: Config>(
         Some(space_id),
         PostExtension::RegularPost,
         Content::None,
+        None,
     )?;
 
     let post = PostById::<T>::get(post_id).ok_or(DispatchError::Other("Post wasn't created"))?;
@@ -1,18 +1,26 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_support::dispatch::DispatchError;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{dispatch::DispatchError, sp_runtime::traits::Bounded, traits::Currency};
 use frame_system::RawOrigin;
-use pallet_spaces::types::Space;
+use pallet_spaces::types::{BalanceOf, Space};
+use sp_std::vec;
 use subsocial_support::Content;
 
 fn create_dummy_space<T: Config>(
     origin: RawOrigin<T::AccountId>,
 ) -> Result<Space<T>, DispatchError> {
+    if let RawOrigin::Signed(owner) = &origin {
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            owner,
+            BalanceOf::<T>::max_value(),
+        );
+    }
+
     let space_id = pallet_spaces::NextSpaceId::<T>::get();
 
-    pallet_spaces::Pallet::<T>::create_space(origin.into(), Content::None, None)?;
+    pallet_spaces::Pallet::<T>::create_space(origin.into(), Content::None, None, None)?;
 
     let space = pallet_spaces::SpaceById::<T>::get(space_id)
         .ok_or(DispatchError::Other("Space not found"))?;
@@ -31,6 +39,7 @@ fn create_dummy_post<T: Config>(
         Some(space.id),
         PostExtension::RegularPost,
         Content::None,
+        None,
     )?;
 
     let post = PostById::<T>::get(post_id).ok_or(DispatchError::Other("Post wasn't created"))?;
@@ -50,6 +59,7 @@ fn create_dummy_reply<T: Config>(
         Some(space.id),
         PostExtension::Comment(Comment { parent_id: None, root_post_id: post.id }),
         Content::None,
+        None,
     )?;
 
     let post = PostById::<T>::get(post_id).ok_or(DispatchError::Other("Reply wasn't created"))?;
@@ -63,7 +73,7 @@ benchmarks! {
         let space = create_dummy_space::<T>(origin.clone())?;
         let post_id = NextPostId::<T>::get();
 
-    }: create_post(origin, Some(space.id), PostExtension::RegularPost, Content::None)
+    }: create_post(origin, Some(space.id), PostExtension::RegularPost, Content::None, None)
     verify {
         let post = PostById::<T>::get(post_id)
             .ok_or(DispatchError::Other("Post wasn't created"))?;
@@ -78,7 +88,7 @@ benchmarks! {
         let original_post = create_dummy_post::<T>(origin.clone(), space.clone())?;
         let post_id = NextPostId::<T>::get();
 
-    }: create_post(origin, Some(space.id), PostExtension::SharedPost(original_post.id), Content::None)
+    }: create_post(origin, Some(space.id), PostExtension::SharedPost(original_post.id), Content::None, None)
     verify {
         let post = PostById::<T>::get(post_id)
             .ok_or(DispatchError::Other("Post wasn't created"))?;
@@ -98,7 +108,7 @@ benchmarks! {
             parent_id: Some(reply.id),
             root_post_id: original_post.id,
         });
-    }: create_post(origin, Some(space.id), ext, Content::None)
+    }: create_post(origin, Some(space.id), ext, Content::None, None)
     verify {
         let post = PostById::<T>::get(post_id)
             .ok_or(DispatchError::Other("Reply wasn't created"))?;
@@ -120,6 +130,8 @@ benchmarks! {
             hidden: Some(true),
             content: Some(new_content.clone()),
             space_id: None,
+            permissions: None,
+            subscribers_only: None,
         };
     }: update_post(origin, reply.id, update)
     verify {
@@ -144,4 +156,163 @@ benchmarks! {
 
         ensure!(moved_post.space_id == Some(new_space.id), "Post wasn't moved");
     }
+
+    delete_post {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post = create_dummy_post::<T>(origin.clone(), space.clone())?;
+        create_dummy_reply::<T>(origin.clone(), space, post.clone())?;
+    }: delete_post(origin, post.id)
+    verify {
+        ensure!(DeletedPostById::<T>::contains_key(post.id), "Post wasn't deleted");
+    }
+
+    pin_post {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post = create_dummy_post::<T>(origin.clone(), space.clone())?;
+    }: pin_post(origin, post.id)
+    verify {
+        let pinned_post_ids = PinnedPostIdsBySpaceId::<T>::get(space.id);
+        ensure!(pinned_post_ids.contains(&post.id), "Post wasn't pinned");
+    }
+
+    unpin_post {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post = create_dummy_post::<T>(origin.clone(), space.clone())?;
+        Pallet::<T>::pin_post(origin.clone().into(), post.id)?;
+    }: unpin_post(origin, post.id)
+    verify {
+        let pinned_post_ids = PinnedPostIdsBySpaceId::<T>::get(space.id);
+        ensure!(!pinned_post_ids.contains(&post.id), "Post wasn't unpinned");
+    }
+
+    cancel_scheduled_post {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post_id = NextPostId::<T>::get();
+        let publish_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+
+        Pallet::<T>::create_post(
+            origin.clone().into(),
+            Some(space.id),
+            PostExtension::RegularPost,
+            Content::None,
+            Some(publish_at),
+        )?;
+    }: cancel_scheduled_post(origin, post_id)
+    verify {
+        ensure!(!PostById::<T>::contains_key(post_id), "Post wasn't removed");
+        ensure!(!PublishAtByPostId::<T>::contains_key(post_id), "Post wasn't unscheduled");
+    }
+
+    publish_scheduled_posts {
+        let n in 0 .. T::MaxScheduledPostsPerBlock::get();
+
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let publish_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+
+        for _ in 0 .. n {
+            Pallet::<T>::create_post(
+                origin.clone().into(),
+                Some(space.id),
+                PostExtension::RegularPost,
+                Content::None,
+                Some(publish_at),
+            )?;
+        }
+    }: {
+        Pallet::<T>::publish_scheduled_posts(publish_at);
+    }
+    verify {
+        ensure!(
+            PostCountBySpaceId::<T>::get(space.id) == n,
+            "Scheduled posts weren't published"
+        );
+    }
+
+    force_remove_post_thread {
+        let n in 1 .. 100;
+
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post = create_dummy_post::<T>(origin.clone(), space.clone())?;
+
+        for _ in 1 .. n {
+            create_dummy_reply::<T>(origin.clone(), space.clone(), post.clone())?;
+        }
+    }: force_remove_post_thread(RawOrigin::Root, post.id, n)
+    verify {
+        ensure!(!PostById::<T>::contains_key(post.id), "Post wasn't removed");
+        ensure!(ReplyCountByPostId::<T>::get(post.id) == 0, "Replies weren't removed");
+    }
+
+    create_poll {
+        let n in 2 .. T::MaxPollOptions::get();
+
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post_id = NextPostId::<T>::get();
+        let options = vec![Content::None; n as usize];
+        let closes_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+    }: _(origin, space.id, Content::None, options, closes_at)
+    verify {
+        let poll = PollByPostId::<T>::get(post_id).ok_or(DispatchError::Other("Poll wasn't created"))?;
+        ensure!(poll.options.len() == n as usize, "Poll wasn't created with all the options");
+    }
+
+    vote_in_poll {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post_id = NextPostId::<T>::get();
+        let closes_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        Pallet::<T>::create_poll(
+            origin.clone().into(),
+            space.id,
+            Content::None,
+            vec![Content::None, Content::None],
+            closes_at,
+        )?;
+    }: _(origin, post_id, 1)
+    verify {
+        let poll = PollByPostId::<T>::get(post_id).ok_or(DispatchError::Other("Poll not found"))?;
+        ensure!(poll.options[1].votes_count == 1, "Vote wasn't counted");
+    }
+
+    remove_poll_votes {
+        let n in 0 .. 100;
+
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post_id = NextPostId::<T>::get();
+        let closes_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        Pallet::<T>::create_poll(
+            origin.clone().into(),
+            space.id,
+            Content::None,
+            vec![Content::None, Content::None],
+            closes_at,
+        )?;
+
+        for i in 0..n {
+            let voter = account::<T::AccountId>("voter", i, 0);
+            <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+                &voter,
+                BalanceOf::<T>::max_value(),
+            );
+            Pallet::<T>::reserve_poll_vote_deposit(post_id, voter.clone(), 0)?;
+            PollVoteByAccount::<T>::insert(post_id, voter, 0);
+        }
+        PollByPostId::<T>::remove(post_id);
+    }: _(origin, post_id, n)
+    verify {
+        ensure!(
+            PollVoteByAccount::<T>::iter_key_prefix(post_id).next().is_none(),
+            "Poll votes should be removed"
+        );
+    }
 }
@@ -1,9 +1,13 @@
-use frame_support::dispatch::DispatchResult;
+use frame_support::{dispatch::DispatchResult, weights::Weight};
 use sp_runtime::traits::Saturating;
 
-use subsocial_support::{remove_from_vec, SpaceId};
+use subsocial_support::{
+    push_evicting_oldest, remove_from_bounded_vec, traits::BeforeSpaceDeleted,
+    update_storage_deposit, SpaceId,
+};
 
 use super::*;
+use crate::weights::WeightInfo;
 
 impl<T: Config> Post<T> {
     pub fn new(
@@ -17,11 +21,16 @@ impl<T: Config> Post<T> {
             id,
             created: new_who_and_when::<T>(created_by.clone()),
             edited: false,
+            updated: None,
             owner: created_by,
             extension,
             space_id: space_id_opt,
             content,
             hidden: false,
+            subscribers_only: false,
+            permissions: None,
+            replies_count: 0,
+            shares_count: 0,
             upvotes_count: 0,
             downvotes_count: 0,
         }
@@ -52,6 +61,10 @@ impl<T: Config> Post<T> {
         matches!(self.extension, PostExtension::SharedPost(_))
     }
 
+    pub fn is_poll(&self) -> bool {
+        matches!(self.extension, PostExtension::Poll)
+    }
+
     pub fn get_comment_ext(&self) -> Result<Comment, DispatchError> {
         match self.extension {
             PostExtension::Comment(comment_ext) => Ok(comment_ext),
@@ -68,7 +81,8 @@ impl<T: Config> Post<T> {
 
     pub fn get_root_post(&self) -> Result<Post<T>, DispatchError> {
         match self.extension {
-            PostExtension::RegularPost | PostExtension::SharedPost(_) => Ok(self.clone()),
+            PostExtension::RegularPost | PostExtension::SharedPost(_) | PostExtension::Poll =>
+                Ok(self.clone()),
             PostExtension::Comment(comment) => Pallet::<T>::require_post(comment.root_post_id),
         }
     }
@@ -106,6 +120,22 @@ impl<T: Config> Post<T> {
         }
     }
 
+    pub fn inc_replies(&mut self) {
+        self.replies_count.saturating_inc();
+    }
+
+    pub fn dec_replies(&mut self) {
+        self.replies_count.saturating_dec();
+    }
+
+    pub fn inc_shares(&mut self) {
+        self.shares_count.saturating_inc();
+    }
+
+    pub fn dec_shares(&mut self) {
+        self.shares_count.saturating_dec();
+    }
+
     pub fn inc_upvotes(&mut self) {
         self.upvotes_count.saturating_inc();
     }
@@ -162,14 +192,159 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        Spaces::ensure_account_has_space_permission(
+        Self::ensure_account_has_post_permission(
             editor.clone(),
+            post,
+            space,
+            permission_to_check,
+            permission_error,
+        )
+    }
+
+    pub fn ensure_account_can_delete_post(
+        account: &T::AccountId,
+        post: &Post<T>,
+        space: &Space<T>,
+    ) -> DispatchResult {
+        let is_owner = post.is_owner(account);
+        let is_comment = post.is_comment();
+
+        let permission_to_check: SpacePermission;
+        let permission_error: DispatchError;
+
+        if is_comment {
+            if is_owner {
+                permission_to_check = SpacePermission::DeleteOwnComments;
+                permission_error = Error::<T>::NoPermissionToDeleteOwnComments.into();
+            } else {
+                fail!(Error::<T>::NotACommentAuthor);
+            }
+        } else if is_owner {
+            permission_to_check = SpacePermission::DeleteOwnPosts;
+            permission_error = Error::<T>::NoPermissionToDeleteOwnPosts.into();
+        } else {
+            permission_to_check = SpacePermission::DeleteAnyPost;
+            permission_error = Error::<T>::NoPermissionToDeleteAnyPost.into();
+        }
+
+        Self::ensure_account_has_post_permission(
+            account.clone(),
+            post,
             space,
             permission_to_check,
             permission_error,
         )
     }
 
+    pub fn ensure_account_can_hide_post(
+        account: &T::AccountId,
+        post: &Post<T>,
+        space: &Space<T>,
+    ) -> DispatchResult {
+        let is_owner = post.is_owner(account);
+        let is_comment = post.is_comment();
+
+        let permission_to_check: SpacePermission;
+        let permission_error: DispatchError;
+
+        if is_comment {
+            if is_owner {
+                permission_to_check = SpacePermission::HideOwnComments;
+                permission_error = Error::<T>::NoPermissionToHideOwnComments.into();
+            } else {
+                permission_to_check = SpacePermission::HideAnyComment;
+                permission_error = Error::<T>::NoPermissionToHideAnyComment.into();
+            }
+        } else if is_owner {
+            permission_to_check = SpacePermission::HideOwnPosts;
+            permission_error = Error::<T>::NoPermissionToHideOwnPosts.into();
+        } else {
+            permission_to_check = SpacePermission::HideAnyPost;
+            permission_error = Error::<T>::NoPermissionToHideAnyPost.into();
+        }
+
+        Self::ensure_account_has_post_permission(
+            account.clone(),
+            post,
+            space,
+            permission_to_check,
+            permission_error,
+        )
+    }
+
+    /// Check that a given account can pin or unpin a post in its' space,
+    /// and return this space. Either `UpdateSpace` or `PinPosts` permission is enough.
+    pub fn ensure_account_can_pin_post(
+        account: &T::AccountId,
+        post: &Post<T>,
+    ) -> Result<Space<T>, DispatchError> {
+        ensure!(!post.is_comment(), Error::<T>::CannotPinComment);
+        Self::ensure_post_not_deleted(post.id)?;
+        Self::ensure_post_is_published(post.id)?;
+
+        let space = post.get_space()?;
+        ensure!(
+            T::IsAccountBlocked::is_allowed_account(account.clone(), space.id),
+            ModerationError::AccountIsBlocked
+        );
+
+        let can_update_space = Spaces::ensure_account_has_space_permission(
+            account.clone(),
+            &space,
+            SpacePermission::UpdateSpace,
+            Error::<T>::NoPermissionToPinPosts.into(),
+        )
+        .is_ok();
+
+        if !can_update_space {
+            Spaces::ensure_account_has_space_permission(
+                account.clone(),
+                &space,
+                SpacePermission::PinPosts,
+                Error::<T>::NoPermissionToPinPosts.into(),
+            )?;
+        }
+
+        Ok(space)
+    }
+
+    /// Check a permission in the space of a given post, resolving permission overrides
+    /// of the post before the ones of the space. Comments inherit overrides of their root post.
+    pub fn ensure_account_has_post_permission(
+        account: T::AccountId,
+        post: &Post<T>,
+        space: &Space<T>,
+        permission: SpacePermission,
+        error: DispatchError,
+    ) -> DispatchResult {
+        let post_permissions = post.get_root_post()?.permissions;
+        if post_permissions.is_none() {
+            return Spaces::ensure_account_has_space_permission(account, space, permission, error)
+        }
+
+        let mut scope = space.clone();
+        scope.permissions = Permissions::<T>::merge_overrides(post_permissions, scope.permissions);
+
+        Spaces::ensure_account_has_space_permission(account, &scope, permission, error)
+    }
+
+    /// Check that an account can interact with a post: if its' root post is subscribers-only,
+    /// the account should be either the author of the root post or an active subscriber
+    /// of the space.
+    pub fn ensure_account_can_access_post(
+        account: &T::AccountId,
+        post: &Post<T>,
+        space: &Space<T>,
+        error: DispatchError,
+    ) -> DispatchResult {
+        let root_post = post.get_root_post()?;
+        if !root_post.subscribers_only || root_post.is_owner(account) {
+            return Ok(())
+        }
+
+        Spaces::ensure_account_is_active_subscriber(account, space, error)
+    }
+
     /// Check that there is a `Post` with such `post_id` in the storage
     /// or return`PostNotFound` error.
     pub fn ensure_post_exists(post_id: PostId) -> DispatchResult {
@@ -177,11 +352,41 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Get the details of a post by its' id.
+    ///
+    /// A post, that is not migrated to the current storage layout yet, is not returned.
+    pub fn post_by_id(post_id: PostId) -> Option<Post<T>> {
+        if migration::v6::is_post_pending::<T>(post_id) {
+            return None
+        }
+        PostById::<T>::get(post_id)
+    }
+
+    /// Fail with `MigrationInProgress` error, if a post or the lists that refer to it
+    /// are not migrated to the current storage layout yet.
+    pub fn ensure_post_migrated(post_id: PostId) -> DispatchResult {
+        ensure!(
+            !migration::v6::is_post_being_migrated::<T>(post_id),
+            Error::<T>::MigrationInProgress
+        );
+        Ok(())
+    }
+
     /// Get `Post` by id from the storage or return `PostNotFound` error.
     pub fn require_post(post_id: SpaceId) -> Result<Post<T>, DispatchError> {
         Ok(Self::post_by_id(post_id).ok_or(Error::<T>::PostNotFound)?)
     }
 
+    pub fn is_post_deleted(post_id: PostId) -> bool {
+        DeletedPostById::<T>::contains_key(post_id)
+    }
+
+    /// Fail with `PostIsDeleted` error, if a post was replaced with a tombstone.
+    pub fn ensure_post_not_deleted(post_id: PostId) -> DispatchResult {
+        ensure!(!Self::is_post_deleted(post_id), Error::<T>::PostIsDeleted);
+        Ok(())
+    }
+
     pub fn is_root_post_hidden(post_id: PostId) -> Result<bool, DispatchError> {
         let post = Self::require_post(post_id)?;
         let root_post = post.get_root_post()?;
@@ -208,19 +413,338 @@ impl<T: Config> Pallet<T> {
         })
     }
 
-    // TODO refactor to a tail recursion
-    /// Get all post ancestors (parent_id) including this post
-    pub fn get_post_ancestors(post_id: PostId) -> Vec<Post<T>> {
-        let mut ancestors: Vec<Post<T>> = Vec::new();
+    /// Keep the previous content of a post in its' bounded edit history.
+    /// Empty content is not kept.
+    pub(crate) fn add_content_to_history(post_id: PostId, previous_content: Content) {
+        if previous_content.is_none() {
+            return
+        }
+
+        ContentHistoryByPostId::<T>::mutate(post_id, |history| {
+            push_evicting_oldest(history, previous_content)
+        });
+    }
+
+    /// Schedule a regular post to be published, i.e. listed in its' space, at a given block.
+    pub(crate) fn schedule_post(post: &Post<T>, publish_at: T::BlockNumber) -> DispatchResult {
+        ensure!(post.is_regular_post(), Error::<T>::OnlyRegularPostsCanBeScheduled);
+        ensure!(
+            publish_at > frame_system::Pallet::<T>::block_number(),
+            Error::<T>::PublishAtBlockIsNotInFuture
+        );
+
+        ScheduledPostIdsByBlock::<T>::try_mutate(publish_at, |post_ids| {
+            post_ids.try_push(post.id).map_err(|_| Error::<T>::TooManyPostsScheduledAtBlock)
+        })?;
+        PublishAtByPostId::<T>::insert(post.id, publish_at);
+
+        if let Some(space_id) = post.space_id {
+            Self::add_scheduled_post_to_space(space_id, post.id);
+        }
+        Ok(())
+    }
+
+    /// Remove a post from the publication schedule, if it's scheduled.
+    pub(crate) fn unschedule_post(post: &Post<T>) {
+        if let Some(publish_at) = PublishAtByPostId::<T>::take(post.id) {
+            ScheduledPostIdsByBlock::<T>::mutate(publish_at, |post_ids| {
+                remove_from_bounded_vec(post_ids, post.id)
+            });
 
-        if let Some(post) = Self::post_by_id(post_id) {
-            ancestors.push(post.clone());
-            if let Some(parent_id) = post.get_comment_ext().ok().unwrap().parent_id {
-                ancestors.extend(Self::get_post_ancestors(parent_id).iter().cloned());
+            if let Some(space_id) = post.space_id {
+                Self::remove_scheduled_post_from_space(space_id, post.id);
             }
         }
+    }
+
+    /// Publish all the posts scheduled at a given block by listing them in their spaces.
+    pub(crate) fn publish_scheduled_posts(now: T::BlockNumber) -> Weight {
+        let post_ids = ScheduledPostIdsByBlock::<T>::take(now);
+        let posts_count = post_ids.len() as u32;
+
+        for post_id in post_ids {
+            PublishAtByPostId::<T>::remove(post_id);
+
+            if let Some(post) = Self::post_by_id(post_id) {
+                if let Some(space_id) = post.space_id {
+                    Self::remove_scheduled_post_from_space(space_id, post_id);
+                    Self::add_post_to_space(space_id, post_id);
+                }
+                Self::deposit_event(Event::PostPublished { account: post.owner, post_id });
+            }
+        }
+
+        <T as Config>::WeightInfo::publish_scheduled_posts(posts_count)
+    }
 
-        ancestors
+    /// Fail with `PostIsNotPublished` error, if a post is still scheduled for publication.
+    pub fn ensure_post_is_published(post_id: PostId) -> DispatchResult {
+        ensure!(!PublishAtByPostId::<T>::contains_key(post_id), Error::<T>::PostIsNotPublished);
+        Ok(())
+    }
+
+    pub(crate) fn add_post_to_space(space_id: SpaceId, post_id: PostId) {
+        if !PostIdsBySpaceId::<T>::contains_key(space_id, post_id) {
+            PostIdsBySpaceId::<T>::insert(space_id, post_id, ());
+            PostCountBySpaceId::<T>::mutate(space_id, |count| *count = count.saturating_add(1));
+        }
+    }
+
+    pub(crate) fn remove_post_from_space(space_id: SpaceId, post_id: PostId) {
+        if PostIdsBySpaceId::<T>::take(space_id, post_id).is_some() {
+            PostCountBySpaceId::<T>::mutate(space_id, |count| *count = count.saturating_sub(1));
+        }
+    }
+
+    pub(crate) fn add_scheduled_post_to_space(space_id: SpaceId, post_id: PostId) {
+        if !ScheduledPostIdsBySpaceId::<T>::contains_key(space_id, post_id) {
+            ScheduledPostIdsBySpaceId::<T>::insert(space_id, post_id, ());
+            ScheduledPostCountBySpaceId::<T>::mutate(space_id, |count| {
+                *count = count.saturating_add(1)
+            });
+        }
+    }
+
+    pub(crate) fn remove_scheduled_post_from_space(space_id: SpaceId, post_id: PostId) {
+        if ScheduledPostIdsBySpaceId::<T>::take(space_id, post_id).is_some() {
+            ScheduledPostCountBySpaceId::<T>::mutate(space_id, |count| {
+                *count = count.saturating_sub(1)
+            });
+        }
+    }
+
+    pub(crate) fn add_reply(parent_id: PostId, reply_id: PostId) {
+        if !ReplyIdsByPostId::<T>::contains_key(parent_id, reply_id) {
+            ReplyIdsByPostId::<T>::insert(parent_id, reply_id, ());
+            ReplyCountByPostId::<T>::mutate(parent_id, |count| *count = count.saturating_add(1));
+        }
+    }
+
+    pub(crate) fn remove_reply(parent_id: PostId, reply_id: PostId) {
+        if ReplyIdsByPostId::<T>::take(parent_id, reply_id).is_some() {
+            ReplyCountByPostId::<T>::mutate(parent_id, |count| *count = count.saturating_sub(1));
+        }
+    }
+
+    pub(crate) fn add_shared_post(original_post_id: PostId, shared_post_id: PostId) {
+        if !SharedPostIdsByOriginalPostId::<T>::contains_key(original_post_id, shared_post_id) {
+            SharedPostIdsByOriginalPostId::<T>::insert(original_post_id, shared_post_id, ());
+            SharedPostCountByOriginalPostId::<T>::mutate(original_post_id, |count| {
+                *count = count.saturating_add(1)
+            });
+        }
+    }
+
+    pub(crate) fn remove_shared_post(original_post_id: PostId, shared_post_id: PostId) {
+        if SharedPostIdsByOriginalPostId::<T>::take(original_post_id, shared_post_id).is_some() {
+            SharedPostCountByOriginalPostId::<T>::mutate(original_post_id, |count| {
+                *count = count.saturating_sub(1)
+            });
+        }
+    }
+
+    /// Get up to `limit` ids of root posts in a given space, starting after `start_after`.
+    /// Ids are not returned in the order they were added.
+    pub fn get_post_ids_by_space_id(
+        space_id: SpaceId,
+        start_after: Option<PostId>,
+        limit: u32,
+    ) -> Vec<PostId> {
+        let iter = match start_after {
+            Some(post_id) => PostIdsBySpaceId::<T>::iter_key_prefix_from(
+                space_id,
+                PostIdsBySpaceId::<T>::hashed_key_for(space_id, post_id),
+            ),
+            None => PostIdsBySpaceId::<T>::iter_key_prefix(space_id),
+        };
+        iter.take(limit as usize).collect()
+    }
+
+    /// Get up to `limit` ids of scheduled posts in a given space, starting after `start_after`.
+    /// Ids are not returned in the order they were scheduled.
+    pub fn get_scheduled_post_ids_by_space_id(
+        space_id: SpaceId,
+        start_after: Option<PostId>,
+        limit: u32,
+    ) -> Vec<PostId> {
+        let iter = match start_after {
+            Some(post_id) => ScheduledPostIdsBySpaceId::<T>::iter_key_prefix_from(
+                space_id,
+                ScheduledPostIdsBySpaceId::<T>::hashed_key_for(space_id, post_id),
+            ),
+            None => ScheduledPostIdsBySpaceId::<T>::iter_key_prefix(space_id),
+        };
+        iter.take(limit as usize).collect()
+    }
+
+    /// Get up to `limit` ids of direct replies to a given post, starting after `start_after`.
+    /// Ids are not returned in the order they were added.
+    pub fn get_reply_ids_by_post_id(
+        parent_id: PostId,
+        start_after: Option<PostId>,
+        limit: u32,
+    ) -> Vec<PostId> {
+        let iter = match start_after {
+            Some(reply_id) => ReplyIdsByPostId::<T>::iter_key_prefix_from(
+                parent_id,
+                ReplyIdsByPostId::<T>::hashed_key_for(parent_id, reply_id),
+            ),
+            None => ReplyIdsByPostId::<T>::iter_key_prefix(parent_id),
+        };
+        iter.take(limit as usize).collect()
+    }
+
+    /// Get up to `limit` ids of posts that shared a given post, starting after `start_after`.
+    /// Ids are not returned in the order they were added.
+    pub fn get_shared_post_ids_by_original_post_id(
+        original_post_id: PostId,
+        start_after: Option<PostId>,
+        limit: u32,
+    ) -> Vec<PostId> {
+        let iter = match start_after {
+            Some(shared_post_id) => SharedPostIdsByOriginalPostId::<T>::iter_key_prefix_from(
+                original_post_id,
+                SharedPostIdsByOriginalPostId::<T>::hashed_key_for(
+                    original_post_id,
+                    shared_post_id,
+                ),
+            ),
+            None => SharedPostIdsByOriginalPostId::<T>::iter_key_prefix(original_post_id),
+        };
+        iter.take(limit as usize).collect()
+    }
+
+    /// Unpin a post in a given space, if it's pinned there.
+    pub(crate) fn remove_from_pinned_posts(space_id: SpaceId, post_id: PostId) {
+        PinnedPostIdsBySpaceId::<T>::mutate(space_id, |pinned_ids| {
+            remove_from_bounded_vec(pinned_ids, post_id)
+        });
+    }
+
+    /// Adjust the deposit held for a given post to the encoded size of the post,
+    /// its' content history and its' poll, if it's a poll.
+    ///
+    /// The deposit stays with the account that has reserved it, no matter who edits the post.
+    /// A post that has no deposit yet (e.g. created before deposits were introduced)
+    /// gets it reserved from the post owner.
+    pub(crate) fn update_post_deposit(post: &Post<T>) -> DispatchResult {
+        let size = post
+            .encoded_size()
+            .saturating_add(Self::content_history_by_post_id(post.id).encoded_size())
+            .saturating_add(Self::poll_by_post_id(post.id).encoded_size());
+        let amount = T::PostByteDeposit::get().saturating_mul(BalanceOf::<T>::from(size as u32));
+
+        let old_deposit_opt = Self::deposit_by_post_id(post.id);
+        let depositor = old_deposit_opt
+            .as_ref()
+            .map_or_else(|| post.owner.clone(), |deposit| deposit.depositor.clone());
+
+        let deposit = update_storage_deposit::<_, <T as pallet_spaces::Config>::Currency>(
+            old_deposit_opt,
+            depositor,
+            amount,
+        )?;
+        DepositByPostId::<T>::insert(post.id, deposit);
+        Ok(())
+    }
+
+    /// Return the deposit held for a given post back to its depositor.
+    pub fn refund_post_deposit(post_id: PostId) {
+        if let Some(deposit) = DepositByPostId::<T>::take(post_id) {
+            refund_storage_deposit::<_, <T as pallet_spaces::Config>::Currency>(deposit);
+        }
+    }
+
+    /// The depth of a new comment: one level deeper than its' parent comment,
+    /// or 1 if it's a comment on a root post.
+    pub(crate) fn new_comment_depth(comment_ext: Comment) -> u32 {
+        comment_ext
+            .parent_id
+            .map_or(1, |parent_id| Self::comment_depth_by_post_id(parent_id).saturating_add(1))
+    }
+
+    /// Create a post of any kind, after checking that `creator` is allowed to create it.
+    pub(crate) fn do_create_post(
+        creator: T::AccountId,
+        space_id_opt: Option<SpaceId>,
+        extension: PostExtension,
+        content: Content,
+        publish_at: Option<T::BlockNumber>,
+    ) -> Result<PostId, DispatchError> {
+        ensure_content_is_valid::<T::MaxInlineContentLen>(content.clone())?;
+
+        let new_post_id = Self::next_post_id();
+        let new_post: Post<T> =
+            Post::new(new_post_id, creator.clone(), space_id_opt, extension, content.clone());
+
+        // Get space from either space_id_opt or Comment if a comment provided
+        let space = &new_post.get_space()?;
+        ensure!(!space.is_hidden_in_hierarchy(), Error::<T>::CannotCreateInHiddenScope);
+
+        ensure!(
+            T::IsAccountBlocked::is_allowed_account(creator.clone(), space.id),
+            ModerationError::AccountIsBlocked
+        );
+        ensure!(
+            T::IsContentBlocked::is_allowed_content(content, space.id),
+            ModerationError::ContentIsBlocked
+        );
+
+        let root_post = &mut new_post.get_root_post()?;
+        ensure!(!root_post.hidden, Error::<T>::CannotCreateInHiddenScope);
+        Self::ensure_post_not_deleted(root_post.id)?;
+        Self::ensure_post_is_published(root_post.id)?;
+
+        // Check whether account has permission to create Post (by extension)
+        if let PostExtension::Comment(_) = extension {
+            Self::ensure_account_has_post_permission(
+                creator.clone(),
+                root_post,
+                space,
+                SpacePermission::CreateComments,
+                Error::<T>::NoPermissionToCreateComments.into(),
+            )?;
+            Self::ensure_account_can_access_post(
+                &creator,
+                root_post,
+                space,
+                Error::<T>::OnlySubscribersCanComment.into(),
+            )?;
+        } else {
+            Spaces::ensure_account_has_space_permission(
+                creator.clone(),
+                space,
+                SpacePermission::CreatePosts,
+                Error::<T>::NoPermissionToCreatePosts.into(),
+            )?;
+        }
+
+        // A scheduled post gets listed in its' space only when it's published.
+        if let Some(publish_at) = publish_at {
+            Self::schedule_post(&new_post, publish_at)?;
+        }
+
+        match extension {
+            PostExtension::SharedPost(original_post_id) =>
+                Self::create_shared_post(&creator, new_post_id, original_post_id, space.id)?,
+            PostExtension::Comment(comment_ext) =>
+                Self::create_comment(new_post_id, comment_ext, root_post.id)?,
+            _ => (),
+        }
+
+        Self::update_post_deposit(&new_post)?;
+
+        if new_post.is_root_post() && publish_at.is_none() {
+            Self::add_post_to_space(space.id, new_post_id);
+        }
+
+        PostById::insert(new_post_id, new_post);
+        NextPostId::<T>::mutate(|n| {
+            *n += 1;
+        });
+
+        Self::deposit_event(Event::PostCreated { account: creator, post_id: new_post_id });
+        Ok(new_post_id)
     }
 
     pub(crate) fn create_comment(
@@ -233,42 +757,81 @@ impl<T: Config> Pallet<T> {
         if let Some(parent_id) = comment_ext.parent_id {
             let parent_comment =
                 Self::post_by_id(parent_id).ok_or(Error::<T>::UnknownParentComment)?;
+            // The depth of a parent comment may not be stored yet.
+            Self::ensure_post_migrated(parent_id)?;
 
             ensure!(parent_comment.is_comment(), Error::<T>::NotACommentByParentId);
-
-            let ancestors = Self::get_post_ancestors(parent_id);
-            ensure!(
-                ancestors.len() < T::MaxCommentDepth::get() as usize,
-                Error::<T>::MaxCommentDepthReached
-            );
+            Self::ensure_post_not_deleted(parent_id)?;
 
             commented_post_id = parent_id;
         }
 
-        ReplyIdsByPostId::<T>::mutate(commented_post_id, |reply_ids| reply_ids.push(new_post_id));
+        let depth = Self::new_comment_depth(comment_ext);
+        ensure!(depth <= T::MaxCommentDepth::get(), Error::<T>::MaxCommentDepthReached);
+
+        Self::add_reply(commented_post_id, new_post_id);
+        Self::mutate_comment_ancestors(comment_ext, Post::inc_replies);
+        CommentDepthByPostId::<T>::insert(new_post_id, depth);
 
         Ok(())
     }
 
+    /// Apply `f` to every ancestor of a comment: its' parent comments and the root post.
+    pub(crate) fn mutate_comment_ancestors(comment_ext: Comment, f: impl Fn(&mut Post<T>)) {
+        let mut parent_id_opt = comment_ext.parent_id;
+        while let Some(parent_id) = parent_id_opt {
+            parent_id_opt = PostById::<T>::mutate(parent_id, |post_opt| {
+                post_opt.as_mut().and_then(|post| {
+                    f(post);
+                    post.try_get_parent_id()
+                })
+            });
+        }
+
+        Self::mutate_post(comment_ext.root_post_id, f);
+    }
+
+    /// Apply `f` to a post, if it exists.
+    pub(crate) fn mutate_post(post_id: PostId, f: impl FnOnce(&mut Post<T>)) {
+        PostById::<T>::mutate(post_id, |post_opt| {
+            if let Some(post) = post_opt {
+                f(post)
+            }
+        });
+    }
+
     pub(crate) fn create_shared_post(
         creator: &T::AccountId,
         new_post_id: PostId,
         original_post_id: PostId,
+        space_id: SpaceId,
     ) -> DispatchResult {
         let original_post =
             &mut Self::post_by_id(original_post_id).ok_or(Error::<T>::OriginalPostNotFound)?;
 
         ensure!(!original_post.is_shared_post(), Error::<T>::CannotShareSharedPost);
+        Self::ensure_post_not_deleted(original_post_id)?;
+        Self::ensure_post_is_published(original_post_id)?;
+
+        // Check if the space of original post is not blocked in the space of a new post.
+        if let Some(original_space_id) = original_post.try_get_space_id() {
+            ensure!(
+                T::IsSpaceBlocked::is_allowed_space(original_space_id, space_id),
+                ModerationError::SpaceIsBlocked
+            );
+        }
 
         // Check if it's allowed to share a post from the space of original post.
-        Spaces::ensure_account_has_space_permission(
+        Self::ensure_account_has_post_permission(
             creator.clone(),
+            original_post,
             &original_post.get_space()?,
             SpacePermission::Share,
             Error::<T>::NoPermissionToShare.into(),
         )?;
 
-        SharedPostIdsByOriginalPostId::<T>::mutate(original_post_id, |ids| ids.push(new_post_id));
+        Self::add_shared_post(original_post_id, new_post_id);
+        Self::mutate_post(original_post_id, Post::inc_shares);
         Ok(())
     }
 
@@ -298,16 +861,21 @@ impl<T: Config> Pallet<T> {
             T::IsContentBlocked::is_allowed_content(post.content.clone(), new_space_id),
             ModerationError::ContentIsBlocked
         );
+        if let Some(old_space_id) = old_space_id_opt {
+            ensure!(
+                T::IsSpaceBlocked::is_allowed_space(old_space_id, new_space_id),
+                ModerationError::SpaceIsBlocked
+            );
+        }
 
         match post.extension {
-            PostExtension::RegularPost | PostExtension::SharedPost(_) => {
+            PostExtension::RegularPost | PostExtension::SharedPost(_) | PostExtension::Poll => {
                 if let Some(old_space_id) = old_space_id_opt {
-                    PostIdsBySpaceId::<T>::mutate(old_space_id, |post_ids| {
-                        remove_from_vec(post_ids, post.id)
-                    });
+                    Self::remove_post_from_space(old_space_id, post.id);
+                    Self::remove_from_pinned_posts(old_space_id, post.id);
                 }
 
-                PostIdsBySpaceId::<T>::mutate(new_space_id, |post_ids| post_ids.push(post.id));
+                Self::add_post_to_space(new_space_id, post.id);
 
                 post.space_id = Some(new_space_id);
                 PostById::<T>::insert(post.id, post);
@@ -318,6 +886,213 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Remove a post from all the indexes. A post that has replies or shares is replaced with
+    /// a tombstone, so that its threads don't break, otherwise it's removed from the storage.
+    pub(crate) fn do_delete_post(who: T::AccountId, mut post: Post<T>) -> DispatchResult {
+        let post_id = post.id;
+
+        Self::unschedule_post(&post);
+        Self::remove_poll(post_id);
+
+        if post.is_root_post() {
+            if let Some(space_id) = post.space_id {
+                Self::remove_post_from_space(space_id, post_id);
+                Self::remove_from_pinned_posts(space_id, post_id);
+            }
+        }
+
+        if let PostExtension::SharedPost(original_post_id) = post.extension {
+            Self::remove_shared_post(original_post_id, post_id);
+            Self::mutate_post(original_post_id, Post::dec_shares);
+        }
+
+        let has_replies = Self::reply_count_by_post_id(post_id) > 0;
+        let has_shares = Self::shared_post_count_by_original_post_id(post_id) > 0;
+
+        if has_replies || has_shares {
+            post.content = Content::None;
+            post.upvotes_count = 0;
+            post.downvotes_count = 0;
+            ContentHistoryByPostId::<T>::remove(post_id);
+
+            if DepositByPostId::<T>::contains_key(post_id) {
+                Self::update_post_deposit(&post)?;
+            }
+
+            PostById::<T>::insert(post_id, post);
+            DeletedPostById::<T>::insert(post_id, new_who_and_when::<T>(who));
+        } else {
+            if let PostExtension::Comment(ext) = post.extension {
+                let commented_post_id = ext.parent_id.unwrap_or(ext.root_post_id);
+                Self::remove_reply(commented_post_id, post_id);
+                Self::mutate_comment_ancestors(ext, Post::dec_replies);
+            }
+
+            PostById::<T>::remove(post_id);
+            ContentHistoryByPostId::<T>::remove(post_id);
+            CommentDepthByPostId::<T>::remove(post_id);
+            Self::refund_post_deposit(post_id);
+        }
+
+        Ok(())
+    }
+
+    /// Remove a post and its' indexes from the storage, without keeping a tombstone.
+    pub(crate) fn remove_post_from_storage(post: Post<T>) {
+        let post_id = post.id;
+
+        if post.is_root_post() {
+            if let Some(space_id) = post.space_id {
+                Self::remove_post_from_space(space_id, post_id);
+            }
+        }
+
+        match post.extension {
+            PostExtension::Comment(ext) => {
+                let commented_post_id = ext.parent_id.unwrap_or(ext.root_post_id);
+                Self::remove_reply(commented_post_id, post_id);
+                Self::mutate_comment_ancestors(ext, Post::dec_replies);
+            },
+            PostExtension::SharedPost(original_post_id) => {
+                Self::remove_shared_post(original_post_id, post_id);
+                Self::mutate_post(original_post_id, Post::dec_shares);
+            },
+            _ => (),
+        }
+
+        if let Some(space_id) = post.space_id {
+            Self::remove_from_pinned_posts(space_id, post_id);
+        }
+        Self::unschedule_post(&post);
+
+        PostById::<T>::remove(post_id);
+        DeletedPostById::<T>::remove(post_id);
+        ContentHistoryByPostId::<T>::remove(post_id);
+        CommentDepthByPostId::<T>::remove(post_id);
+        SharedPostCountByOriginalPostId::<T>::remove(post_id);
+        Self::remove_poll(post_id);
+        Self::refund_post_deposit(post_id);
+    }
+
+    /// Remove the options and results of a poll, if a given post is a poll.
+    /// The votes are kept until they're removed with `remove_poll_votes`,
+    /// as there can be too many of them to remove at once.
+    pub(crate) fn remove_poll(post_id: PostId) {
+        PollByPostId::<T>::remove(post_id);
+    }
+
+    /// Reserve a deposit from a voter for storing their vote in a given poll.
+    pub(crate) fn reserve_poll_vote_deposit(
+        post_id: PostId,
+        voter: T::AccountId,
+        option_index: u32,
+    ) -> DispatchResult {
+        let size = (post_id, &voter, option_index).encoded_size();
+        let amount = T::PostByteDeposit::get().saturating_mul(BalanceOf::<T>::from(size as u32));
+
+        let deposit = update_storage_deposit::<_, <T as pallet_spaces::Config>::Currency>(
+            None,
+            voter.clone(),
+            amount,
+        )?;
+        PollVoteDepositByAccount::<T>::insert(post_id, voter, deposit);
+        Ok(())
+    }
+
+    /// Remove up to `limit` votes of a removed poll and return their deposits to the voters.
+    pub(crate) fn do_remove_poll_votes(post_id: PostId, limit: u32) -> (u32, bool) {
+        let mut removed_count = 0;
+
+        let voters: Vec<_> =
+            PollVoteByAccount::<T>::iter_key_prefix(post_id).take(limit as usize).collect();
+        for voter in voters {
+            PollVoteByAccount::<T>::remove(post_id, &voter);
+            if let Some(deposit) = PollVoteDepositByAccount::<T>::take(post_id, &voter) {
+                refund_storage_deposit::<_, <T as pallet_spaces::Config>::Currency>(deposit);
+            }
+            removed_count += 1;
+        }
+
+        let completed = PollVoteByAccount::<T>::iter_key_prefix(post_id).next().is_none();
+        (removed_count, completed)
+    }
+
+    /// Remove up to `limit` posts from the thread of a given post, the deepest replies first,
+    /// so that no reply is left without its' parent, even if the thread is not removed at once.
+    /// Posts that shared a removed post are unlisted from its' shares before it's removed,
+    /// and each of them counts toward `limit` too.
+    ///
+    /// Returns the number of removed and unlisted posts and whether the whole thread was removed.
+    pub(crate) fn remove_post_thread(
+        post_id: PostId,
+        limit: u32,
+    ) -> Result<(u32, bool), DispatchError> {
+        // Replies and shares of older posts are not listed until the migration is finished.
+        ensure!(!migration::v6::is_migration_in_progress::<T>(), Error::<T>::MigrationInProgress);
+
+        let mut removed_count = 0;
+
+        while removed_count < limit {
+            let (parent_id_opt, leaf_id) = Self::find_deepest_reply(post_id);
+
+            // There can be many shares of a post, so they're unlisted one at a time.
+            if let Some(shared_post_id) =
+                SharedPostIdsByOriginalPostId::<T>::iter_key_prefix(leaf_id).next()
+            {
+                Self::remove_shared_post(leaf_id, shared_post_id);
+                removed_count += 1;
+                continue
+            }
+
+            match PostById::<T>::get(leaf_id) {
+                Some(leaf) => {
+                    T::BeforePostDeleted::before_post_deleted(leaf_id)?;
+                    Self::remove_post_from_storage(leaf);
+                },
+                // A reply that is already gone, only its' index is left.
+                None => match parent_id_opt {
+                    Some(parent_id) => Self::remove_reply(parent_id, leaf_id),
+                    None => return Ok((removed_count, true)),
+                },
+            }
+
+            removed_count += 1;
+            if leaf_id == post_id {
+                return Ok((removed_count, true))
+            }
+        }
+
+        Ok((removed_count, !PostById::<T>::contains_key(post_id)))
+    }
+
+    /// Follow the first reply at each level down from a given post, until there are no replies.
+    /// Returns the found post id and the id of its' parent, if it's not the given post itself.
+    fn find_deepest_reply(post_id: PostId) -> (Option<PostId>, PostId) {
+        let mut parent_id_opt = None;
+        let mut leaf_id = post_id;
+
+        while let Some(reply_id) = ReplyIdsByPostId::<T>::iter_key_prefix(leaf_id).next() {
+            parent_id_opt = Some(leaf_id);
+            leaf_id = reply_id;
+        }
+
+        (parent_id_opt, leaf_id)
+    }
+
+    /// The weight of finding the deepest reply `n` times, walking down one level more
+    /// than `MaxCommentDepth` each time.
+    pub(crate) fn find_deepest_reply_weight(n: u32) -> Weight {
+        let levels = u64::from(T::MaxCommentDepth::get()).saturating_add(1);
+        T::DbWeight::get().reads(levels.saturating_mul(n.into()))
+    }
+
+    /// The weight of updating all the ancestors of `n` comments, as if each of them
+    /// was at `MaxCommentDepth`. The benchmarks only cover comments on a root post.
+    pub(crate) fn comment_ancestors_weight(n: u32) -> Weight {
+        let ancestors = u64::from(T::MaxCommentDepth::get()).saturating_mul(n.into());
+        T::DbWeight::get().reads_writes(ancestors, ancestors)
+    }
+
     pub fn delete_post_from_space(post_id: PostId) -> DispatchResult {
         let mut post = Self::require_post(post_id)?;
 
@@ -327,7 +1102,8 @@ impl<T: Config> Pallet<T> {
             let space_id = post.get_space_id()?;
 
             post.space_id = None;
-            PostIdsBySpaceId::<T>::mutate(space_id, |post_ids| remove_from_vec(post_ids, post_id));
+            Self::remove_post_from_space(space_id, post_id);
+            Self::remove_from_pinned_posts(space_id, post_id);
         }
 
         PostById::insert(post.id, post);
@@ -335,3 +1111,13 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 }
+
+impl<T: Config> BeforeSpaceDeleted for Pallet<T> {
+    fn before_space_deleted(space_id: SpaceId) -> DispatchResult {
+        // Posts of a space are not counted until the migration is finished.
+        ensure!(!migration::v6::is_migration_in_progress::<T>(), Error::<T>::MigrationInProgress);
+        ensure!(Self::post_count_by_space_id(space_id) == 0, Error::<T>::SpaceHasPosts);
+        ensure!(Self::scheduled_post_count_by_space_id(space_id) == 0, Error::<T>::SpaceHasPosts);
+        Ok(())
+    }
+}
@@ -26,16 +26,22 @@ use serde::{Deserialize, Serialize};
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 
-use pallet_permissions::SpacePermission;
-use pallet_spaces::{types::Space, Pallet as Spaces};
+use pallet_permissions::{Pallet as Permissions, SpacePermission, SpacePermissions};
+use pallet_spaces::{
+    types::{BalanceOf, Space, StorageDepositOf},
+    Pallet as Spaces,
+};
 use subsocial_support::{
-    ensure_content_is_valid, new_who_and_when, remove_from_vec,
-    traits::{IsAccountBlocked, IsContentBlocked, IsPostBlocked},
+    ensure_content_is_valid, new_who_and_when, refund_storage_deposit,
+    traits::{
+        BeforePostDeleted, IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked,
+    },
     Content, ModerationError, PostId, SpaceId, WhoAndWhen, WhoAndWhenOf,
 };
 
 pub use pallet::*;
 pub mod functions;
+pub mod migration;
 
 pub mod types;
 pub use types::*;
@@ -54,6 +60,9 @@ pub mod pallet {
     use frame_support::{pallet_prelude::*, traits::IsType};
     use frame_system::pallet_prelude::*;
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
     #[pallet::config]
     pub trait Config:
         frame_system::Config
@@ -70,11 +79,35 @@ pub mod pallet {
 
         type IsPostBlocked: IsPostBlocked<PostId>;
 
+        /// Pallets that should clean up their data before a post is deleted.
+        type BeforePostDeleted: BeforePostDeleted;
+
+        /// Max number of previous contents of a post kept in its' edit history.
+        #[pallet::constant]
+        type MaxPostContentHistory: Get<u32>;
+
+        /// Max number of posts that can be pinned in a single space.
+        #[pallet::constant]
+        type MaxPinnedPostsPerSpace: Get<u32>;
+
+        /// Max number of posts that can be scheduled for publication at the same block.
+        #[pallet::constant]
+        type MaxScheduledPostsPerBlock: Get<u32>;
+
+        /// Max number of options in a poll.
+        #[pallet::constant]
+        type MaxPollOptions: Get<u32>;
+
+        /// The amount held on deposit per byte of an encoded post.
+        #[pallet::constant]
+        type PostByteDeposit: Get<BalanceOf<Self>>;
+
         type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -90,26 +123,119 @@ pub mod pallet {
 
     /// Get the details of a post by its' id.
     #[pallet::storage]
-    #[pallet::getter(fn post_by_id)]
     pub type PostById<T: Config> = StorageMap<_, Twox64Concat, PostId, Post<T>>;
 
-    /// Get the ids of all direct replies by their parent's post id.
+    /// Direct replies of a post, by the parent's post id and the reply id.
+    /// See `get_reply_ids_by_post_id` to page through them.
     #[pallet::storage]
-    #[pallet::getter(fn reply_ids_by_post_id)]
     pub type ReplyIdsByPostId<T: Config> =
-        StorageMap<_, Twox64Concat, PostId, Vec<PostId>, ValueQuery>;
+        StorageDoubleMap<_, Twox64Concat, PostId, Twox64Concat, PostId, ()>;
+
+    /// The number of direct replies of a post, by the parent's post id.
+    #[pallet::storage]
+    #[pallet::getter(fn reply_count_by_post_id)]
+    pub type ReplyCountByPostId<T: Config> = StorageMap<_, Twox64Concat, PostId, u32, ValueQuery>;
 
-    /// Get the ids of all posts in a given space, by the space's id.
+    /// The depth of a comment in its' thread, by the comment id. A comment on a root post
+    /// has depth 1, a reply to that comment has depth 2, and so on.
+    #[pallet::storage]
+    #[pallet::getter(fn comment_depth_by_post_id)]
+    pub type CommentDepthByPostId<T: Config> = StorageMap<_, Twox64Concat, PostId, u32, ValueQuery>;
+
+    /// Root posts listed in a space, by the space id and the post id.
+    /// See `get_post_ids_by_space_id` to page through them.
     #[pallet::storage]
-    #[pallet::getter(fn post_ids_by_space_id)]
     pub type PostIdsBySpaceId<T: Config> =
-        StorageMap<_, Twox64Concat, SpaceId, Vec<PostId>, ValueQuery>;
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Twox64Concat, PostId, ()>;
+
+    /// The number of root posts listed in a space, by the space id.
+    #[pallet::storage]
+    #[pallet::getter(fn post_count_by_space_id)]
+    pub type PostCountBySpaceId<T: Config> = StorageMap<_, Twox64Concat, SpaceId, u32, ValueQuery>;
 
-    /// Get the ids of all posts that have shared a given original post id.
+    /// Posts that have shared a given post, by the original post id and the shared post id.
+    /// See `get_shared_post_ids_by_original_post_id` to page through them.
     #[pallet::storage]
-    #[pallet::getter(fn shared_post_ids_by_original_post_id)]
     pub type SharedPostIdsByOriginalPostId<T: Config> =
-        StorageMap<_, Twox64Concat, PostId, Vec<PostId>, ValueQuery>;
+        StorageDoubleMap<_, Twox64Concat, PostId, Twox64Concat, PostId, ()>;
+
+    /// The number of posts that have shared a given post, by the original post id.
+    #[pallet::storage]
+    #[pallet::getter(fn shared_post_count_by_original_post_id)]
+    pub type SharedPostCountByOriginalPostId<T: Config> =
+        StorageMap<_, Twox64Concat, PostId, u32, ValueQuery>;
+
+    /// Get the options, closing block and results of a poll by the post id of the poll.
+    #[pallet::storage]
+    #[pallet::getter(fn poll_by_post_id)]
+    pub type PollByPostId<T: Config> = StorageMap<_, Twox64Concat, PostId, Poll<T>>;
+
+    /// Get the index of an option that an account voted for, by the post id of a poll
+    /// and the account.
+    #[pallet::storage]
+    #[pallet::getter(fn poll_vote_by_account)]
+    pub type PollVoteByAccount<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, PostId, Blake2_128Concat, T::AccountId, u32>;
+
+    /// The deposit held for storing a vote in a poll, by the post id of the poll and the voter.
+    #[pallet::storage]
+    #[pallet::getter(fn poll_vote_deposit_by_account)]
+    pub type PollVoteDepositByAccount<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PostId,
+        Blake2_128Concat,
+        T::AccountId,
+        StorageDepositOf<T>,
+    >;
+
+    /// Get the ids of posts pinned in a given space, in the order they were pinned.
+    #[pallet::storage]
+    #[pallet::getter(fn pinned_post_ids_by_space_id)]
+    pub type PinnedPostIdsBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, PinnedPostIds<T>, ValueQuery>;
+
+    /// Get the ids of posts that should be published at a given block.
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_post_ids_by_block)]
+    pub type ScheduledPostIdsByBlock<T: Config> =
+        StorageMap<_, Twox64Concat, T::BlockNumber, ScheduledPostIds<T>, ValueQuery>;
+
+    /// Get the block, at which a scheduled post should be published, by the post id.
+    /// A scheduled post is not listed in its' space until it's published.
+    #[pallet::storage]
+    #[pallet::getter(fn publish_at_by_post_id)]
+    pub type PublishAtByPostId<T: Config> = StorageMap<_, Twox64Concat, PostId, T::BlockNumber>;
+
+    /// Posts in a given space that are scheduled, but not published yet,
+    /// by the space id and the post id.
+    /// See `get_scheduled_post_ids_by_space_id` to page through them.
+    #[pallet::storage]
+    pub type ScheduledPostIdsBySpaceId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Twox64Concat, PostId, ()>;
+
+    /// The number of scheduled posts in a given space, by the space id.
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_post_count_by_space_id)]
+    pub type ScheduledPostCountBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, u32, ValueQuery>;
+
+    /// Who and when deleted a post that was kept as a tombstone,
+    /// because it has replies or shares.
+    #[pallet::storage]
+    #[pallet::getter(fn deleted_post_by_id)]
+    pub type DeletedPostById<T: Config> = StorageMap<_, Twox64Concat, PostId, WhoAndWhenOf<T>>;
+
+    /// Previous contents of a post, from the oldest to the latest one, by the post id.
+    #[pallet::storage]
+    #[pallet::getter(fn content_history_by_post_id)]
+    pub type ContentHistoryByPostId<T: Config> =
+        StorageMap<_, Twox64Concat, PostId, PostContentHistory<T>, ValueQuery>;
+
+    /// The deposit held for storing a post, by the post id.
+    #[pallet::storage]
+    #[pallet::getter(fn deposit_by_post_id)]
+    pub type DepositByPostId<T: Config> = StorageMap<_, Twox64Concat, PostId, StorageDepositOf<T>>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -128,6 +254,61 @@ pub mod pallet {
             from_space: Option<SpaceId>,
             to_space: Option<SpaceId>,
         },
+        PostDeleted {
+            account: T::AccountId,
+            post_id: PostId,
+        },
+        PostHidden {
+            account: T::AccountId,
+            post_id: PostId,
+        },
+        PostUnhidden {
+            account: T::AccountId,
+            post_id: PostId,
+        },
+        PostPinned {
+            account: T::AccountId,
+            space_id: SpaceId,
+            post_id: PostId,
+        },
+        PostUnpinned {
+            account: T::AccountId,
+            space_id: SpaceId,
+            post_id: PostId,
+        },
+        PostPublished {
+            account: T::AccountId,
+            post_id: PostId,
+        },
+        PostPublicationCancelled {
+            account: T::AccountId,
+            post_id: PostId,
+        },
+        /// Some of the replies in a thread were removed, but the thread is not removed yet.
+        PostThreadPartiallyRemoved {
+            post_id: PostId,
+            removed_count: u32,
+        },
+        /// A post was removed together with all of its' replies.
+        PostThreadRemoved {
+            post_id: PostId,
+            removed_count: u32,
+        },
+        PollVoted {
+            account: T::AccountId,
+            post_id: PostId,
+            option_index: u32,
+        },
+        /// Some of the votes of a removed poll were removed, but not all of them yet.
+        PollVotesPartiallyRemoved {
+            post_id: PostId,
+            removed_count: u32,
+        },
+        /// All the votes of a removed poll were removed.
+        PollVotesRemoved {
+            post_id: PostId,
+            removed_count: u32,
+        },
     }
 
     #[pallet::error]
@@ -147,6 +328,56 @@ pub mod pallet {
         NoRepliesOnPost,
         /// Cannot move a post to the same space.
         CannotMoveToSameSpace,
+        /// Cannot delete a space that still has posts, including scheduled ones.
+        /// Move or delete them first.
+        SpaceHasPosts,
+        /// This post was deleted, so it can be neither updated, moved nor replied to.
+        PostIsDeleted,
+
+        // Pin related errors:
+        /// Only root posts can be pinned in a space.
+        CannotPinComment,
+        /// This post is already pinned in its' space.
+        PostIsAlreadyPinned,
+        /// This post is not pinned in its' space.
+        PostIsNotPinned,
+        /// Cannot pin more posts in this space. Unpin some of them first.
+        TooManyPinnedPosts,
+
+        // Scheduling related errors:
+        /// Only regular posts can be scheduled for publication.
+        OnlyRegularPostsCanBeScheduled,
+        /// A post can be scheduled for publication only at a future block.
+        PublishAtBlockIsNotInFuture,
+        /// Cannot schedule more posts for publication at this block.
+        TooManyPostsScheduledAtBlock,
+        /// This post is not published yet, so it can be neither replied to, shared,
+        /// moved nor pinned.
+        PostIsNotPublished,
+        /// This post is not scheduled for publication.
+        PostIsNotScheduled,
+
+        // Poll related errors:
+        /// Polls should be created with `create_poll`, so that they have options.
+        CannotCreatePollWithoutOptions,
+        /// A poll should have at least two options.
+        NotEnoughPollOptions,
+        /// A poll cannot have more than `MaxPollOptions` options.
+        TooManyPollOptions,
+        /// A poll can be closed only at a future block.
+        PollClosingBlockIsNotInFuture,
+        /// This post's extension is not a `Poll`.
+        NotAPoll,
+        /// This poll is closed, so it doesn't accept votes anymore.
+        PollIsClosed,
+        /// There is no option with this index in the poll.
+        PollOptionNotFound,
+        /// An account can vote in a poll only once.
+        AccountAlreadyVoted,
+        /// Not allowed to vote in a poll when its' space or the poll itself is hidden.
+        CannotVoteInHiddenScope,
+        /// Votes can be removed only after their poll is removed together with its' post.
+        PollIsNotRemoved,
 
         // Share related errors:
         /// Cannot share, because the original post was not found.
@@ -183,10 +414,51 @@ pub mod pallet {
         NoPermissionToUpdateOwnPosts,
         /// A comment owner is not allowed to update their own comments in this space.
         NoPermissionToUpdateOwnComments,
+        /// User has no permission to delete any posts in this space.
+        NoPermissionToDeleteAnyPost,
+        /// A post owner is not allowed to delete their own posts in this space.
+        NoPermissionToDeleteOwnPosts,
+        /// A comment owner is not allowed to delete their own comments in this space.
+        NoPermissionToDeleteOwnComments,
+        /// User has no permission to hide any posts in this space.
+        NoPermissionToHideAnyPost,
+        /// A post owner is not allowed to hide their own posts in this space.
+        NoPermissionToHideOwnPosts,
+        /// User has no permission to hide any comments in this space.
+        NoPermissionToHideAnyComment,
+        /// A comment owner is not allowed to hide their own comments in this space.
+        NoPermissionToHideOwnComments,
+        /// User has no permission to override permissions of posts in this space.
+        NoPermissionToOverridePostPermissions,
+        /// Comments inherit permissions of their root post, so they can't be overridden.
+        CannotOverrideCommentPermissions,
+        /// User has no permission to pin or unpin posts in this space.
+        NoPermissionToPinPosts,
+        /// User has no permission to vote in polls in this space.
+        NoPermissionToVoteInPolls,
+
+        // Subscribers-only posts related errors:
+        /// Only active subscribers of a space can comment on its' subscribers-only posts.
+        OnlySubscribersCanComment,
+        /// Comments inherit the subscribers-only flag of their root post, so it can't be changed.
+        CannotChangeCommentSubscribersOnly,
+        /// Only a post in a space with a subscription plan can be made subscribers-only.
+        SpaceHasNoSubscriptionPlan,
 
         /// `force_create_post` failed, because this post already exists.
         /// Consider removing the post with `force_remove_post` first.
         PostAlreadyExists,
+        /// Posts are being migrated to a new storage layout, try again after it's finished.
+        MigrationInProgress,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let migration_weight =
+                migration::v6::migrate_posts::<T>(migration::MIGRATED_POSTS_PER_BLOCK);
+            Self::publish_scheduled_posts(now).saturating_add(migration_weight)
+        }
     }
 
     #[pallet::call]
@@ -195,8 +467,10 @@ pub mod pallet {
         #[pallet::weight(
             match extension {
                 PostExtension::RegularPost => <T as Config>::WeightInfo::create_post__regular(),
-                PostExtension::Comment(..) => <T as Config>::WeightInfo::create_post__comment(),
+                PostExtension::Comment(..) => <T as Config>::WeightInfo::create_post__comment()
+                    .saturating_add(Pallet::<T>::comment_ancestors_weight(1)),
                 PostExtension::SharedPost(..) => <T as Config>::WeightInfo::create_post__shared(),
+                PostExtension::Poll => <T as Config>::WeightInfo::create_post__regular(),
             }
         )]
         pub fn create_post(
@@ -204,65 +478,13 @@ pub mod pallet {
             space_id_opt: Option<SpaceId>,
             extension: PostExtension,
             content: Content,
+            publish_at: Option<T::BlockNumber>,
         ) -> DispatchResult {
             let creator = ensure_signed(origin)?;
 
-            ensure_content_is_valid(content.clone())?;
-
-            let new_post_id = Self::next_post_id();
-            let new_post: Post<T> =
-                Post::new(new_post_id, creator.clone(), space_id_opt, extension, content.clone());
-
-            // Get space from either space_id_opt or Comment if a comment provided
-            let space = &new_post.get_space()?;
-            ensure!(!space.hidden, Error::<T>::CannotCreateInHiddenScope);
-
-            ensure!(
-                T::IsAccountBlocked::is_allowed_account(creator.clone(), space.id),
-                ModerationError::AccountIsBlocked
-            );
-            ensure!(
-                T::IsContentBlocked::is_allowed_content(content, space.id),
-                ModerationError::ContentIsBlocked
-            );
-
-            let root_post = &mut new_post.get_root_post()?;
-            ensure!(!root_post.hidden, Error::<T>::CannotCreateInHiddenScope);
-
-            // Check whether account has permission to create Post (by extension)
-            let mut permission_to_check = SpacePermission::CreatePosts;
-            let mut error_on_permission_failed = Error::<T>::NoPermissionToCreatePosts;
-
-            if let PostExtension::Comment(_) = extension {
-                permission_to_check = SpacePermission::CreateComments;
-                error_on_permission_failed = Error::<T>::NoPermissionToCreateComments;
-            }
-
-            Spaces::ensure_account_has_space_permission(
-                creator.clone(),
-                space,
-                permission_to_check,
-                error_on_permission_failed.into(),
-            )?;
-
-            match extension {
-                PostExtension::SharedPost(original_post_id) =>
-                    Self::create_shared_post(&creator, new_post_id, original_post_id)?,
-                PostExtension::Comment(comment_ext) =>
-                    Self::create_comment(new_post_id, comment_ext, root_post.id)?,
-                _ => (),
-            }
-
-            if new_post.is_root_post() {
-                PostIdsBySpaceId::<T>::mutate(space.id, |ids| ids.push(new_post_id));
-            }
+            ensure!(extension != PostExtension::Poll, Error::<T>::CannotCreatePollWithoutOptions);
 
-            PostById::insert(new_post_id, new_post);
-            NextPostId::<T>::mutate(|n| {
-                *n += 1;
-            });
-
-            Self::deposit_event(Event::PostCreated { account: creator, post_id: new_post_id });
+            Self::do_create_post(creator, space_id_opt, extension, content, publish_at)?;
             Ok(())
         }
 
@@ -275,11 +497,16 @@ pub mod pallet {
         ) -> DispatchResult {
             let editor = ensure_signed(origin)?;
 
-            let has_updates = update.content.is_some() || update.hidden.is_some();
+            let has_updates = update.content.is_some() ||
+                update.hidden.is_some() ||
+                update.permissions.is_some() ||
+                update.subscribers_only.is_some();
 
             ensure!(has_updates, Error::<T>::NoUpdatesForPost);
 
             let mut post = Self::require_post(post_id)?;
+            Self::ensure_post_not_deleted(post_id)?;
+
             let space_opt = &post.try_get_space();
 
             if let Some(space) = space_opt {
@@ -287,14 +514,23 @@ pub mod pallet {
                     T::IsAccountBlocked::is_allowed_account(editor.clone(), space.id),
                     ModerationError::AccountIsBlocked
                 );
-                Self::ensure_account_can_update_post(&editor, &post, space)?;
+                // Hiding is checked separately, so that moderators can hide posts and comments
+                // without being able to update them.
+                if update.content.is_some() ||
+                    update.permissions.is_some() ||
+                    update.subscribers_only.is_some()
+                {
+                    Self::ensure_account_can_update_post(&editor, &post, space)?;
+                }
             }
 
             let mut is_update_applied = false;
+            let mut is_visibility_changed = false;
+            let mut previous_content_opt = None;
 
             if let Some(content) = update.content {
                 if content != post.content {
-                    ensure_content_is_valid(content.clone())?;
+                    ensure_content_is_valid::<T::MaxInlineContentLen>(content.clone())?;
 
                     if let Some(space) = space_opt {
                         ensure!(
@@ -303,7 +539,7 @@ pub mod pallet {
                         );
                     }
 
-                    post.content = content;
+                    previous_content_opt = Some(sp_std::mem::replace(&mut post.content, content));
                     post.edited = true;
                     is_update_applied = true;
                 }
@@ -311,15 +547,78 @@ pub mod pallet {
 
             if let Some(hidden) = update.hidden {
                 if hidden != post.hidden {
+                    if let Some(space) = space_opt {
+                        Self::ensure_account_can_hide_post(&editor, &post, space)?;
+                    }
+
                     post.hidden = hidden;
+                    is_visibility_changed = true;
+                }
+            }
+
+            if let Some(overrides_opt) = update.permissions {
+                if post.permissions != overrides_opt {
+                    ensure!(!post.is_comment(), Error::<T>::CannotOverrideCommentPermissions);
+
+                    let space = space_opt.as_ref().ok_or(Error::<T>::PostHasNoSpaceId)?;
+                    Spaces::ensure_account_has_space_permission(
+                        editor.clone(),
+                        space,
+                        SpacePermission::OverridePostPermissions,
+                        Error::<T>::NoPermissionToOverridePostPermissions.into(),
+                    )?;
+
+                    post.permissions =
+                        overrides_opt.map(|perms| Permissions::<T>::override_permissions(perms));
+                    is_update_applied = true;
+                }
+            }
+
+            if let Some(subscribers_only) = update.subscribers_only {
+                if subscribers_only != post.subscribers_only {
+                    ensure!(!post.is_comment(), Error::<T>::CannotChangeCommentSubscribersOnly);
+
+                    if subscribers_only {
+                        let space = space_opt.as_ref().ok_or(Error::<T>::PostHasNoSpaceId)?;
+                        ensure!(
+                            Spaces::<T>::subscription_plan_by_space_id(space.id).is_some(),
+                            Error::<T>::SpaceHasNoSubscriptionPlan
+                        );
+                    }
+
+                    post.subscribers_only = subscribers_only;
                     is_update_applied = true;
                 }
             }
 
             // Update this post only if at least one field should be updated:
+            if !is_update_applied && !is_visibility_changed {
+                return Ok(())
+            }
+
+            // Hiding doesn't change the size of a post, so its' deposit is left as is.
             if is_update_applied {
-                <PostById<T>>::insert(post.id, post);
-                Self::deposit_event(Event::PostUpdated { account: editor, post_id });
+                post.updated = Some(new_who_and_when::<T>(editor.clone()));
+
+                if let Some(previous_content) = previous_content_opt {
+                    Self::add_content_to_history(post_id, previous_content);
+                }
+
+                Self::update_post_deposit(&post)?;
+            }
+
+            let hidden = post.hidden;
+            <PostById<T>>::insert(post.id, post);
+
+            if is_update_applied {
+                Self::deposit_event(Event::PostUpdated { account: editor.clone(), post_id });
+            }
+            if is_visibility_changed {
+                if hidden {
+                    Self::deposit_event(Event::PostHidden { account: editor, post_id });
+                } else {
+                    Self::deposit_event(Event::PostUnhidden { account: editor, post_id });
+                }
             }
             Ok(())
         }
@@ -334,6 +633,9 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
 
             let post = &mut Self::require_post(post_id)?;
+            Self::ensure_post_migrated(post_id)?;
+            Self::ensure_post_not_deleted(post_id)?;
+            Self::ensure_post_is_published(post_id)?;
 
             ensure!(new_space_id != post.space_id, Error::<T>::CannotMoveToSameSpace);
 
@@ -362,7 +664,7 @@ pub mod pallet {
 
         #[pallet::call_index(3)]
         #[pallet::weight((
-            Weight::from_ref_time(50_000) + T::DbWeight::get().reads_writes(4, 3),
+            Weight::from_ref_time(50_000) + T::DbWeight::get().reads_writes(8, 6),
             DispatchClass::Operational,
             Pays::Yes,
         ))]
@@ -390,32 +692,36 @@ pub mod pallet {
                 id: post_id,
                 created: new_who_and_when,
                 edited: false,
+                updated: None,
                 owner: owner.clone(),
                 extension,
                 space_id: space_id_opt,
                 content,
                 hidden,
+                subscribers_only: false,
+                permissions: None,
+                replies_count: 0,
+                shares_count: 0,
                 upvotes_count,
                 downvotes_count,
             };
 
             if new_post.is_root_post() {
                 if let Some(space_id) = new_post.space_id {
-                    PostIdsBySpaceId::<T>::mutate(space_id, |ids| ids.push(post_id));
+                    Self::add_post_to_space(space_id, post_id);
                 }
             }
 
             match new_post.extension {
                 PostExtension::Comment(ext) => {
                     let commented_post_id = ext.parent_id.unwrap_or(ext.root_post_id);
-                    ReplyIdsByPostId::<T>::mutate(commented_post_id, |reply_ids| {
-                        reply_ids.push(post_id)
-                    });
+                    Self::add_reply(commented_post_id, post_id);
+                    Self::mutate_comment_ancestors(ext, Post::inc_replies);
+                    CommentDepthByPostId::<T>::insert(post_id, Self::new_comment_depth(ext));
                 },
                 PostExtension::SharedPost(original_post_id) => {
-                    SharedPostIdsByOriginalPostId::<T>::mutate(original_post_id, |ids| {
-                        ids.push(post_id)
-                    });
+                    Self::add_shared_post(original_post_id, post_id);
+                    Self::mutate_post(original_post_id, Post::inc_shares);
                 },
                 _ => (),
             }
@@ -428,7 +734,7 @@ pub mod pallet {
 
         #[pallet::call_index(4)]
         #[pallet::weight((
-            Weight::from_ref_time(10_000) + T::DbWeight::get().reads_writes(2, 3),
+            Weight::from_ref_time(10_000) + T::DbWeight::get().reads_writes(10, 14),
             DispatchClass::Operational,
             Pays::Yes,
         ))]
@@ -439,29 +745,8 @@ pub mod pallet {
             ensure_root(origin)?;
 
             if let Ok(old_post) = Self::require_post(post_id) {
-                if old_post.is_root_post() {
-                    if let Some(space_id) = old_post.space_id {
-                        PostIdsBySpaceId::<T>::mutate(space_id, |ids| {
-                            remove_from_vec(ids, post_id)
-                        });
-                    }
-                }
-
-                match old_post.extension {
-                    PostExtension::Comment(ext) => {
-                        let commented_post_id = ext.parent_id.unwrap_or(ext.root_post_id);
-                        ReplyIdsByPostId::<T>::mutate(commented_post_id, |reply_ids| {
-                            remove_from_vec(reply_ids, post_id)
-                        });
-                    },
-                    PostExtension::SharedPost(original_post_id) => {
-                        SharedPostIdsByOriginalPostId::<T>::mutate(original_post_id, |ids| {
-                            remove_from_vec(ids, post_id)
-                        });
-                    },
-                    _ => (),
-                }
-                PostById::<T>::remove(post_id);
+                T::BeforePostDeleted::before_post_deleted(post_id)?;
+                Self::remove_post_from_storage(old_post);
             }
 
             Ok(Pays::No.into())
@@ -481,5 +766,251 @@ pub mod pallet {
             NextPostId::<T>::put(post_id);
             Ok(Pays::No.into())
         }
+
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::delete_post()
+                .saturating_add(Pallet::<T>::comment_ancestors_weight(1))
+        )]
+        pub fn delete_post(origin: OriginFor<T>, post_id: PostId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let post = Self::require_post(post_id)?;
+            Self::ensure_post_migrated(post_id)?;
+            Self::ensure_post_not_deleted(post_id)?;
+
+            if let Some(space) = post.try_get_space() {
+                ensure!(
+                    T::IsAccountBlocked::is_allowed_account(who.clone(), space.id),
+                    ModerationError::AccountIsBlocked
+                );
+                Self::ensure_account_can_delete_post(&who, &post, &space)?;
+            } else {
+                post.ensure_owner(&who)?;
+            }
+
+            T::BeforePostDeleted::before_post_deleted(post_id)?;
+
+            Self::do_delete_post(who.clone(), post)?;
+
+            Self::deposit_event(Event::PostDeleted { account: who, post_id });
+            Ok(())
+        }
+
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::pin_post())]
+        pub fn pin_post(origin: OriginFor<T>, post_id: PostId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let post = Self::require_post(post_id)?;
+            let space = Self::ensure_account_can_pin_post(&who, &post)?;
+
+            let mut pinned_ids = Self::pinned_post_ids_by_space_id(space.id);
+            ensure!(!pinned_ids.contains(&post_id), Error::<T>::PostIsAlreadyPinned);
+            pinned_ids.try_push(post_id).map_err(|_| Error::<T>::TooManyPinnedPosts)?;
+            PinnedPostIdsBySpaceId::<T>::insert(space.id, pinned_ids);
+
+            Self::deposit_event(Event::PostPinned { account: who, space_id: space.id, post_id });
+            Ok(())
+        }
+
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::unpin_post())]
+        pub fn unpin_post(origin: OriginFor<T>, post_id: PostId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let post = Self::require_post(post_id)?;
+            let space = Self::ensure_account_can_pin_post(&who, &post)?;
+
+            ensure!(
+                Self::pinned_post_ids_by_space_id(space.id).contains(&post_id),
+                Error::<T>::PostIsNotPinned
+            );
+            Self::remove_from_pinned_posts(space.id, post_id);
+
+            Self::deposit_event(Event::PostUnpinned { account: who, space_id: space.id, post_id });
+            Ok(())
+        }
+
+        /// Cancel the publication of a scheduled post. The author of the post or the owner
+        /// of its' space can cancel it, and the post is removed, since it was never published.
+        ///
+        /// A scheduled post keeps its' space from being deleted, so the space owner
+        /// doesn't have to wait until it's published to delete the space.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_scheduled_post())]
+        pub fn cancel_scheduled_post(origin: OriginFor<T>, post_id: PostId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let post = Self::require_post(post_id)?;
+            ensure!(PublishAtByPostId::<T>::contains_key(post_id), Error::<T>::PostIsNotScheduled);
+            if !post.is_owner(&who) {
+                let space = post.get_space().map_err(|_| Error::<T>::NotAPostOwner)?;
+                ensure!(space.is_owner(&who), Error::<T>::NotAPostOwner);
+            }
+
+            T::BeforePostDeleted::before_post_deleted(post_id)?;
+
+            Self::do_delete_post(who.clone(), post)?;
+
+            Self::deposit_event(Event::PostPublicationCancelled { account: who, post_id });
+            Ok(())
+        }
+
+        /// Remove a post together with all of its' replies, the deepest replies first.
+        /// Posts that shared a removed post are kept, but unlisted from its' shares.
+        ///
+        /// At most `limit` posts are removed or unlisted in one call. If the thread is larger
+        /// than that, call this again with the same `post_id` to continue where the previous
+        /// call stopped.
+        #[pallet::call_index(10)]
+        #[pallet::weight((
+            <T as Config>::WeightInfo::force_remove_post_thread(*limit)
+                .saturating_add(Pallet::<T>::find_deepest_reply_weight(*limit))
+                .saturating_add(Pallet::<T>::comment_ancestors_weight(*limit)),
+            DispatchClass::Operational,
+            Pays::Yes,
+        ))]
+        pub fn force_remove_post_thread(
+            origin: OriginFor<T>,
+            post_id: PostId,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let (removed_count, completed) = Self::remove_post_thread(post_id, limit)?;
+
+            if completed {
+                Self::deposit_event(Event::PostThreadRemoved { post_id, removed_count });
+            } else {
+                Self::deposit_event(Event::PostThreadPartiallyRemoved { post_id, removed_count });
+            }
+
+            let actual_weight = <T as Config>::WeightInfo::force_remove_post_thread(removed_count)
+                .saturating_add(Self::find_deepest_reply_weight(removed_count))
+                .saturating_add(Self::comment_ancestors_weight(removed_count));
+            Ok((Some(actual_weight), Pays::No).into())
+        }
+
+        /// Create a poll in a given space. Accounts can vote for one of the `options`
+        /// until the `closes_at` block.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_poll(options.len() as u32))]
+        pub fn create_poll(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            content: Content,
+            options: Vec<Content>,
+            closes_at: T::BlockNumber,
+        ) -> DispatchResult {
+            let creator = ensure_signed(origin)?;
+
+            ensure!(options.len() >= 2, Error::<T>::NotEnoughPollOptions);
+            ensure!(
+                options.len() <= T::MaxPollOptions::get() as usize,
+                Error::<T>::TooManyPollOptions
+            );
+            ensure!(
+                closes_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::PollClosingBlockIsNotInFuture
+            );
+
+            let mut poll_options = Vec::with_capacity(options.len());
+            for content in options {
+                ensure_content_is_valid::<T::MaxInlineContentLen>(content.clone())?;
+                poll_options.push(PollOption { content, votes_count: 0 });
+            }
+            let options: PollOptions<T> =
+                poll_options.try_into().map_err(|_| Error::<T>::TooManyPollOptions)?;
+
+            let post_id =
+                Self::do_create_post(creator, Some(space_id), PostExtension::Poll, content, None)?;
+            PollByPostId::<T>::insert(post_id, Poll { options, closes_at });
+            Self::update_post_deposit(&Self::require_post(post_id)?)?;
+
+            Ok(())
+        }
+
+        /// Vote for an option of a poll by its' index. An account can vote in a poll only once.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::vote_in_poll())]
+        pub fn vote_in_poll(
+            origin: OriginFor<T>,
+            post_id: PostId,
+            option_index: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let post = Self::require_post(post_id)?;
+            Self::ensure_post_not_deleted(post_id)?;
+            let mut poll = Self::poll_by_post_id(post_id).ok_or(Error::<T>::NotAPoll)?;
+
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < poll.closes_at,
+                Error::<T>::PollIsClosed
+            );
+            ensure!(
+                !PollVoteByAccount::<T>::contains_key(post_id, &who),
+                Error::<T>::AccountAlreadyVoted
+            );
+
+            let space = post.get_space()?;
+            ensure!(
+                !space.is_hidden_in_hierarchy() && !post.hidden,
+                Error::<T>::CannotVoteInHiddenScope
+            );
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space.id),
+                ModerationError::AccountIsBlocked
+            );
+            Self::ensure_account_has_post_permission(
+                who.clone(),
+                &post,
+                &space,
+                SpacePermission::VoteInPolls,
+                Error::<T>::NoPermissionToVoteInPolls.into(),
+            )?;
+
+            let option = poll
+                .options
+                .get_mut(option_index as usize)
+                .ok_or(Error::<T>::PollOptionNotFound)?;
+            option.votes_count = option.votes_count.saturating_add(1);
+
+            Self::reserve_poll_vote_deposit(post_id, who.clone(), option_index)?;
+            PollByPostId::<T>::insert(post_id, poll);
+            PollVoteByAccount::<T>::insert(post_id, who.clone(), option_index);
+
+            Self::deposit_event(Event::PollVoted { account: who, post_id, option_index });
+            Ok(())
+        }
+
+        /// Remove the votes of a poll, that was removed together with its' post,
+        /// and return their deposits to the voters. Anyone can call this dispatch.
+        ///
+        /// At most `limit` votes are removed in one call. If there are more of them,
+        /// call this again with the same `post_id` to continue where the previous call stopped.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_poll_votes(*limit))]
+        pub fn remove_poll_votes(
+            origin: OriginFor<T>,
+            post_id: PostId,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            ensure!(!PollByPostId::<T>::contains_key(post_id), Error::<T>::PollIsNotRemoved);
+
+            let (removed_count, completed) = Self::do_remove_poll_votes(post_id, limit);
+
+            if completed {
+                Self::deposit_event(Event::PollVotesRemoved { post_id, removed_count });
+            } else {
+                Self::deposit_event(Event::PollVotesPartiallyRemoved { post_id, removed_count });
+            }
+
+            let actual_weight = <T as Config>::WeightInfo::remove_poll_votes(removed_count);
+            Ok(Some(actual_weight).into())
+        }
     }
 }
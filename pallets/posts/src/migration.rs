@@ -0,0 +1,417 @@
+use frame_support::{
+    pallet_prelude::*, storage::StorageMap as StorageMapT, storage_alias, traits::OnRuntimeUpgrade,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use pallet_spaces::{types::FIRST_SPACE_ID, Pallet as Spaces};
+use subsocial_support::{Content, PostId, SpaceId, WhoAndWhenOf};
+
+use crate::{
+    CommentDepthByPostId, Config, Pallet, Post, PostById, PostExtension,
+    SharedPostCountByOriginalPostId, FIRST_POST_ID,
+};
+
+/// Max number of post ids looked up by the migration in one block.
+pub const MIGRATED_POSTS_PER_BLOCK: u32 = 200;
+
+/// Migrates posts from storage version 0 to the current layout:
+/// - adds optional permission overrides, an optional `updated` record, the `subscribers_only` flag
+///   and the replies and shares counters to every post, and reserves a storage deposit for it from
+///   the post owner;
+/// - moves the lists of post ids by space, replies by post and shares by post into double maps, and
+///   counts their entries;
+/// - stores the depth of every comment, then counts the replies and shares of every post.
+///
+/// Storage versions 1 to 5 were never released, so posts are migrated from version 0 only.
+/// Posts are migrated in bounded steps across several blocks by `migrate_posts`,
+/// which is called from `on_initialize`, until the storage version is set to 6.
+/// A post, that existed before the migration has started, can not be found until it's finished.
+pub mod v6 {
+    use super::*;
+
+    /// A post as it's stored at storage version 0.
+    #[derive(Encode, Decode)]
+    pub struct OldPost<T: Config> {
+        pub id: PostId,
+        pub created: WhoAndWhenOf<T>,
+        pub edited: bool,
+        pub owner: T::AccountId,
+        pub extension: PostExtension,
+        pub space_id: Option<SpaceId>,
+        pub content: Content,
+        pub hidden: bool,
+        pub upvotes_count: u32,
+        pub downvotes_count: u32,
+    }
+
+    #[storage_alias]
+    pub type OldPostById<T: Config> = StorageMap<Pallet<T>, Twox64Concat, PostId, OldPost<T>>;
+
+    // The old lists share their storage prefixes with the new double maps,
+    // so they're only ever taken out by their keys.
+
+    #[storage_alias]
+    pub type OldPostIdsBySpaceId<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, SpaceId, Vec<PostId>>;
+
+    #[storage_alias]
+    pub type OldReplyIdsByPostId<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, PostId, Vec<PostId>>;
+
+    #[storage_alias]
+    pub type OldSharedPostIdsByOriginalPostId<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, PostId, Vec<PostId>>;
+
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum MigrationStage {
+        /// Translating posts to the current layout, starting from a given post id.
+        TranslatePosts(PostId),
+        /// Moving the old lists of post ids by space into a double map,
+        /// starting from a given space id.
+        MovePostIdsBySpace(SpaceId),
+        /// Moving the old lists of replies by post into a double map,
+        /// starting from a given post id.
+        MoveReplyIds(PostId),
+        /// Moving the old lists of shares by post into a double map,
+        /// starting from a given post id.
+        MoveSharedPostIds(PostId),
+        /// Storing the depth of every comment, starting from a given post id.
+        StoreCommentDepths(PostId),
+        /// Counting the replies and shares of every post, starting from a given post id.
+        CountRepliesAndShares(PostId),
+    }
+
+    /// The current stage of the migration and the id of the first post created after
+    /// the migration has started. Such posts are already stored in the current layout.
+    #[storage_alias]
+    pub type MigrationCursor<T: Config> = StorageValue<Pallet<T>, (MigrationStage, PostId)>;
+
+    pub struct MigrateToV6<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+            if onchain_version >= 6 || MigrationCursor::<T>::exists() {
+                return T::DbWeight::get().reads(2)
+            }
+
+            MigrationCursor::<T>::put((
+                MigrationStage::TranslatePosts(FIRST_POST_ID),
+                Pallet::<T>::next_post_id(),
+            ));
+            T::DbWeight::get().reads_writes(3, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let post_count = PostById::<T>::iter_keys().count() as u64;
+            Ok(post_count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let post_count: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "Cannot decode the post count")?;
+
+            // Run the remaining steps at once, to check the migrated posts right away.
+            while MigrationCursor::<T>::exists() {
+                migrate_posts::<T>(MIGRATED_POSTS_PER_BLOCK);
+            }
+
+            ensure!(Pallet::<T>::on_chain_storage_version() >= 6, "Storage version is not 6");
+            ensure!(
+                PostById::<T>::iter_values().count() as u64 == post_count,
+                "Some posts were not migrated"
+            );
+            Ok(())
+        }
+    }
+
+    /// Whether the migration has started, but not finished yet.
+    pub fn is_migration_in_progress<T: Config>() -> bool {
+        MigrationCursor::<T>::exists()
+    }
+
+    /// Whether a post with a given id is not translated to the current storage layout yet.
+    pub fn is_post_pending<T: Config>(post_id: PostId) -> bool {
+        matches!(
+            MigrationCursor::<T>::get(),
+            Some((MigrationStage::TranslatePosts(next_id), end_id))
+                if next_id <= post_id && post_id < end_id
+        )
+    }
+
+    /// Whether a post with a given id existed before the migration has started,
+    /// while the migration is not finished yet. The replies, shares and comment depth
+    /// of such a post may not be moved to the current storage layout yet.
+    pub fn is_post_being_migrated<T: Config>(post_id: PostId) -> bool {
+        MigrationCursor::<T>::get().map_or(false, |(_, end_id)| post_id < end_id)
+    }
+
+    /// Run the next step of the migration, that looks up at most `limit` post ids
+    /// where it's done in bounded steps, continuing where the previous call stopped.
+    /// Set the storage version to 6 once the last stage is finished.
+    pub fn migrate_posts<T: Config>(limit: u32) -> Weight {
+        let (stage, end_id) = match MigrationCursor::<T>::get() {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+
+        let (next_stage_opt, weight) = match stage {
+            MigrationStage::TranslatePosts(next_id) => translate_posts::<T>(next_id, end_id, limit),
+            MigrationStage::MovePostIdsBySpace(next_id) => {
+                let end_space_id = Spaces::<T>::next_space_id();
+                let (next_id, weight) = move_old_lists::<T, OldPostIdsBySpaceId<T>>(
+                    next_id,
+                    end_space_id,
+                    limit,
+                    Pallet::<T>::add_post_to_space,
+                );
+                let next_stage = if next_id < end_space_id {
+                    MigrationStage::MovePostIdsBySpace(next_id)
+                } else {
+                    MigrationStage::MoveReplyIds(FIRST_POST_ID)
+                };
+                (Some(next_stage), weight.saturating_add(T::DbWeight::get().reads(1)))
+            },
+            MigrationStage::MoveReplyIds(next_id) => {
+                let (next_id, weight) = move_old_lists::<T, OldReplyIdsByPostId<T>>(
+                    next_id,
+                    end_id,
+                    limit,
+                    Pallet::<T>::add_reply,
+                );
+                let next_stage = if next_id < end_id {
+                    MigrationStage::MoveReplyIds(next_id)
+                } else {
+                    MigrationStage::MoveSharedPostIds(FIRST_POST_ID)
+                };
+                (Some(next_stage), weight)
+            },
+            MigrationStage::MoveSharedPostIds(next_id) => {
+                let (next_id, weight) = move_old_lists::<T, OldSharedPostIdsByOriginalPostId<T>>(
+                    next_id,
+                    end_id,
+                    limit,
+                    Pallet::<T>::add_shared_post,
+                );
+                let next_stage = if next_id < end_id {
+                    MigrationStage::MoveSharedPostIds(next_id)
+                } else {
+                    MigrationStage::StoreCommentDepths(FIRST_POST_ID)
+                };
+                (Some(next_stage), weight)
+            },
+            MigrationStage::StoreCommentDepths(next_id) =>
+                store_comment_depths::<T>(next_id, end_id, limit),
+            MigrationStage::CountRepliesAndShares(next_id) =>
+                count_replies_and_shares::<T>(next_id, end_id, limit),
+        };
+
+        match next_stage_opt {
+            Some(next_stage) => MigrationCursor::<T>::put((next_stage, end_id)),
+            None => {
+                MigrationCursor::<T>::kill();
+                StorageVersion::new(6).put::<Pallet<T>>();
+            },
+        }
+
+        weight.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+    }
+
+    fn translate_posts<T: Config>(
+        mut next_id: PostId,
+        end_id: PostId,
+        limit: u32,
+    ) -> (Option<MigrationStage>, Weight) {
+        let mut translated = 0u64;
+        let mut looked_up = 0u32;
+
+        while next_id < end_id && looked_up < limit {
+            if let Some(old) = OldPostById::<T>::get(next_id) {
+                let post = Post {
+                    id: old.id,
+                    created: old.created,
+                    edited: old.edited,
+                    updated: None,
+                    owner: old.owner,
+                    extension: old.extension,
+                    space_id: old.space_id,
+                    content: old.content,
+                    hidden: old.hidden,
+                    subscribers_only: false,
+                    permissions: None,
+                    replies_count: 0,
+                    shares_count: 0,
+                    upvotes_count: old.upvotes_count,
+                    downvotes_count: old.downvotes_count,
+                };
+
+                // An owner, that can't afford the deposit, keeps the post without it,
+                // until the deposit is reserved on the next update of the post.
+                translated += 1;
+                let _ = Pallet::<T>::update_post_deposit(&post);
+                PostById::<T>::insert(next_id, post);
+            }
+            next_id = next_id.saturating_add(1);
+            looked_up += 1;
+        }
+
+        let next_stage = if next_id < end_id {
+            MigrationStage::TranslatePosts(next_id)
+        } else {
+            MigrationStage::MovePostIdsBySpace(FIRST_SPACE_ID)
+        };
+        // A translated post is stored together with its' deposit and the owner's account.
+        (
+            Some(next_stage),
+            T::DbWeight::get().reads_writes(
+                u64::from(looked_up).saturating_add(translated.saturating_mul(4)),
+                translated.saturating_mul(3),
+            ),
+        )
+    }
+
+    /// Move the ids from the old lists with keys from `next_key` up to `end_key` into
+    /// a double map with `add`. At most `limit` lists are looked up and ids are moved
+    /// in total, so a long list may be moved in parts.
+    ///
+    /// Returns the key of the next list to move.
+    fn move_old_lists<T, OldList>(
+        mut next_key: u64,
+        end_key: u64,
+        limit: u32,
+        add: impl Fn(u64, PostId),
+    ) -> (u64, Weight)
+    where
+        T: Config,
+        OldList: StorageMapT<u64, Vec<PostId>, Query = Option<Vec<PostId>>>,
+    {
+        let mut budget = limit;
+        let mut reads = 0u64;
+        let mut writes = 0u64;
+
+        while next_key < end_key && budget > 0 {
+            budget -= 1;
+            reads += 1;
+
+            let mut ids = match OldList::get(next_key) {
+                Some(ids) => ids,
+                None => {
+                    next_key = next_key.saturating_add(1);
+                    continue
+                },
+            };
+
+            let completed = ids.len() <= budget as usize;
+            if completed {
+                OldList::remove(next_key);
+            } else if budget > 0 {
+                let rest = ids.split_off(budget as usize);
+                OldList::insert(next_key, rest);
+            } else {
+                break
+            }
+            writes += 1;
+
+            budget = budget.saturating_sub(ids.len() as u32);
+            for id in ids {
+                reads += 2;
+                writes += 2;
+                add(next_key, id);
+            }
+
+            if completed {
+                next_key = next_key.saturating_add(1);
+            }
+        }
+
+        (next_key, T::DbWeight::get().reads_writes(reads, writes))
+    }
+
+    /// Store the depths of comments with up to `limit` next ids, starting from `next_id`.
+    fn store_comment_depths<T: Config>(
+        mut next_id: PostId,
+        end_id: PostId,
+        limit: u32,
+    ) -> (Option<MigrationStage>, Weight) {
+        let mut reads = 0u64;
+        let mut writes = 0u64;
+        let mut looked_up = 0u32;
+
+        // A parent comment always has a smaller id than its' replies, so its' depth is
+        // already stored by this or a previous step, when the depth of a reply is looked up.
+        while next_id < end_id && looked_up < limit {
+            reads += 1;
+            if let Some(PostExtension::Comment(comment_ext)) =
+                PostById::<T>::get(next_id).map(|post| post.extension)
+            {
+                reads += 1;
+                writes += 1;
+                CommentDepthByPostId::<T>::insert(
+                    next_id,
+                    Pallet::<T>::new_comment_depth(comment_ext),
+                );
+            }
+            next_id = next_id.saturating_add(1);
+            looked_up += 1;
+        }
+
+        let next_stage = if next_id < end_id {
+            MigrationStage::StoreCommentDepths(next_id)
+        } else {
+            MigrationStage::CountRepliesAndShares(FIRST_POST_ID)
+        };
+        (Some(next_stage), T::DbWeight::get().reads_writes(reads, writes))
+    }
+
+    /// Count the replies and shares of posts with ids from `next_id`. At most `limit` posts
+    /// are looked up and ancestors of comments are updated in total, but the ancestors
+    /// of a comment are always updated at once.
+    fn count_replies_and_shares<T: Config>(
+        mut next_id: PostId,
+        end_id: PostId,
+        limit: u32,
+    ) -> (Option<MigrationStage>, Weight) {
+        let mut budget = limit;
+        let mut reads = 0u64;
+        let mut writes = 0u64;
+
+        while next_id < end_id && budget > 0 {
+            let post_id = next_id;
+            next_id = next_id.saturating_add(1);
+            budget -= 1;
+
+            reads += 1;
+            let post = match PostById::<T>::get(post_id) {
+                Some(post) => post,
+                None => continue,
+            };
+
+            reads += 1;
+            let shares_count = SharedPostCountByOriginalPostId::<T>::get(post_id);
+            if shares_count > 0 {
+                writes += 1;
+                Pallet::<T>::mutate_post(post_id, |post| post.shares_count = shares_count);
+            }
+
+            // Every comment is counted by each of its' ancestors up to the root post.
+            if let PostExtension::Comment(comment_ext) = post.extension {
+                // The depth of a comment is the number of its' ancestors.
+                let ancestors_count = CommentDepthByPostId::<T>::get(post_id);
+                budget = budget.saturating_sub(ancestors_count);
+                reads += u64::from(ancestors_count).saturating_add(1);
+                writes += u64::from(ancestors_count);
+                Pallet::<T>::mutate_comment_ancestors(comment_ext, Post::inc_replies);
+            }
+        }
+
+        let next_stage_opt = if next_id < end_id {
+            Some(MigrationStage::CountRepliesAndShares(next_id))
+        } else {
+            None
+        };
+        (next_stage_opt, T::DbWeight::get().reads_writes(reads, writes))
+    }
+}
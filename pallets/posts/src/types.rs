@@ -1,7 +1,17 @@
+use frame_support::BoundedVec;
+
 use super::*;
 
 pub const FIRST_POST_ID: u64 = 1;
 
+pub(crate) type PostContentHistory<T> = BoundedVec<Content, <T as Config>::MaxPostContentHistory>;
+
+pub(crate) type PinnedPostIds<T> = BoundedVec<PostId, <T as Config>::MaxPinnedPostsPerSpace>;
+
+pub(crate) type ScheduledPostIds<T> = BoundedVec<PostId, <T as Config>::MaxScheduledPostsPerBlock>;
+
+pub(crate) type PollOptions<T> = BoundedVec<PollOption, <T as Config>::MaxPollOptions>;
+
 /// Information about a post's owner, its' related space, content, and visibility.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -12,6 +22,8 @@ pub struct Post<T: Config> {
     pub created: WhoAndWhenOf<T>,
     /// True, if the content of this post was edited.
     pub edited: bool,
+    /// Who and when updated this post last time.
+    pub updated: Option<WhoAndWhenOf<T>>,
 
     /// The current owner of a given post.
     pub owner: T::AccountId,
@@ -29,6 +41,19 @@ pub struct Post<T: Config> {
     /// posts and its' comments should not be shown.
     pub hidden: bool,
 
+    /// If true, only active subscribers of the space can comment on and react to this post
+    /// and its' comments.
+    pub subscribers_only: bool,
+
+    /// This allows you to override the permissions of a space for this post and its' comments.
+    pub permissions: Option<SpacePermissions>,
+
+    /// The number of comments on a given post, including replies to its' comments at any depth.
+    pub replies_count: u32,
+
+    /// The number of times a given post has been shared.
+    pub shares_count: u32,
+
     /// The number of times a given post has been upvoted.
     pub upvotes_count: u32,
 
@@ -44,6 +69,8 @@ pub struct PostUpdate {
 
     pub content: Option<Content>,
     pub hidden: Option<bool>,
+    pub permissions: Option<Option<SpacePermissions>>,
+    pub subscribers_only: Option<bool>,
 }
 
 /// Post extension provides specific information necessary for different kinds
@@ -55,6 +82,7 @@ pub enum PostExtension {
     RegularPost,
     Comment(Comment),
     SharedPost(PostId),
+    Poll,
 }
 
 #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -64,6 +92,25 @@ pub struct Comment {
     pub parent_id: Option<PostId>,
 }
 
+/// The options, closing block and results of a poll, stored alongside a post
+/// with a `Poll` extension.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct Poll<T: Config> {
+    pub options: PollOptions<T>,
+
+    /// Votes are accepted until this block, exclusively.
+    pub closes_at: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PollOption {
+    pub content: Content,
+
+    /// The number of accounts that voted for this option.
+    pub votes_count: u32,
+}
+
 impl Default for PostExtension {
     fn default() -> Self {
         PostExtension::RegularPost
@@ -1,34 +1,10 @@
 
-//! Autogenerated weights for pallet_posts
+//! Weights for pallet_posts
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-02-14, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-    // ./scripts/../target/release/subsocial-collator
-    // benchmark
-    // pallet
-    // --chain
-    // dev
-    // --execution
-    // wasm
-    // --wasm-execution
-    // Compiled
-    // --pallet
-    // pallet_posts
-    // --extrinsic
-    // *
-    // --steps
-    // 50
-    // --repeat
-    // 20
-    // --heap-pages
-    // 4096
-    // --output
-    // pallets/posts/src/weights.rs
-    // --template
-    // ./.maintain/weight-template.hbs
+//! These weights started as the output of the benchmark CLI, but the extrinsics and their storage
+//! access have changed since then, and the weights were adjusted by hand. They are estimates and
+//! should be replaced by the output of `benchmark pallet --pallet pallet_posts` once the benchmarks
+//! are run on reference hardware.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -45,6 +21,15 @@ pub trait WeightInfo {
     fn create_post__comment() -> Weight;
     fn update_post() -> Weight;
     fn move_post() -> Weight;
+    fn delete_post() -> Weight;
+    fn pin_post() -> Weight;
+    fn unpin_post() -> Weight;
+    fn cancel_scheduled_post() -> Weight;
+    fn publish_scheduled_posts(n: u32, ) -> Weight;
+    fn force_remove_post_thread(n: u32, ) -> Weight;
+    fn create_poll(n: u32, ) -> Weight;
+    fn vote_in_poll() -> Weight;
+    fn remove_poll_votes(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_posts using the Substrate node and recommended hardware.
@@ -56,55 +41,236 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
             // Storage: Posts PostIdsBySpaceId (r:1 w:1)
             // Storage: Posts PostById (r:0 w:1)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:0 w:1)
+            // Storage: Posts ScheduledPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:1)
+            // Storage: Posts ScheduledPostCountBySpaceId (r:1 w:1)
         fn create_post__regular() -> Weight {
         // Minimum execution time: 30_000 nanoseconds.
         Weight::from_ref_time(47_502_000)
-            .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(10))
         }
             // Storage: Posts NextPostId (r:1 w:1)
             // Storage: Timestamp Now (r:1 w:0)
             // Storage: Spaces SpaceById (r:1 w:0)
             // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
-            // Storage: Posts PostById (r:1 w:1)
+            // Storage: Posts PostById (r:1 w:2)
             // Storage: Posts SharedPostIdsByOriginalPostId (r:1 w:1)
             // Storage: Posts PostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Posts SharedPostCountByOriginalPostId (r:1 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:1)
         fn create_post__shared() -> Weight {
         // Minimum execution time: 39_000 nanoseconds.
         Weight::from_ref_time(62_353_000)
-            .saturating_add(T::DbWeight::get().reads(7))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(12))
+            .saturating_add(T::DbWeight::get().writes(9))
         }
             // Storage: Posts NextPostId (r:1 w:1)
             // Storage: Timestamp Now (r:1 w:0)
-            // Storage: Posts PostById (r:2 w:1)
+            // Storage: Posts PostById (r:2 w:2)
             // Storage: Spaces SpaceById (r:1 w:0)
             // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
             // Storage: Posts ReplyIdsByPostId (r:1 w:1)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Posts ReplyCountByPostId (r:1 w:1)
+            // Storage: Spaces SubscriptionExpiresAtByAccount (r:1 w:0)
+            // Storage: Posts CommentDepthByPostId (r:1 w:1)
         fn create_post__comment() -> Weight {
         // Minimum execution time: 39_000 nanoseconds.
         Weight::from_ref_time(59_771_000)
-            .saturating_add(T::DbWeight::get().reads(7))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(13))
+            .saturating_add(T::DbWeight::get().writes(8))
         }
             // Storage: Posts PostById (r:2 w:1)
             // Storage: Spaces SpaceById (r:1 w:0)
             // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts ContentHistoryByPostId (r:1 w:1)
+            // Storage: Spaces SubscriptionPlanBySpaceId (r:1 w:0)
         fn update_post() -> Weight {
         // Minimum execution time: 30_000 nanoseconds.
         Weight::from_ref_time(48_472_000)
-            .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(4))
         }
             // Storage: Posts PostById (r:1 w:1)
             // Storage: Spaces SpaceById (r:2 w:0)
             // Storage: SpaceFollows SpaceFollowedByAccount (r:2 w:0)
             // Storage: Posts PostIdsBySpaceId (r:2 w:2)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Posts PostCountBySpaceId (r:2 w:2)
         fn move_post() -> Weight {
         // Minimum execution time: 39_000 nanoseconds.
         Weight::from_ref_time(60_733_000)
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(6))
+        }
+            // Storage: Posts PostById (r:2 w:2)
+            // Storage: Posts DeletedPostById (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Reactions TipsTotalByPostId (r:0 w:1)
+            // Storage: Posts PostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts ReplyCountByPostId (r:1 w:0)
+            // Storage: Posts SharedPostCountByOriginalPostId (r:1 w:0)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts ContentHistoryByPostId (r:0 w:1)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:1)
+            // Storage: Posts ScheduledPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:1)
+            // Storage: Posts ScheduledPostCountBySpaceId (r:1 w:1)
+            // Storage: Posts CommentDepthByPostId (r:0 w:1)
+        fn delete_post() -> Weight {
+        // Minimum execution time: 52_000 nanoseconds.
+        Weight::from_ref_time(71_418_000)
+            .saturating_add(T::DbWeight::get().reads(15))
+            .saturating_add(T::DbWeight::get().writes(14))
+        }
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts DeletedPostById (r:1 w:0)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+        fn pin_post() -> Weight {
+        // Minimum execution time: 17_000 nanoseconds.
+        Weight::from_ref_time(24_713_000)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(1))
+        }
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts DeletedPostById (r:1 w:0)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+        fn unpin_post() -> Weight {
+        // Minimum execution time: 17_000 nanoseconds.
+        Weight::from_ref_time(23_905_000)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(1))
+        }
+            // Storage: Posts PostById (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:1)
+            // Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+            // Storage: Reactions TipsTotalByPostId (r:0 w:1)
+            // Storage: Posts PostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts ReplyCountByPostId (r:1 w:0)
+            // Storage: Posts SharedPostCountByOriginalPostId (r:1 w:0)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts ContentHistoryByPostId (r:0 w:1)
+            // Storage: Posts ScheduledPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts ScheduledPostCountBySpaceId (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:0)
+        fn cancel_scheduled_post() -> Weight {
+        // Minimum execution time: 41_000 nanoseconds.
+        Weight::from_ref_time(58_216_000)
+            .saturating_add(T::DbWeight::get().reads(12))
+            .saturating_add(T::DbWeight::get().writes(11))
+        }
+            // Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts ScheduledPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:0 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:1)
+            // Storage: Posts ScheduledPostCountBySpaceId (r:1 w:1)
+            /// The range of component `n` is `[0, 100]`.
+        fn publish_scheduled_posts(n: u32, ) -> Weight {
+        // Minimum execution time: 3_000 nanoseconds.
+        Weight::from_ref_time(4_836_000)
+            // Standard Error: 3_000
+            .saturating_add(Weight::from_ref_time(9_127_000).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+        }
+            // Storage: Posts ReplyIdsByPostId (r:2 w:1)
+            // Storage: Posts PostById (r:2 w:2)
+            // Storage: Reactions TipsTotalByPostId (r:0 w:1)
+            // Storage: Posts ReplyCountByPostId (r:1 w:1)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:1)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts DeletedPostById (r:0 w:1)
+            // Storage: Posts ContentHistoryByPostId (r:0 w:1)
+            // Storage: Posts CommentDepthByPostId (r:0 w:1)
+            // Storage: Posts SharedPostIdsByOriginalPostId (r:1 w:0)
+            // Storage: Posts SharedPostCountByOriginalPostId (r:0 w:1)
+            /// The range of component `n` is `[1, 100]`.
+        fn force_remove_post_thread(n: u32, ) -> Weight {
+        // Minimum execution time: 4_000 nanoseconds.
+        Weight::from_ref_time(6_114_000)
+            // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(41_562_000).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((13_u64).saturating_mul(n.into())))
+        }
+            // Storage: Posts NextPostId (r:1 w:1)
+            // Storage: Timestamp Now (r:1 w:0)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Posts PostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:1)
+            // Storage: Posts PostById (r:1 w:1)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Posts PollByPostId (r:1 w:1)
+            /// The range of component `n` is `[2, 10]`.
+        fn create_poll(n: u32, ) -> Weight {
+        // Minimum execution time: 36_000 nanoseconds.
+        Weight::from_ref_time(49_871_000)
+            // Standard Error: 6_000
+            .saturating_add(Weight::from_ref_time(1_204_000).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(7))
+        }
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts DeletedPostById (r:1 w:0)
+            // Storage: Posts PollByPostId (r:1 w:1)
+            // Storage: Posts PollVoteByAccount (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Posts PollVoteDepositByAccount (r:0 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn vote_in_poll() -> Weight {
+        // Minimum execution time: 22_000 nanoseconds.
+        Weight::from_ref_time(31_406_000)
             .saturating_add(T::DbWeight::get().reads(7))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes(4))
+        }
+            // Storage: Posts PollByPostId (r:1 w:0)
+            // Storage: Posts PollVoteByAccount (r:2 w:1)
+            // Storage: Posts PollVoteDepositByAccount (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            /// The range of component `n` is `[0, 100]`.
+        fn remove_poll_votes(n: u32, ) -> Weight {
+        // Minimum execution time: 12_000 nanoseconds.
+        Weight::from_ref_time(14_208_000)
+            // Standard Error: 5_000
+            .saturating_add(Weight::from_ref_time(11_362_000).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
         }
     }
 
@@ -116,54 +282,235 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
             // Storage: Posts PostIdsBySpaceId (r:1 w:1)
             // Storage: Posts PostById (r:0 w:1)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:0 w:1)
+            // Storage: Posts ScheduledPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:1)
+            // Storage: Posts ScheduledPostCountBySpaceId (r:1 w:1)
         fn create_post__regular() -> Weight {
         // Minimum execution time: 30_000 nanoseconds.
         Weight::from_ref_time(47_502_000)
-            .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().reads(11))
+            .saturating_add(RocksDbWeight::get().writes(10))
         }
             // Storage: Posts NextPostId (r:1 w:1)
             // Storage: Timestamp Now (r:1 w:0)
             // Storage: Spaces SpaceById (r:1 w:0)
             // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
-            // Storage: Posts PostById (r:1 w:1)
+            // Storage: Posts PostById (r:1 w:2)
             // Storage: Posts SharedPostIdsByOriginalPostId (r:1 w:1)
             // Storage: Posts PostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Posts SharedPostCountByOriginalPostId (r:1 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:1)
         fn create_post__shared() -> Weight {
         // Minimum execution time: 39_000 nanoseconds.
         Weight::from_ref_time(62_353_000)
-            .saturating_add(RocksDbWeight::get().reads(7))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(12))
+            .saturating_add(RocksDbWeight::get().writes(9))
         }
             // Storage: Posts NextPostId (r:1 w:1)
             // Storage: Timestamp Now (r:1 w:0)
-            // Storage: Posts PostById (r:2 w:1)
+            // Storage: Posts PostById (r:2 w:2)
             // Storage: Spaces SpaceById (r:1 w:0)
             // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
             // Storage: Posts ReplyIdsByPostId (r:1 w:1)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Posts ReplyCountByPostId (r:1 w:1)
+            // Storage: Spaces SubscriptionExpiresAtByAccount (r:1 w:0)
+            // Storage: Posts CommentDepthByPostId (r:1 w:1)
         fn create_post__comment() -> Weight {
         // Minimum execution time: 39_000 nanoseconds.
         Weight::from_ref_time(59_771_000)
-            .saturating_add(RocksDbWeight::get().reads(7))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().reads(13))
+            .saturating_add(RocksDbWeight::get().writes(8))
         }
             // Storage: Posts PostById (r:2 w:1)
             // Storage: Spaces SpaceById (r:1 w:0)
             // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts ContentHistoryByPostId (r:1 w:1)
+            // Storage: Spaces SubscriptionPlanBySpaceId (r:1 w:0)
         fn update_post() -> Weight {
         // Minimum execution time: 30_000 nanoseconds.
         Weight::from_ref_time(48_472_000)
-            .saturating_add(RocksDbWeight::get().reads(4))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads(8))
+            .saturating_add(RocksDbWeight::get().writes(4))
         }
             // Storage: Posts PostById (r:1 w:1)
             // Storage: Spaces SpaceById (r:2 w:0)
             // Storage: SpaceFollows SpaceFollowedByAccount (r:2 w:0)
             // Storage: Posts PostIdsBySpaceId (r:2 w:2)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Posts PostCountBySpaceId (r:2 w:2)
         fn move_post() -> Weight {
         // Minimum execution time: 39_000 nanoseconds.
         Weight::from_ref_time(60_733_000)
+            .saturating_add(RocksDbWeight::get().reads(11))
+            .saturating_add(RocksDbWeight::get().writes(6))
+        }
+            // Storage: Posts PostById (r:2 w:2)
+            // Storage: Posts DeletedPostById (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Reactions TipsTotalByPostId (r:0 w:1)
+            // Storage: Posts PostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts ReplyCountByPostId (r:1 w:0)
+            // Storage: Posts SharedPostCountByOriginalPostId (r:1 w:0)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts ContentHistoryByPostId (r:0 w:1)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:1)
+            // Storage: Posts ScheduledPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:1)
+            // Storage: Posts ScheduledPostCountBySpaceId (r:1 w:1)
+            // Storage: Posts CommentDepthByPostId (r:0 w:1)
+        fn delete_post() -> Weight {
+        // Minimum execution time: 52_000 nanoseconds.
+        Weight::from_ref_time(71_418_000)
+            .saturating_add(RocksDbWeight::get().reads(15))
+            .saturating_add(RocksDbWeight::get().writes(14))
+        }
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts DeletedPostById (r:1 w:0)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+        fn pin_post() -> Weight {
+        // Minimum execution time: 17_000 nanoseconds.
+        Weight::from_ref_time(24_713_000)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(1))
+        }
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts DeletedPostById (r:1 w:0)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+        fn unpin_post() -> Weight {
+        // Minimum execution time: 17_000 nanoseconds.
+        Weight::from_ref_time(23_905_000)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(1))
+        }
+            // Storage: Posts PostById (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:1)
+            // Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+            // Storage: Reactions TipsTotalByPostId (r:0 w:1)
+            // Storage: Posts PostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts ReplyCountByPostId (r:1 w:0)
+            // Storage: Posts SharedPostCountByOriginalPostId (r:1 w:0)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts ContentHistoryByPostId (r:0 w:1)
+            // Storage: Posts ScheduledPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts ScheduledPostCountBySpaceId (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:0)
+        fn cancel_scheduled_post() -> Weight {
+        // Minimum execution time: 41_000 nanoseconds.
+        Weight::from_ref_time(58_216_000)
+            .saturating_add(RocksDbWeight::get().reads(12))
+            .saturating_add(RocksDbWeight::get().writes(11))
+        }
+            // Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts ScheduledPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:0 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:1)
+            // Storage: Posts ScheduledPostCountBySpaceId (r:1 w:1)
+            /// The range of component `n` is `[0, 100]`.
+        fn publish_scheduled_posts(n: u32, ) -> Weight {
+        // Minimum execution time: 3_000 nanoseconds.
+        Weight::from_ref_time(4_836_000)
+            // Standard Error: 3_000
+            .saturating_add(Weight::from_ref_time(9_127_000).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+        }
+            // Storage: Posts ReplyIdsByPostId (r:2 w:1)
+            // Storage: Posts PostById (r:2 w:2)
+            // Storage: Reactions TipsTotalByPostId (r:0 w:1)
+            // Storage: Posts ReplyCountByPostId (r:1 w:1)
+            // Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:1)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts DeletedPostById (r:0 w:1)
+            // Storage: Posts ContentHistoryByPostId (r:0 w:1)
+            // Storage: Posts CommentDepthByPostId (r:0 w:1)
+            // Storage: Posts SharedPostIdsByOriginalPostId (r:1 w:0)
+            // Storage: Posts SharedPostCountByOriginalPostId (r:0 w:1)
+            /// The range of component `n` is `[1, 100]`.
+        fn force_remove_post_thread(n: u32, ) -> Weight {
+        // Minimum execution time: 4_000 nanoseconds.
+        Weight::from_ref_time(6_114_000)
+            // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(41_562_000).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((13_u64).saturating_mul(n.into())))
+        }
+            // Storage: Posts NextPostId (r:1 w:1)
+            // Storage: Timestamp Now (r:1 w:0)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Posts PostIdsBySpaceId (r:1 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:1)
+            // Storage: Posts PostById (r:1 w:1)
+            // Storage: Posts DepositByPostId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Posts PollByPostId (r:1 w:1)
+            /// The range of component `n` is `[2, 10]`.
+        fn create_poll(n: u32, ) -> Weight {
+        // Minimum execution time: 36_000 nanoseconds.
+        Weight::from_ref_time(49_871_000)
+            // Standard Error: 6_000
+            .saturating_add(Weight::from_ref_time(1_204_000).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(11))
+            .saturating_add(RocksDbWeight::get().writes(7))
+        }
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts DeletedPostById (r:1 w:0)
+            // Storage: Posts PollByPostId (r:1 w:1)
+            // Storage: Posts PollVoteByAccount (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Posts PollVoteDepositByAccount (r:0 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn vote_in_poll() -> Weight {
+        // Minimum execution time: 22_000 nanoseconds.
+        Weight::from_ref_time(31_406_000)
             .saturating_add(RocksDbWeight::get().reads(7))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
+        }
+            // Storage: Posts PollByPostId (r:1 w:0)
+            // Storage: Posts PollVoteByAccount (r:2 w:1)
+            // Storage: Posts PollVoteDepositByAccount (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            /// The range of component `n` is `[0, 100]`.
+        fn remove_poll_votes(n: u32, ) -> Weight {
+        // Minimum execution time: 12_000 nanoseconds.
+        Weight::from_ref_time(14_208_000)
+            // Standard Error: 5_000
+            .saturating_add(Weight::from_ref_time(11_362_000).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
         }
     }
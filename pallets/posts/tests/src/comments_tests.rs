@@ -1,8 +1,8 @@
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::DispatchError;
 
-use pallet_posts::Error as PostsError;
-use subsocial_support::{mock_functions::*, ContentError, PostId};
+use pallet_posts::{CommentDepthByPostId, Error as PostsError};
+use subsocial_support::{mock_functions::*, Content, ContentError, PostId};
 
 use crate::{mock::*, tests_utils::*};
 
@@ -12,7 +12,7 @@ fn create_comment_should_work() {
         assert_ok!(_create_default_comment()); // PostId 2 by ACCOUNT1 which is permitted by default
 
         // Check storages
-        assert_eq!(Posts::reply_ids_by_post_id(POST1), vec![POST2]);
+        assert_eq!(reply_ids_by_post_id(POST1), vec![POST2]);
 
         // Check whether data stored correctly
         let comment = Posts::post_by_id(POST2).unwrap();
@@ -26,6 +26,8 @@ fn create_comment_should_work() {
 
         assert_eq!(comment.upvotes_count, 0);
         assert_eq!(comment.downvotes_count, 0);
+
+        assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, 1);
     });
 }
 
@@ -47,12 +49,21 @@ fn create_comment_should_work_when_comment_has_parents() {
         // except the last parent.
         for comment_id in first_comment_id..parent_id_by_one {
             // All of comments has 1 reply as they respond to each other.
-            assert_eq!(Posts::reply_ids_by_post_id(comment_id), vec![comment_id + 1]);
+            assert_eq!(reply_ids_by_post_id(comment_id), vec![comment_id + 1]);
         }
 
-        assert_eq!(Posts::reply_ids_by_post_id(parent_id), vec![last_comment_id]);
+        assert_eq!(reply_ids_by_post_id(parent_id), vec![last_comment_id]);
+
+        assert!(reply_ids_by_post_id(last_comment_id).is_empty());
 
-        assert!(Posts::reply_ids_by_post_id(last_comment_id).is_empty());
+        // Every comment is counted by all of its' ancestors up to the root post.
+        assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, (last_comment_id - 1) as u32);
+        for comment_id in first_comment_id..=last_comment_id {
+            assert_eq!(
+                Posts::post_by_id(comment_id).unwrap().replies_count,
+                (last_comment_id - comment_id) as u32
+            );
+        }
     });
 }
 
@@ -104,6 +115,20 @@ fn create_comment_should_fail_when_trying_create_in_hidden_post_scope() {
     });
 }
 
+#[test]
+fn create_comment_should_store_comment_depth() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 3
+        assert_ok!(_create_comment(None, None, Some(Some(3)), None)); // PostId 4
+
+        assert_eq!(Posts::comment_depth_by_post_id(POST2), 1);
+        assert_eq!(Posts::comment_depth_by_post_id(3), 2);
+        assert_eq!(Posts::comment_depth_by_post_id(4), 3);
+        // Root posts have no depth.
+        assert_eq!(Posts::comment_depth_by_post_id(POST1), 0);
+    });
+}
+
 #[test]
 fn create_comment_should_fail_when_max_comment_depth_reached() {
     ExtBuilder::build_with_post().execute_with(|| {
@@ -162,14 +187,11 @@ fn update_comment_hidden_should_work_when_comment_has_parents() {
         // except the last before hidden.
         for comment_id in first_comment_id..should_hide_by_one_id {
             // All of comments has 1 replies as they reply to each other.
-            assert_eq!(Posts::reply_ids_by_post_id(comment_id), vec![comment_id + 1]);
+            assert_eq!(reply_ids_by_post_id(comment_id), vec![comment_id + 1]);
         }
 
-        assert_eq!(
-            Posts::reply_ids_by_post_id(should_hide_by_one_id),
-            vec![should_hide_by_one_id + 1]
-        );
-        assert_eq!(Posts::reply_ids_by_post_id(should_hide_id), vec![should_hide_id + 1]);
+        assert_eq!(reply_ids_by_post_id(should_hide_by_one_id), vec![should_hide_by_one_id + 1]);
+        assert_eq!(reply_ids_by_post_id(should_hide_id), vec![should_hide_id + 1]);
     });
 }
 
@@ -207,3 +229,132 @@ fn update_comment_should_fail_when_ipfs_cid_is_invalid() {
         );
     });
 }
+
+#[test]
+fn delete_comment_should_remove_it_from_replies() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_eq!(Posts::comment_depth_by_post_id(POST2), 1);
+        assert_ok!(_delete_post(None, Some(POST2)));
+
+        assert!(Posts::post_by_id(POST2).is_none());
+        assert!(reply_ids_by_post_id(POST1).is_empty());
+        assert!(!CommentDepthByPostId::<Test>::contains_key(POST2));
+    });
+}
+
+#[test]
+fn reply_count_by_post_id_should_follow_created_and_deleted_comments() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_create_default_comment()); // PostId 3
+        assert_eq!(Posts::reply_count_by_post_id(POST1), 2);
+
+        assert_ok!(_delete_post(None, Some(POST2)));
+        assert_eq!(Posts::reply_count_by_post_id(POST1), 1);
+        assert_eq!(reply_ids_by_post_id(POST1), vec![POST3]);
+    });
+}
+
+#[test]
+fn delete_comment_should_decrease_replies_count_of_all_ancestors() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 3
+
+        assert_ok!(_delete_post(None, Some(POST3)));
+
+        assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, 1);
+        assert_eq!(Posts::post_by_id(POST2).unwrap().replies_count, 0);
+    });
+}
+
+#[test]
+fn force_create_and_remove_comment_should_update_replies_count_of_ancestors() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        let created = Posts::post_by_id(POST2).unwrap().created;
+        assert_ok!(Posts::force_create_post(
+            RuntimeOrigin::root(),
+            POST3,
+            created,
+            ACCOUNT1,
+            extension_comment(Some(POST2), POST1),
+            None,
+            reply_content_ipfs(),
+            false,
+            0,
+            0,
+        ));
+
+        assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, 2);
+        assert_eq!(Posts::post_by_id(POST2).unwrap().replies_count, 1);
+
+        assert_ok!(Posts::force_remove_post(RuntimeOrigin::root(), POST3));
+
+        assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, 1);
+        assert_eq!(Posts::post_by_id(POST2).unwrap().replies_count, 0);
+    });
+}
+
+#[test]
+fn delete_comment_should_keep_tombstone_when_comment_has_replies() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 3
+
+        assert_ok!(_delete_post(None, Some(POST2)));
+
+        assert_eq!(Posts::post_by_id(POST2).unwrap().content, Content::None);
+        assert_eq!(reply_ids_by_post_id(POST1), vec![POST2]);
+        assert_eq!(reply_ids_by_post_id(POST2), vec![POST3]);
+    });
+}
+
+#[test]
+fn create_comment_should_fail_when_parent_comment_is_deleted() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 3
+        assert_ok!(_delete_post(None, Some(POST2)));
+
+        assert_noop!(
+            _create_comment(None, None, Some(Some(POST2)), None),
+            PostsError::<Test>::PostIsDeleted
+        );
+    });
+}
+
+#[test]
+fn delete_comment_should_fail_when_account_is_not_comment_author() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_noop!(
+            _delete_post(Some(RuntimeOrigin::signed(ACCOUNT2)), Some(POST2)),
+            PostsError::<Test>::NotACommentAuthor
+        );
+    });
+}
+
+#[test]
+fn hide_any_comment_should_work_for_space_owner() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_comment(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None)); // PostId 2
+
+        // Space owner can hide any comment by default, but can't update it.
+        assert_ok!(_update_comment(None, None, Some(post_update(None, None, Some(true)))));
+        assert!(Posts::post_by_id(POST2).unwrap().hidden);
+        System::assert_last_event(
+            pallet_posts::Event::PostHidden { account: ACCOUNT1, post_id: POST2 }.into(),
+        );
+
+        assert_noop!(_update_comment(None, None, None), PostsError::<Test>::NotACommentAuthor);
+    });
+}
+
+#[test]
+fn hide_any_comment_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_noop!(
+            _update_comment(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                Some(post_update(None, None, Some(true)))
+            ),
+            PostsError::<Test>::NoPermissionToHideAnyComment
+        );
+    });
+}
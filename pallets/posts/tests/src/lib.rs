@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod comments_tests;
 #[cfg(test)]
+mod migration_tests;
+#[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod post_tests;
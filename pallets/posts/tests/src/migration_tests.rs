@@ -0,0 +1,276 @@
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+
+use pallet_posts::{
+    migration::v6::{
+        migrate_posts, MigrateToV6, MigrationCursor, MigrationStage, OldPost, OldPostById,
+        OldPostIdsBySpaceId, OldReplyIdsByPostId, OldSharedPostIdsByOriginalPostId,
+    },
+    Error as PostsError, NextPostId, PostById, PostExtension, FIRST_POST_ID,
+};
+use pallet_spaces::types::FIRST_SPACE_ID;
+use subsocial_support::{new_who_and_when, PostId, SpaceId};
+
+use crate::{mock::*, tests_utils::*};
+
+const POST4: PostId = 4;
+
+fn insert_old_post(post_id: PostId, extension: PostExtension, space_id: Option<SpaceId>) {
+    let old_post = OldPost::<Test> {
+        id: post_id,
+        created: new_who_and_when::<Test>(ACCOUNT1),
+        edited: false,
+        owner: ACCOUNT1,
+        extension,
+        space_id,
+        content: post_content_ipfs(),
+        hidden: false,
+        upvotes_count: 0,
+        downvotes_count: 0,
+    };
+    OldPostById::<Test>::insert(post_id, old_post);
+}
+
+fn start_posts_migration(next_post_id: PostId) {
+    NextPostId::<Test>::put(next_post_id);
+    StorageVersion::new(0).put::<Posts>();
+    MigrateToV6::<Test>::on_runtime_upgrade();
+}
+
+fn run_posts_migration_until(stage: MigrationStage) {
+    for _ in 0..1000 {
+        if matches!(MigrationCursor::<Test>::get(), Some((current, _)) if current == stage) {
+            return
+        }
+        migrate_posts::<Test>(2);
+    }
+    panic!("Migration stage {:?} was not reached", stage);
+}
+
+fn finish_posts_migration() {
+    // Space ids are looked up from the first reserved one.
+    for _ in 0..1000 {
+        migrate_posts::<Test>(2);
+    }
+    assert!(MigrationCursor::<Test>::get().is_none());
+}
+
+#[test]
+fn migrate_posts_should_work_in_bounded_steps() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        insert_old_post(POST1, extension_regular_post(), Some(SPACE1));
+        insert_old_post(POST2, extension_comment(None, POST1), None);
+        insert_old_post(POST3, extension_comment(Some(POST2), POST1), None);
+        insert_old_post(POST4, extension_shared_post(POST1), Some(SPACE1));
+        OldPostIdsBySpaceId::<Test>::insert(SPACE1, vec![POST1, POST4]);
+        OldReplyIdsByPostId::<Test>::insert(POST1, vec![POST2]);
+        OldReplyIdsByPostId::<Test>::insert(POST2, vec![POST3]);
+        OldSharedPostIdsByOriginalPostId::<Test>::insert(POST1, vec![POST4]);
+        start_posts_migration(POST4 + 1);
+
+        assert_eq!(
+            MigrationCursor::<Test>::get(),
+            Some((MigrationStage::TranslatePosts(POST1), POST4 + 1))
+        );
+
+        migrate_posts::<Test>(2);
+
+        assert_eq!(
+            MigrationCursor::<Test>::get(),
+            Some((MigrationStage::TranslatePosts(POST3), POST4 + 1))
+        );
+        assert!(Posts::post_by_id(POST1).is_some());
+        assert!(PostById::<Test>::get(POST3).is_none());
+        assert!(Posts::post_by_id(POST3).is_none());
+        assert_eq!(Posts::on_chain_storage_version(), 0);
+
+        finish_posts_migration();
+
+        assert_eq!(Posts::on_chain_storage_version(), 6);
+
+        let post = Posts::post_by_id(POST1).unwrap();
+        assert_eq!(post.owner, ACCOUNT1);
+        assert_eq!(post.content, post_content_ipfs());
+        assert_eq!(post.replies_count, 2);
+        assert_eq!(post.shares_count, 1);
+        assert_eq!(post.updated, None);
+        assert_eq!(post.permissions, None);
+        assert!(!post.subscribers_only);
+        assert_eq!(Posts::post_by_id(POST2).unwrap().replies_count, 1);
+        assert_eq!(Posts::post_by_id(POST3).unwrap().replies_count, 0);
+
+        assert_eq!(Posts::comment_depth_by_post_id(POST2), 1);
+        assert_eq!(Posts::comment_depth_by_post_id(POST3), 2);
+
+        assert_eq!(post_ids_by_space_id(SPACE1), vec![POST1, POST4]);
+        assert_eq!(Posts::post_count_by_space_id(SPACE1), 2);
+        assert_eq!(reply_ids_by_post_id(POST1), vec![POST2]);
+        assert_eq!(reply_ids_by_post_id(POST2), vec![POST3]);
+        assert_eq!(Posts::reply_count_by_post_id(POST1), 1);
+        assert_eq!(shared_post_ids_by_original_post_id(POST1), vec![POST4]);
+        assert_eq!(Posts::shared_post_count_by_original_post_id(POST1), 1);
+    });
+}
+
+#[test]
+fn migrate_posts_should_move_a_long_list_in_parts() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let post_ids: Vec<PostId> = (POST1..=5).collect();
+        for post_id in post_ids.iter().copied() {
+            insert_old_post(post_id, extension_regular_post(), Some(SPACE1));
+        }
+        OldPostIdsBySpaceId::<Test>::insert(SPACE1, post_ids.clone());
+        start_posts_migration(6);
+
+        migrate_posts::<Test>(5);
+        assert_eq!(
+            MigrationCursor::<Test>::get(),
+            Some((MigrationStage::MovePostIdsBySpace(FIRST_SPACE_ID), 6))
+        );
+
+        // Space ids from 1 to 1001 are looked up, then 2 of 5 post ids are moved.
+        migrate_posts::<Test>(1003);
+
+        assert_eq!(
+            MigrationCursor::<Test>::get(),
+            Some((MigrationStage::MovePostIdsBySpace(SPACE1), 6))
+        );
+        assert_eq!(Posts::post_count_by_space_id(SPACE1), 2);
+        assert_eq!(OldPostIdsBySpaceId::<Test>::get(SPACE1), Some(vec![3, 4, 5]));
+
+        finish_posts_migration();
+
+        assert_eq!(post_ids_by_space_id(SPACE1), post_ids);
+        assert_eq!(Posts::post_count_by_space_id(SPACE1), 5);
+        assert!(OldPostIdsBySpaceId::<Test>::get(SPACE1).is_none());
+    });
+}
+
+#[test]
+fn migrate_posts_should_count_replies_in_bounded_steps() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        insert_old_post(POST1, extension_regular_post(), Some(SPACE1));
+        insert_old_post(POST2, extension_comment(None, POST1), None);
+        insert_old_post(POST3, extension_comment(Some(POST2), POST1), None);
+        start_posts_migration(POST4);
+
+        run_posts_migration_until(MigrationStage::CountRepliesAndShares(POST1));
+
+        // Post 1 is looked up, then post 2 is looked up and counted by post 1.
+        migrate_posts::<Test>(2);
+
+        assert_eq!(
+            MigrationCursor::<Test>::get(),
+            Some((MigrationStage::CountRepliesAndShares(POST3), POST4))
+        );
+        assert_eq!(PostById::<Test>::get(POST1).unwrap().replies_count, 1);
+
+        finish_posts_migration();
+
+        assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, 2);
+        assert_eq!(Posts::post_by_id(POST2).unwrap().replies_count, 1);
+    });
+}
+
+#[test]
+fn migrate_posts_should_store_comment_depths_in_bounded_steps() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        insert_old_post(POST1, extension_regular_post(), Some(SPACE1));
+        insert_old_post(POST2, extension_comment(None, POST1), None);
+        insert_old_post(POST3, extension_comment(Some(POST2), POST1), None);
+        start_posts_migration(POST4);
+
+        run_posts_migration_until(MigrationStage::StoreCommentDepths(POST1));
+        migrate_posts::<Test>(2);
+
+        assert_eq!(
+            MigrationCursor::<Test>::get(),
+            Some((MigrationStage::StoreCommentDepths(POST3), POST4))
+        );
+        assert_eq!(Posts::comment_depth_by_post_id(POST2), 1);
+        assert_eq!(Posts::comment_depth_by_post_id(POST3), 0);
+
+        finish_posts_migration();
+
+        assert_eq!(Posts::comment_depth_by_post_id(POST3), 2);
+    });
+}
+
+#[test]
+fn migrate_posts_should_reserve_deposits() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        PostByteDeposit::set(1);
+        insert_old_post(POST1, extension_regular_post(), Some(SPACE1));
+        start_posts_migration(POST2);
+
+        finish_posts_migration();
+
+        let expected_deposit = expected_post_deposit(POST1);
+        let deposit = Posts::deposit_by_post_id(POST1).unwrap();
+        assert_eq!(deposit.depositor, ACCOUNT1);
+        assert_eq!(deposit.amount, expected_deposit);
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), expected_deposit);
+    });
+}
+
+#[test]
+fn migrate_posts_should_not_touch_posts_created_after_start() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        insert_old_post(POST1, extension_regular_post(), Some(SPACE1));
+        OldPostIdsBySpaceId::<Test>::insert(SPACE1, vec![POST1]);
+        start_posts_migration(POST2);
+
+        assert_ok!(_create_default_post()); // PostId 2
+        let new_post = Posts::post_by_id(POST2).unwrap();
+
+        // Posts created before the migration can not be found until it's finished.
+        assert_noop!(_create_default_comment(), PostsError::<Test>::PostNotFound);
+
+        finish_posts_migration();
+
+        assert_eq!(Posts::post_by_id(POST1).unwrap().id, POST1);
+        assert_eq!(Posts::post_by_id(POST2), Some(new_post));
+        assert_eq!(post_ids_by_space_id(SPACE1), vec![POST1, POST2]);
+        assert_eq!(Posts::post_count_by_space_id(SPACE1), 2);
+    });
+}
+
+#[test]
+fn migrate_posts_should_not_let_remove_translated_posts_until_finished() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        insert_old_post(POST1, extension_regular_post(), Some(SPACE1));
+        insert_old_post(POST2, extension_comment(None, POST1), None);
+        OldPostIdsBySpaceId::<Test>::insert(SPACE1, vec![POST1]);
+        OldReplyIdsByPostId::<Test>::insert(POST1, vec![POST2]);
+        start_posts_migration(POST3);
+
+        run_posts_migration_until(MigrationStage::MoveReplyIds(FIRST_POST_ID));
+
+        // Translated posts can be read, but their replies are not listed yet.
+        assert!(Posts::post_by_id(POST1).is_some());
+        assert_noop!(_delete_default_post(), PostsError::<Test>::MigrationInProgress);
+        assert_noop!(
+            Posts::force_remove_post_thread(RuntimeOrigin::root(), POST1, 10),
+            PostsError::<Test>::MigrationInProgress
+        );
+
+        finish_posts_migration();
+
+        assert_ok!(Posts::force_remove_post_thread(RuntimeOrigin::root(), POST1, 10));
+        assert!(PostById::<Test>::get(POST1).is_none());
+        assert!(PostById::<Test>::get(POST2).is_none());
+    });
+}
+
+#[test]
+fn migrate_to_v6_should_not_start_when_already_migrated() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        StorageVersion::new(6).put::<Posts>();
+        MigrateToV6::<Test>::on_runtime_upgrade();
+
+        assert!(MigrationCursor::<Test>::get().is_none());
+        assert!(Posts::post_by_id(POST1).is_some());
+    });
+}
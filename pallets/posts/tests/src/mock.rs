@@ -1,4 +1,8 @@
-use frame_support::{pallet_prelude::ConstU32, parameter_types, traits::Everything};
+use frame_support::{
+    pallet_prelude::ConstU32,
+    parameter_types,
+    traits::{ConstU64, Everything},
+};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -95,12 +99,19 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
     pub const MaxCommentDepth: u32 = 10;
+    pub static PostByteDeposit: u64 = 0;
 }
 
 impl pallet_posts::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxCommentDepth = MaxCommentDepth;
     type IsPostBlocked = MockModeration;
+    type BeforePostDeleted = ();
+    type MaxPostContentHistory = ConstU32<2>;
+    type MaxPinnedPostsPerSpace = ConstU32<2>;
+    type MaxScheduledPostsPerBlock = ConstU32<2>;
+    type MaxPollOptions = ConstU32<3>;
+    type PostByteDeposit = PostByteDeposit;
     type WeightInfo = ();
 }
 
@@ -119,6 +130,9 @@ impl pallet_roles::Config for Test {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = MockModeration;
     type IsContentBlocked = MockModeration;
+    type MaxInlineContentLen = ConstU32<512>;
+    type Currency = Balances;
+    type RoleByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -135,7 +149,17 @@ impl pallet_spaces::Config for Test {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = MockModeration;
     type IsContentBlocked = MockModeration;
+    type IsSpaceBlocked = MockModeration;
+    type MaxInlineContentLen = ConstU32<512>;
+    type ProfileManager = Profiles;
+    type BeforeSpaceDeleted = (Posts, SpaceFollows, SpaceOwnership);
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<5>;
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type MaxSpaceContentHistory = ConstU32<10>;
+    type Currency = Balances;
+    type SpaceByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -146,6 +170,5 @@ impl pallet_space_follows::Config for Test {
 
 impl pallet_space_ownership::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type ProfileManager = Profiles;
     type WeightInfo = ();
 }
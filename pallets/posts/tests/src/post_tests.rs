@@ -1,10 +1,13 @@
+use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::DispatchError;
 
 use pallet_permissions::SpacePermission as SP;
-use pallet_posts::{Error as PostsError, Post};
+use pallet_posts::{Error as PostsError, Post, PostExtension};
 use pallet_spaces::Error as SpacesError;
-use subsocial_support::{mock_functions::*, ContentError, ModerationError, PostId, SpaceId};
+use subsocial_support::{
+    mock_functions::*, Content, ContentError, ModerationError, PostId, SpaceId,
+};
 
 use crate::{mock::*, tests_utils::*};
 
@@ -96,7 +99,7 @@ fn create_post_should_work() {
         assert_ok!(_create_default_post()); // PostId 1 by ACCOUNT1 which is permitted by default
 
         // Check storages
-        assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1]);
+        assert_eq!(post_ids_by_space_id(SPACE1), vec![POST1]);
         assert_eq!(Posts::next_post_id(), POST2);
 
         // Check whether data stored correctly
@@ -158,6 +161,26 @@ fn create_post_should_fail_when_ipfs_cid_is_invalid() {
     });
 }
 
+#[test]
+fn create_post_should_work_with_inline_content() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_post(None, None, None, Some(valid_content_inline())));
+
+        let post = Posts::post_by_id(POST1).unwrap();
+        assert_eq!(post.content, valid_content_inline());
+    });
+}
+
+#[test]
+fn create_post_should_fail_when_inline_content_is_too_long() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _create_post(None, None, None, Some(Content::Inline(vec![b'a'; 513]))),
+            DispatchError::from(ContentError::InlineContentIsTooLong)
+        );
+    });
+}
+
 #[test]
 fn create_post_should_fail_when_account_has_no_permission() {
     ExtBuilder::build_with_space().execute_with(|| {
@@ -207,6 +230,127 @@ fn update_post_should_work() {
     });
 }
 
+#[test]
+fn update_post_should_record_who_updated_it_and_previous_content() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(post_update(None, Some(updated_post_content()), None))
+        ));
+
+        let post = Posts::post_by_id(POST1).unwrap();
+        assert_eq!(post.updated.unwrap().account, ACCOUNT1);
+        assert_eq!(
+            Posts::content_history_by_post_id(POST1).into_inner(),
+            vec![post_content_ipfs()]
+        );
+    });
+}
+
+#[test]
+fn update_post_should_evict_oldest_content_when_history_is_full() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        // MaxPostContentHistory is 2 in the mock.
+        let update_content = |content: Content| {
+            _update_post(None, None, Some(post_update(None, Some(content), None)))
+        };
+
+        assert_ok!(update_content(valid_content_ipfs()));
+        assert_ok!(update_content(another_valid_content_ipfs()));
+        assert_ok!(update_content(updated_post_content()));
+
+        assert_eq!(
+            Posts::content_history_by_post_id(POST1).into_inner(),
+            vec![valid_content_ipfs(), another_valid_content_ipfs()]
+        );
+    });
+}
+
+#[test]
+fn delete_post_should_remove_its_content_history() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(post_update(None, Some(updated_post_content()), None))
+        ));
+        assert_ok!(_delete_default_post());
+
+        assert!(Posts::content_history_by_post_id(POST1).is_empty());
+    });
+}
+
+#[test]
+fn create_post_should_reserve_storage_deposit() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        PostByteDeposit::set(1);
+
+        assert_ok!(_create_default_post()); // PostId 1
+
+        let expected_deposit = expected_post_deposit(POST1);
+        assert!(expected_deposit > 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), expected_deposit);
+
+        let deposit = Posts::deposit_by_post_id(POST1).unwrap();
+        assert_eq!(deposit.depositor, ACCOUNT1);
+        assert_eq!(deposit.amount, expected_deposit);
+    });
+}
+
+#[test]
+fn update_post_should_adjust_storage_deposit() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        PostByteDeposit::set(1);
+        assert_ok!(_create_default_post()); // PostId 1
+        let initial_deposit = expected_post_deposit(POST1);
+
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(post_update(None, Some(Content::Inline(vec![b'a'; 80])), None))
+        ));
+
+        let new_deposit = expected_post_deposit(POST1);
+        assert!(new_deposit > initial_deposit);
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), new_deposit);
+        assert_eq!(Posts::deposit_by_post_id(POST1).unwrap().amount, new_deposit);
+    });
+}
+
+#[test]
+fn update_any_post_should_keep_storage_deposit_with_depositor() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateAnyPost]).execute_with(
+        || {
+            PostByteDeposit::set(1);
+            assert_ok!(_create_default_post()); // PostId 1
+
+            assert_ok!(_update_post(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                Some(POST1),
+                Some(post_update(None, Some(Content::Inline(vec![b'a'; 80])), None))
+            ));
+
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), expected_post_deposit(POST1));
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+            assert_eq!(Posts::deposit_by_post_id(POST1).unwrap().depositor, ACCOUNT1);
+        },
+    );
+}
+
+#[test]
+fn force_remove_post_should_refund_storage_deposit() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        PostByteDeposit::set(1);
+        assert_ok!(_create_default_post()); // PostId 1
+
+        assert_ok!(Posts::force_remove_post(RuntimeOrigin::root(), POST1));
+
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        assert!(Posts::deposit_by_post_id(POST1).is_none());
+    });
+}
+
 fn check_if_post_moved_correctly(moved_post_id: PostId, expected_new_space_id: SpaceId) {
     let post: Post<Test> = Posts::post_by_id(moved_post_id).unwrap(); // `POST2` is a comment
     let new_space_id = post.space_id.unwrap();
@@ -226,10 +370,10 @@ fn move_post_should_work() {
         check_if_post_moved_correctly(moved_post_id, expected_new_space_id);
 
         // Check that there are no posts ids in the old space
-        assert!(Posts::post_ids_by_space_id(old_space_id).is_empty());
+        assert!(post_ids_by_space_id(old_space_id).is_empty());
 
         // Check that there is the post id in the new space
-        assert_eq!(Posts::post_ids_by_space_id(expected_new_space_id), vec![moved_post_id]);
+        assert_eq!(post_ids_by_space_id(expected_new_space_id), vec![moved_post_id]);
     });
 }
 
@@ -246,10 +390,10 @@ fn move_post_should_work_when_space_id_none() {
         check_if_post_moved_correctly(moved_post_id, expected_new_space_id);
 
         // Check that there are no posts ids in the old space
-        assert!(Posts::post_ids_by_space_id(old_space_id).is_empty());
+        assert!(post_ids_by_space_id(old_space_id).is_empty());
 
         // Check that there is the post id in the new space
-        assert_eq!(Posts::post_ids_by_space_id(expected_new_space_id), vec![moved_post_id]);
+        assert_eq!(post_ids_by_space_id(expected_new_space_id), vec![moved_post_id]);
     });
 }
 
@@ -272,10 +416,10 @@ fn move_hidden_post_should_work() {
         check_if_post_moved_correctly(moved_post_id, expected_new_space_id);
 
         // Check that there are no posts ids in the old space
-        assert!(Posts::post_ids_by_space_id(old_space_id).is_empty());
+        assert!(post_ids_by_space_id(old_space_id).is_empty());
 
         // Check that there is the post id in the new space
-        assert_eq!(Posts::post_ids_by_space_id(expected_new_space_id), vec![moved_post_id]);
+        assert_eq!(post_ids_by_space_id(expected_new_space_id), vec![moved_post_id]);
     });
 }
 
@@ -307,6 +451,18 @@ fn move_hidden_post_should_fail_origin_has_no_permission_to_create_posts() {
     });
 }
 
+#[test]
+fn move_post_should_fail_when_old_space_is_blocked_in_new_one() {
+    ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+        block_space_1_in_space_2();
+
+        assert_noop!(
+            _move_post_1_to_space_2(),
+            DispatchError::Other(ModerationError::SpaceIsBlocked.into())
+        );
+    });
+}
+
 #[test]
 fn move_post_should_fail_when_account_has_no_permission() {
     ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
@@ -379,7 +535,7 @@ fn update_any_post_should_work_when_account_has_default_permission() {
 fn update_any_post_should_work_when_one_of_roles_is_permitted() {
     ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateAnyPost]).execute_with(
         || {
-            let post_update = post_update(None, Some(updated_post_content()), Some(true));
+            let post_update = post_update(None, Some(updated_post_content()), None);
             assert_ok!(_create_default_post()); // PostId 1
 
             // Post update with ID 1 should be fine
@@ -473,6 +629,937 @@ fn update_post_should_fail_when_no_right_permission_in_account_roles() {
     );
 }
 
+#[test]
+fn delete_post_should_remove_post_without_replies() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        PostByteDeposit::set(1);
+        assert_ok!(_create_default_post()); // PostId 1
+        assert!(Balances::reserved_balance(ACCOUNT1) > 0);
+
+        assert_ok!(_delete_default_post());
+
+        assert!(Posts::post_by_id(POST1).is_none());
+        assert!(Posts::deleted_post_by_id(POST1).is_none());
+        assert!(post_ids_by_space_id(SPACE1).is_empty());
+        assert!(Posts::deposit_by_post_id(POST1).is_none());
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+
+        System::assert_last_event(
+            pallet_posts::Event::PostDeleted { account: ACCOUNT1, post_id: POST1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn delete_post_should_keep_tombstone_when_post_has_replies() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_delete_default_post());
+
+        let tombstone = Posts::post_by_id(POST1).unwrap();
+        assert_eq!(tombstone.content, Content::None);
+        assert_eq!(Posts::deleted_post_by_id(POST1).unwrap().account, ACCOUNT1);
+
+        assert!(post_ids_by_space_id(SPACE1).is_empty());
+        assert_eq!(reply_ids_by_post_id(POST1), vec![POST2]);
+    });
+}
+
+#[test]
+fn delete_post_should_fail_when_post_is_already_deleted() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_delete_default_post());
+        assert_noop!(_delete_default_post(), PostsError::<Test>::PostIsDeleted);
+    });
+}
+
+#[test]
+fn update_post_should_fail_when_post_is_deleted() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_delete_default_post());
+        assert_noop!(
+            _update_post(None, None, Some(post_update(None, Some(updated_post_content()), None))),
+            PostsError::<Test>::PostIsDeleted
+        );
+    });
+}
+
+#[test]
+fn delete_post_should_fail_when_account_is_blocked() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        block_account_in_space_1();
+        assert_noop!(
+            _delete_default_post(),
+            DispatchError::Other(ModerationError::AccountIsBlocked.into())
+        );
+    });
+}
+
+#[test]
+fn delete_post_should_fail_when_account_has_no_permission_to_delete_any_post() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            _delete_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            PostsError::<Test>::NoPermissionToDeleteAnyPost
+        );
+    });
+}
+
+#[test]
+fn delete_any_post_should_work_when_one_of_roles_is_permitted() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::DeleteAnyPost]).execute_with(
+        || {
+            assert_ok!(_create_default_post()); // PostId 1
+            assert_ok!(_delete_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None));
+            assert!(Posts::post_by_id(POST1).is_none());
+        },
+    );
+}
+
+#[test]
+fn hide_post_should_work() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_update_post(None, None, Some(post_update(None, None, Some(true)))));
+        assert!(Posts::post_by_id(POST1).unwrap().hidden);
+        System::assert_last_event(
+            pallet_posts::Event::PostHidden { account: ACCOUNT1, post_id: POST1 }.into(),
+        );
+
+        assert_ok!(_update_post(None, None, Some(post_update(None, None, Some(false)))));
+        assert!(!Posts::post_by_id(POST1).unwrap().hidden);
+        System::assert_last_event(
+            pallet_posts::Event::PostUnhidden { account: ACCOUNT1, post_id: POST1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn hide_any_post_should_work_when_one_of_roles_is_permitted() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::HideAnyPost]).execute_with(
+        || {
+            assert_ok!(_create_default_post()); // PostId 1
+            assert_ok!(_update_post(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                Some(post_update(None, None, Some(true)))
+            ));
+
+            let post = Posts::post_by_id(POST1).unwrap();
+            assert!(post.hidden);
+            assert!(!post.edited);
+
+            // Hiding a post doesn't allow to update it.
+            assert_noop!(
+                _update_post(
+                    Some(RuntimeOrigin::signed(ACCOUNT2)),
+                    None,
+                    Some(post_update(None, Some(updated_post_content()), None))
+                ),
+                PostsError::<Test>::NoPermissionToUpdateAnyPost
+            );
+        },
+    );
+}
+
+#[test]
+fn hide_any_post_should_fail_when_account_has_only_update_permission() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateAnyPost]).execute_with(
+        || {
+            assert_ok!(_create_default_post()); // PostId 1
+            assert_noop!(
+                _update_post(
+                    Some(RuntimeOrigin::signed(ACCOUNT2)),
+                    None,
+                    Some(post_update(None, None, Some(true)))
+                ),
+                PostsError::<Test>::NoPermissionToHideAnyPost
+            );
+        },
+    );
+}
+
+#[test]
+fn update_post_should_override_post_permissions() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(post_permissions_update(Some(permissions_where_no_one_can_comment())))
+        ));
+
+        let post = Posts::post_by_id(POST1).unwrap();
+        assert!(post.permissions.unwrap().none.unwrap().contains(&SP::CreateComments));
+
+        assert_noop!(_create_default_comment(), PostsError::<Test>::NoPermissionToCreateComments);
+
+        // Other posts in this space are still resolved with the space permissions.
+        assert_ok!(_create_default_post()); // PostId 2
+        assert_ok!(_create_comment(None, Some(POST2), None, None));
+    });
+}
+
+#[test]
+fn update_post_should_reset_post_permissions() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(post_permissions_update(Some(permissions_where_no_one_can_comment())))
+        ));
+        assert_ok!(_update_post(None, None, Some(post_permissions_update(None))));
+
+        assert!(Posts::post_by_id(POST1).unwrap().permissions.is_none());
+        assert_ok!(_create_default_comment());
+    });
+}
+
+#[test]
+fn update_post_should_override_post_permissions_when_one_of_roles_is_permitted() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![
+        SP::UpdateAnyPost,
+        SP::OverridePostPermissions,
+    ])
+    .execute_with(|| {
+        assert_ok!(_create_default_post()); // PostId 1
+        assert_ok!(_update_post(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            None,
+            Some(post_permissions_update(Some(permissions_where_no_one_can_comment())))
+        ));
+        assert!(Posts::post_by_id(POST1).unwrap().permissions.is_some());
+    });
+}
+
+#[test]
+fn update_post_should_fail_when_account_has_no_permission_to_override_post_permissions() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateAnyPost]).execute_with(
+        || {
+            assert_ok!(_create_default_post()); // PostId 1
+            assert_noop!(
+                _update_post(
+                    Some(RuntimeOrigin::signed(ACCOUNT2)),
+                    None,
+                    Some(post_permissions_update(Some(permissions_where_no_one_can_comment())))
+                ),
+                PostsError::<Test>::NoPermissionToOverridePostPermissions
+            );
+        },
+    );
+}
+
+#[test]
+fn update_post_should_fail_when_overriding_comment_permissions() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_noop!(
+            _update_comment(
+                None,
+                None,
+                Some(post_permissions_update(Some(permissions_where_no_one_can_comment())))
+            ),
+            PostsError::<Test>::CannotOverrideCommentPermissions
+        );
+    });
+}
+
+#[test]
+fn create_reply_should_fail_when_root_post_forbids_comments() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(post_permissions_update(Some(permissions_where_no_one_can_comment())))
+        ));
+        assert_noop!(
+            _create_comment(None, None, Some(Some(POST2)), None),
+            PostsError::<Test>::NoPermissionToCreateComments
+        );
+    });
+}
+
+#[test]
+fn pin_post_should_work() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_pin_default_post());
+
+        assert_eq!(Posts::pinned_post_ids_by_space_id(SPACE1).into_inner(), vec![POST1]);
+        System::assert_last_event(
+            pallet_posts::Event::PostPinned { account: ACCOUNT1, space_id: SPACE1, post_id: POST1 }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn pin_post_should_work_when_one_of_roles_is_permitted() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::PinPosts]).execute_with(|| {
+        assert_ok!(_create_default_post()); // PostId 1
+        assert_ok!(_pin_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None));
+
+        assert_eq!(Posts::pinned_post_ids_by_space_id(SPACE1).into_inner(), vec![POST1]);
+    });
+}
+
+#[test]
+fn pin_post_should_work_when_account_can_update_space() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateSpace]).execute_with(
+        || {
+            assert_ok!(_create_default_post()); // PostId 1
+            assert_ok!(_pin_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None));
+
+            assert_eq!(Posts::pinned_post_ids_by_space_id(SPACE1).into_inner(), vec![POST1]);
+        },
+    );
+}
+
+#[test]
+fn pin_post_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            _pin_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            PostsError::<Test>::NoPermissionToPinPosts
+        );
+    });
+}
+
+#[test]
+fn pin_post_should_fail_when_account_is_blocked() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        block_account_in_space_1();
+        assert_noop!(
+            _pin_default_post(),
+            DispatchError::Other(ModerationError::AccountIsBlocked.into())
+        );
+    });
+}
+
+#[test]
+fn pin_post_should_fail_when_post_is_comment() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_noop!(_pin_post(None, Some(POST2)), PostsError::<Test>::CannotPinComment);
+    });
+}
+
+#[test]
+fn pin_post_should_fail_when_post_is_already_pinned() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_pin_default_post());
+        assert_noop!(_pin_default_post(), PostsError::<Test>::PostIsAlreadyPinned);
+    });
+}
+
+#[test]
+fn pin_post_should_fail_when_too_many_posts_are_pinned() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        // MaxPinnedPostsPerSpace is 2 in the mock.
+        assert_ok!(_create_default_post()); // PostId 2
+        assert_ok!(_create_default_post()); // PostId 3
+
+        assert_ok!(_pin_post(None, Some(POST1)));
+        assert_ok!(_pin_post(None, Some(POST2)));
+        assert_noop!(_pin_post(None, Some(POST3)), PostsError::<Test>::TooManyPinnedPosts);
+    });
+}
+
+#[test]
+fn unpin_post_should_work() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_pin_default_post());
+        assert_ok!(_unpin_post(None, None));
+
+        assert!(Posts::pinned_post_ids_by_space_id(SPACE1).is_empty());
+        System::assert_last_event(
+            pallet_posts::Event::PostUnpinned {
+                account: ACCOUNT1,
+                space_id: SPACE1,
+                post_id: POST1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn unpin_post_should_fail_when_post_is_not_pinned() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(_unpin_post(None, None), PostsError::<Test>::PostIsNotPinned);
+    });
+}
+
+#[test]
+fn move_post_should_unpin_post_in_old_space() {
+    ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+        assert_ok!(_pin_default_post());
+        assert_ok!(_move_post_1_to_space_2());
+
+        assert!(Posts::pinned_post_ids_by_space_id(SPACE1).is_empty());
+        assert!(Posts::pinned_post_ids_by_space_id(SPACE2).is_empty());
+    });
+}
+
+#[test]
+fn move_post_to_nowhere_should_unpin_post() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_pin_default_post());
+        assert_ok!(_move_post_to_nowhere(POST1));
+
+        assert!(Posts::pinned_post_ids_by_space_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn force_remove_post_should_unpin_post() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_pin_default_post());
+        assert_ok!(Posts::force_remove_post(RuntimeOrigin::root(), POST1));
+
+        assert!(Posts::pinned_post_ids_by_space_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn delete_post_should_unpin_post() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_pin_default_post());
+        assert_ok!(_delete_default_post());
+
+        assert!(Posts::pinned_post_ids_by_space_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn create_scheduled_post_should_not_list_post_until_published() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_scheduled_post(3)); // PostId 1
+
+        assert!(Posts::post_by_id(POST1).is_some());
+        assert!(post_ids_by_space_id(SPACE1).is_empty());
+        assert_eq!(Posts::publish_at_by_post_id(POST1), Some(3));
+        assert_eq!(Posts::scheduled_post_ids_by_block(3).into_inner(), vec![POST1]);
+        assert_eq!(Posts::get_scheduled_post_ids_by_space_id(SPACE1, None, 10), vec![POST1]);
+        assert_eq!(Posts::scheduled_post_count_by_space_id(SPACE1), 1);
+
+        run_to_block(2);
+        assert!(post_ids_by_space_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn scheduled_post_should_be_published_at_its_block() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_scheduled_post(3)); // PostId 1
+
+        run_to_block(3);
+
+        assert_eq!(post_ids_by_space_id(SPACE1), vec![POST1]);
+        assert!(Posts::publish_at_by_post_id(POST1).is_none());
+        assert!(Posts::scheduled_post_ids_by_block(3).is_empty());
+        assert_eq!(Posts::scheduled_post_count_by_space_id(SPACE1), 0);
+
+        System::assert_last_event(
+            pallet_posts::Event::PostPublished { account: ACCOUNT1, post_id: POST1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn create_scheduled_post_should_fail_when_block_is_not_in_future() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(_create_scheduled_post(1), PostsError::<Test>::PublishAtBlockIsNotInFuture);
+    });
+}
+
+#[test]
+fn create_scheduled_post_should_fail_when_post_is_not_regular() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            Posts::create_post(
+                RuntimeOrigin::signed(ACCOUNT1),
+                Some(SPACE1),
+                extension_shared_post(POST1),
+                Content::None,
+                Some(3),
+            ),
+            PostsError::<Test>::OnlyRegularPostsCanBeScheduled
+        );
+    });
+}
+
+#[test]
+fn create_scheduled_post_should_fail_when_too_many_posts_are_scheduled_at_block() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        // MaxScheduledPostsPerBlock is 2 in the mock.
+        assert_ok!(_create_scheduled_post(3));
+        assert_ok!(_create_scheduled_post(3));
+        assert_noop!(_create_scheduled_post(3), PostsError::<Test>::TooManyPostsScheduledAtBlock);
+    });
+}
+
+#[test]
+fn create_comment_should_fail_when_post_is_not_published() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_scheduled_post(3)); // PostId 1
+        assert_noop!(_create_default_comment(), PostsError::<Test>::PostIsNotPublished);
+    });
+}
+
+#[test]
+fn cancel_scheduled_post_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_scheduled_post(3)); // PostId 1
+        assert_ok!(_cancel_scheduled_post(None, None));
+
+        assert!(Posts::post_by_id(POST1).is_none());
+        assert!(Posts::publish_at_by_post_id(POST1).is_none());
+        assert!(Posts::scheduled_post_ids_by_block(3).is_empty());
+        System::assert_last_event(
+            pallet_posts::Event::PostPublicationCancelled { account: ACCOUNT1, post_id: POST1 }
+                .into(),
+        );
+
+        run_to_block(3);
+        assert!(post_ids_by_space_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn cancel_scheduled_post_should_work_when_account_is_space_owner() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreatePosts]).execute_with(
+        || {
+            assert_ok!(Posts::create_post(
+                RuntimeOrigin::signed(ACCOUNT2),
+                Some(SPACE1),
+                extension_regular_post(),
+                post_content_ipfs(),
+                Some(3),
+            )); // PostId 1 by ACCOUNT2
+
+            assert_ok!(_cancel_scheduled_post(None, None));
+
+            assert!(Posts::post_by_id(POST1).is_none());
+            assert_eq!(Posts::scheduled_post_count_by_space_id(SPACE1), 0);
+            assert!(Posts::get_scheduled_post_ids_by_space_id(SPACE1, None, 10).is_empty());
+            assert_ok!(Spaces::delete_space(RuntimeOrigin::signed(ACCOUNT1), SPACE1));
+        },
+    );
+}
+
+#[test]
+fn cancel_scheduled_post_should_fail_when_account_is_not_post_owner() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_scheduled_post(3)); // PostId 1
+        assert_noop!(
+            _cancel_scheduled_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            PostsError::<Test>::NotAPostOwner
+        );
+    });
+}
+
+#[test]
+fn cancel_scheduled_post_should_fail_when_post_is_not_scheduled() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(_cancel_scheduled_post(None, None), PostsError::<Test>::PostIsNotScheduled);
+    });
+}
+
+#[test]
+fn post_count_by_space_id_should_follow_created_moved_and_deleted_posts() {
+    ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+        assert_eq!(Posts::post_count_by_space_id(SPACE1), 1);
+
+        assert_ok!(_move_post_1_to_space_2());
+        assert_eq!(Posts::post_count_by_space_id(SPACE1), 0);
+        assert_eq!(Posts::post_count_by_space_id(SPACE2), 1);
+
+        assert_ok!(_delete_post(None, None));
+        assert_eq!(Posts::post_count_by_space_id(SPACE2), 0);
+    });
+}
+
+#[test]
+fn get_post_ids_by_space_id_should_return_pages() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_default_post()); // PostId 2
+        assert_ok!(_create_default_post()); // PostId 3
+
+        let first_page = Posts::get_post_ids_by_space_id(SPACE1, None, 2);
+        assert_eq!(first_page.len(), 2);
+
+        let last_page = Posts::get_post_ids_by_space_id(SPACE1, first_page.last().copied(), 2);
+        assert_eq!(last_page.len(), 1);
+
+        let mut all_post_ids = [first_page, last_page].concat();
+        all_post_ids.sort_unstable();
+        assert_eq!(all_post_ids, vec![POST1, POST2, POST3]);
+
+        assert!(Posts::get_post_ids_by_space_id(SPACE1, None, 0).is_empty());
+    });
+}
+
+#[test]
+fn force_remove_post_thread_should_remove_post_with_all_replies() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 3
+        assert_ok!(_create_default_comment()); // PostId 4
+
+        assert_ok!(Posts::force_remove_post_thread(RuntimeOrigin::root(), POST1, 10));
+
+        for post_id in POST1..=4 {
+            assert!(Posts::post_by_id(post_id).is_none());
+        }
+        assert!(reply_ids_by_post_id(POST1).is_empty());
+        assert!(reply_ids_by_post_id(POST2).is_empty());
+        assert!(post_ids_by_space_id(SPACE1).is_empty());
+        System::assert_last_event(
+            pallet_posts::Event::PostThreadRemoved { post_id: POST1, removed_count: 4 }.into(),
+        );
+    });
+}
+
+#[test]
+fn force_remove_post_thread_should_continue_where_previous_call_stopped() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 3
+        assert_ok!(_create_default_comment()); // PostId 4
+
+        assert_ok!(Posts::force_remove_post_thread(RuntimeOrigin::root(), POST1, 2));
+
+        // The deepest replies are removed first, so no reply is left without its' parent.
+        assert!(Posts::post_by_id(POST1).is_some());
+        assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, 1);
+        System::assert_last_event(
+            pallet_posts::Event::PostThreadPartiallyRemoved { post_id: POST1, removed_count: 2 }
+                .into(),
+        );
+
+        assert_ok!(Posts::force_remove_post_thread(RuntimeOrigin::root(), POST1, 2));
+
+        assert!(Posts::post_by_id(POST1).is_none());
+        System::assert_last_event(
+            pallet_posts::Event::PostThreadRemoved { post_id: POST1, removed_count: 2 }.into(),
+        );
+    });
+}
+
+#[test]
+fn force_remove_post_thread_should_remove_replies_left_by_force_remove_post() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(Posts::force_remove_post(RuntimeOrigin::root(), POST1));
+        assert!(Posts::post_by_id(POST2).is_some());
+
+        assert_ok!(Posts::force_remove_post_thread(RuntimeOrigin::root(), POST1, 10));
+
+        assert!(Posts::post_by_id(POST2).is_none());
+        assert!(reply_ids_by_post_id(POST1).is_empty());
+        System::assert_last_event(
+            pallet_posts::Event::PostThreadRemoved { post_id: POST1, removed_count: 1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn force_remove_post_thread_should_unlist_shares_of_removed_post() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post(None, None, Some(extension_shared_post(POST1)), None)); // PostId 2
+
+        assert_ok!(Posts::force_remove_post_thread(RuntimeOrigin::root(), POST1, 10));
+
+        assert!(Posts::post_by_id(POST1).is_none());
+        assert!(Posts::post_by_id(POST2).is_some());
+        assert!(shared_post_ids_by_original_post_id(POST1).is_empty());
+        assert_eq!(Posts::shared_post_count_by_original_post_id(POST1), 0);
+        System::assert_last_event(
+            pallet_posts::Event::PostThreadRemoved { post_id: POST1, removed_count: 2 }.into(),
+        );
+    });
+}
+
+#[test]
+fn create_poll_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+
+        let post = Posts::post_by_id(POST1).unwrap();
+        assert_eq!(post.extension, PostExtension::Poll);
+        assert_eq!(post_ids_by_space_id(SPACE1), vec![POST1]);
+
+        let poll = Posts::poll_by_post_id(POST1).unwrap();
+        assert_eq!(poll.closes_at, 10);
+        assert_eq!(poll.options.len(), 2);
+        assert!(poll.options.iter().all(|option| option.votes_count == 0));
+    });
+}
+
+#[test]
+fn create_poll_should_fail_when_options_count_is_out_of_bounds() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _create_poll(None, poll_options(1), 10),
+            PostsError::<Test>::NotEnoughPollOptions
+        );
+        // MaxPollOptions is 3 in the mock.
+        assert_noop!(
+            _create_poll(None, poll_options(4), 10),
+            PostsError::<Test>::TooManyPollOptions
+        );
+    });
+}
+
+#[test]
+fn create_poll_should_check_options_count_before_options_content() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _create_poll(None, vec![invalid_content_ipfs(); 4], 10),
+            PostsError::<Test>::TooManyPollOptions
+        );
+    });
+}
+
+#[test]
+fn create_poll_should_fail_when_closing_block_is_not_in_future() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(_create_default_poll(1), PostsError::<Test>::PollClosingBlockIsNotInFuture);
+    });
+}
+
+#[test]
+fn create_post_should_fail_when_extension_is_poll() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _create_post(None, None, Some(PostExtension::Poll), None),
+            PostsError::<Test>::CannotCreatePollWithoutOptions
+        );
+    });
+}
+
+#[test]
+fn vote_in_poll_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+
+        assert_ok!(_vote_in_poll(Some(RuntimeOrigin::signed(ACCOUNT2)), 1));
+        assert_ok!(_vote_in_poll(None, 1));
+
+        let poll = Posts::poll_by_post_id(POST1).unwrap();
+        assert_eq!(poll.options[0].votes_count, 0);
+        assert_eq!(poll.options[1].votes_count, 2);
+        assert_eq!(Posts::poll_vote_by_account(POST1, ACCOUNT2), Some(1));
+        System::assert_last_event(
+            pallet_posts::Event::PollVoted { account: ACCOUNT1, post_id: POST1, option_index: 1 }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn vote_in_poll_should_fail_when_account_has_already_voted() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+        assert_ok!(_vote_in_poll(None, 0));
+
+        assert_noop!(_vote_in_poll(None, 1), PostsError::<Test>::AccountAlreadyVoted);
+    });
+}
+
+#[test]
+fn vote_in_poll_should_fail_when_poll_is_closed() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+
+        run_to_block(10);
+        assert_noop!(_vote_in_poll(None, 0), PostsError::<Test>::PollIsClosed);
+    });
+}
+
+#[test]
+fn vote_in_poll_should_fail_when_option_not_found() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+
+        assert_noop!(_vote_in_poll(None, 2), PostsError::<Test>::PollOptionNotFound);
+    });
+}
+
+#[test]
+fn vote_in_poll_should_fail_when_post_is_not_a_poll() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(_vote_in_poll(None, 0), PostsError::<Test>::NotAPoll);
+    });
+}
+
+#[test]
+fn vote_in_poll_should_fail_when_account_is_blocked() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+
+        block_account_in_space_1();
+        assert_noop!(
+            _vote_in_poll(None, 0),
+            DispatchError::Other(ModerationError::AccountIsBlocked.into())
+        );
+    });
+}
+
+#[test]
+fn vote_in_poll_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(post_permissions_update(Some(permissions_where_no_one_can_vote_in_polls())))
+        ));
+
+        assert_noop!(
+            _vote_in_poll(Some(RuntimeOrigin::signed(ACCOUNT2)), 0),
+            PostsError::<Test>::NoPermissionToVoteInPolls
+        );
+    });
+}
+
+#[test]
+fn create_poll_should_reserve_deposit_for_poll() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        PostByteDeposit::set(1);
+
+        assert_ok!(_create_default_poll(10)); // PostId 1
+
+        // The expected deposit covers the poll options too.
+        let expected_deposit = expected_post_deposit(POST1);
+        assert_eq!(Posts::deposit_by_post_id(POST1).unwrap().amount, expected_deposit);
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), expected_deposit);
+    });
+}
+
+#[test]
+fn vote_in_poll_should_reserve_deposit_from_voter() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+        PostByteDeposit::set(1);
+
+        assert_ok!(_vote_in_poll(Some(RuntimeOrigin::signed(ACCOUNT2)), 0));
+
+        let expected_deposit = (POST1, ACCOUNT2, 0u32).encoded_size() as Balance;
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), expected_deposit);
+        assert_eq!(
+            Posts::poll_vote_deposit_by_account(POST1, ACCOUNT2).unwrap().amount,
+            expected_deposit
+        );
+    });
+}
+
+#[test]
+fn delete_poll_should_remove_its_results_and_keep_votes() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+        assert_ok!(_vote_in_poll(Some(RuntimeOrigin::signed(ACCOUNT2)), 0));
+
+        assert_ok!(_delete_default_post());
+
+        assert!(Posts::poll_by_post_id(POST1).is_none());
+        assert_eq!(Posts::poll_vote_by_account(POST1, ACCOUNT2), Some(0));
+    });
+}
+
+#[test]
+fn remove_poll_votes_should_work_in_parts() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+        PostByteDeposit::set(1);
+        assert_ok!(_vote_in_poll(None, 0));
+        assert_ok!(_vote_in_poll(Some(RuntimeOrigin::signed(ACCOUNT2)), 1));
+        assert_ok!(_delete_default_post());
+
+        assert_ok!(_remove_poll_1_votes(1));
+        System::assert_last_event(
+            pallet_posts::Event::PollVotesPartiallyRemoved { post_id: POST1, removed_count: 1 }
+                .into(),
+        );
+
+        assert_ok!(_remove_poll_1_votes(10));
+        System::assert_last_event(
+            pallet_posts::Event::PollVotesRemoved { post_id: POST1, removed_count: 1 }.into(),
+        );
+
+        assert!(Posts::poll_vote_by_account(POST1, ACCOUNT1).is_none());
+        assert!(Posts::poll_vote_by_account(POST1, ACCOUNT2).is_none());
+        assert!(Posts::poll_vote_deposit_by_account(POST1, ACCOUNT2).is_none());
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+    });
+}
+
+#[test]
+fn remove_poll_votes_should_fail_when_poll_is_not_removed() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_poll(10)); // PostId 1
+        assert_ok!(_vote_in_poll(None, 0));
+
+        assert_noop!(_remove_poll_1_votes(10), PostsError::<Test>::PollIsNotRemoved);
+    });
+}
+
+#[test]
+fn update_post_should_make_post_subscribers_only() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_make_post_1_subscribers_only());
+        assert!(Posts::post_by_id(POST1).unwrap().subscribers_only);
+
+        assert_ok!(_update_post(None, None, Some(post_subscribers_only_update(false))));
+        assert!(!Posts::post_by_id(POST1).unwrap().subscribers_only);
+    });
+}
+
+#[test]
+fn update_post_should_fail_to_make_post_subscribers_only_when_space_has_no_plan() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            _update_post(None, None, Some(post_subscribers_only_update(true))),
+            PostsError::<Test>::SpaceHasNoSubscriptionPlan
+        );
+    });
+}
+
+#[test]
+fn update_post_should_fail_to_make_comment_subscribers_only() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_set_subscription_plan_in_space_1());
+        assert_noop!(
+            _update_post(None, Some(POST2), Some(post_subscribers_only_update(true))),
+            PostsError::<Test>::CannotChangeCommentSubscribersOnly
+        );
+    });
+}
+
+#[test]
+fn create_comment_should_fail_on_subscribers_only_post_when_account_is_not_subscriber() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_make_post_1_subscribers_only());
+
+        assert_noop!(
+            _create_comment(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None),
+            PostsError::<Test>::OnlySubscribersCanComment
+        );
+
+        // The author of a post can always comment on it.
+        assert_ok!(_create_default_comment());
+    });
+}
+
+#[test]
+fn create_comment_should_work_on_subscribers_only_post_while_subscription_is_active() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_make_post_1_subscribers_only());
+        assert_ok!(Spaces::subscribe_to_space(RuntimeOrigin::signed(ACCOUNT2), SPACE1, 10));
+
+        assert_ok!(_create_comment(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None));
+
+        run_to_block(11);
+        assert_noop!(
+            _create_comment(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None),
+            PostsError::<Test>::OnlySubscribersCanComment
+        );
+    });
+}
+
 // TODO: refactor or remove. Deprecated tests
 // Find public post ids tests
 // --------------------------------------------------------------------------------------------
@@ -1,7 +1,9 @@
 use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
 
 use pallet_permissions::SpacePermission as SP;
 use pallet_posts::Error as PostsError;
+use subsocial_support::ModerationError;
 
 use crate::{mock::*, tests_utils::*};
 
@@ -18,11 +20,13 @@ fn share_post_should_work() {
         )); // Share PostId 1 on SpaceId 2 by ACCOUNT2 which is permitted by default in both spaces
 
         // Check storages
-        assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1]);
-        assert_eq!(Posts::post_ids_by_space_id(SPACE2), vec![POST2]);
+        assert_eq!(post_ids_by_space_id(SPACE1), vec![POST1]);
+        assert_eq!(post_ids_by_space_id(SPACE2), vec![POST2]);
         assert_eq!(Posts::next_post_id(), POST3);
 
-        assert_eq!(Posts::shared_post_ids_by_original_post_id(POST1), vec![POST2]);
+        assert_eq!(shared_post_ids_by_original_post_id(POST1), vec![POST2]);
+        assert_eq!(Posts::shared_post_count_by_original_post_id(POST1), 1);
+        assert_eq!(Posts::post_by_id(POST1).unwrap().shares_count, 1);
 
         let shared_post = Posts::post_by_id(POST2).unwrap();
 
@@ -32,6 +36,63 @@ fn share_post_should_work() {
     });
 }
 
+#[test]
+fn delete_shared_post_should_remove_it_from_shares_of_original_post() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // SpaceId 2 by ACCOUNT2
+        assert_ok!(_create_post(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            Some(Some(SPACE2)),
+            Some(extension_shared_post(POST1)),
+            None
+        )); // PostId 2
+
+        assert_ok!(_delete_post(Some(RuntimeOrigin::signed(ACCOUNT2)), Some(POST2)));
+
+        assert!(shared_post_ids_by_original_post_id(POST1).is_empty());
+        assert!(post_ids_by_space_id(SPACE2).is_empty());
+        assert_eq!(Posts::shared_post_count_by_original_post_id(POST1), 0);
+        assert_eq!(Posts::post_by_id(POST1).unwrap().shares_count, 0);
+        assert_eq!(Posts::post_count_by_space_id(SPACE2), 0);
+    });
+}
+
+#[test]
+fn share_post_should_fail_when_original_post_is_deleted() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_create_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // SpaceId 2 by ACCOUNT2
+        assert_ok!(_delete_default_post());
+
+        assert_noop!(
+            _create_post(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                Some(Some(SPACE2)),
+                Some(extension_shared_post(POST1)),
+                None
+            ),
+            PostsError::<Test>::PostIsDeleted
+        );
+    });
+}
+
+#[test]
+fn share_post_should_fail_when_original_space_is_blocked() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // SpaceId 2 by ACCOUNT2
+        block_space_1_in_space_2();
+
+        assert_noop!(
+            _create_post(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                Some(Some(SPACE2)),
+                Some(extension_shared_post(POST1)),
+                None
+            ),
+            DispatchError::Other(ModerationError::SpaceIsBlocked.into())
+        );
+    });
+}
+
 #[test]
 fn share_post_should_work_when_one_of_roles_is_permitted() {
     ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreatePosts]).execute_with(
@@ -70,10 +131,10 @@ fn share_post_should_work_for_share_own_post_in_same_own_space() {
         )); // Share PostId 1
 
         // Check storages
-        assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1, POST2]);
+        assert_eq!(post_ids_by_space_id(SPACE1), vec![POST1, POST2]);
         assert_eq!(Posts::next_post_id(), POST3);
 
-        assert_eq!(Posts::shared_post_ids_by_original_post_id(POST1), vec![POST2]);
+        assert_eq!(shared_post_ids_by_original_post_id(POST1), vec![POST2]);
 
         let shared_post = Posts::post_by_id(POST2).unwrap();
         assert_eq!(shared_post.space_id, Some(SPACE1));
@@ -10,7 +10,7 @@ use sp_io::TestExternalities;
 
 use pallet_permissions::{SpacePermission as SP, SpacePermission, SpacePermissions};
 use pallet_posts::{Comment, PostExtension, PostUpdate};
-use pallet_spaces::types::SpaceUpdate;
+use pallet_spaces::types::{SpaceUpdate, SubscriptionPlan};
 use subsocial_support::{
     mock_functions::*,
     traits::{IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked},
@@ -31,7 +31,7 @@ impl ExtBuilder {
         }
 
         let _ = pallet_balances::GenesisConfig::<Test> {
-            balances: accounts.iter().cloned().map(|k| (k, 100)).collect(),
+            balances: accounts.iter().cloned().map(|k| (k, 1000)).collect(),
         }
         .assimilate_storage(storage);
     }
@@ -151,6 +151,8 @@ impl Hash for EntityId {
                 Content::None => 0.hash(state),
                 Content::Other(content) => content.hash(state),
                 Content::IPFS(content) => content.hash(state),
+                Content::Arweave(content) => content.hash(state),
+                Content::Inline(content) => content.hash(state),
             },
             EntityId::Account(account) => account.hash(state),
             EntityId::Space(space) => space.hash(state),
@@ -247,6 +249,10 @@ pub(crate) fn block_content_in_space_1() {
     );
 }
 
+pub(crate) fn block_space_1_in_space_2() {
+    MockModeration::set_entity_status(EntityId::Space(SPACE1), SPACE2, EntityStatus::Blocked);
+}
+
 ///////////// Space Utils
 
 pub(crate) fn space_content_ipfs() -> Content {
@@ -254,11 +260,11 @@ pub(crate) fn space_content_ipfs() -> Content {
 }
 
 pub(crate) fn another_space_content_ipfs() -> Content {
-    Content::IPFS(b"bafyrelt3cif35x4ribisxgq7unhpun525l54eib3mgbou4xln42qqcgj6q".to_vec())
+    Content::IPFS(b"bafyreia2dve77krat5lsuqdep6xi6ing5hp5xfd6euqehvbvszg27v6he4".to_vec())
 }
 
 pub(crate) fn space_update(content: Option<Content>, hidden: Option<bool>) -> SpaceUpdate {
-    SpaceUpdate { content, hidden, permissions: None }
+    SpaceUpdate { content, handle: None, hidden, permissions: None }
 }
 
 pub(crate) fn _create_default_space() -> DispatchResult {
@@ -274,6 +280,7 @@ pub(crate) fn _create_space(
         origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
         content.unwrap_or_else(space_content_ipfs),
         permissions.unwrap_or_default(),
+        None,
     )
 }
 
@@ -308,7 +315,46 @@ pub(crate) fn post_update(
     content: Option<Content>,
     hidden: Option<bool>,
 ) -> PostUpdate {
-    PostUpdate { space_id, content, hidden }
+    PostUpdate { space_id, content, hidden, permissions: None, subscribers_only: None }
+}
+
+pub(crate) fn post_subscribers_only_update(subscribers_only: bool) -> PostUpdate {
+    PostUpdate { subscribers_only: Some(subscribers_only), ..Default::default() }
+}
+
+pub(crate) fn post_permissions_update(permissions: Option<SpacePermissions>) -> PostUpdate {
+    PostUpdate { permissions: Some(permissions), ..Default::default() }
+}
+
+/// Post permission overrides, where no one can comment on a post.
+pub(crate) fn permissions_where_no_one_can_comment() -> SpacePermissions {
+    SpacePermissions {
+        none: Some(vec![SP::CreateComments].into_iter().collect()),
+        ..Default::default()
+    }
+}
+
+/// Post permission overrides, where no one can vote in a poll.
+pub(crate) fn permissions_where_no_one_can_vote_in_polls() -> SpacePermissions {
+    SpacePermissions {
+        none: Some(vec![SP::VoteInPolls].into_iter().collect()),
+        ..Default::default()
+    }
+}
+
+/// Let anyone subscribe to space 1 for 10 blocks.
+pub(crate) fn _set_subscription_plan_in_space_1() -> DispatchResult {
+    Spaces::set_subscription_plan(
+        RuntimeOrigin::signed(ACCOUNT1),
+        SPACE1,
+        Some(SubscriptionPlan { price: 10, period: 10 }),
+    )
+}
+
+/// Make post 1 available only to subscribers of space 1.
+pub(crate) fn _make_post_1_subscribers_only() -> DispatchResult {
+    assert_ok!(_set_subscription_plan_in_space_1());
+    _update_post(None, None, Some(post_subscribers_only_update(true)))
 }
 
 pub(crate) fn comment_content_ipfs() -> Content {
@@ -331,6 +377,37 @@ pub(crate) fn extension_shared_post(original_post_id: PostId) -> PostExtension {
     PostExtension::SharedPost(original_post_id)
 }
 
+/// The deposit that should be held for a post in its current state.
+pub(crate) fn expected_post_deposit(post_id: PostId) -> Balance {
+    let post = Posts::post_by_id(post_id).unwrap();
+    let history = Posts::content_history_by_post_id(post_id);
+    let poll = Posts::poll_by_post_id(post_id);
+    (post.encoded_size() + history.encoded_size() + poll.encoded_size()) as Balance *
+        PostByteDeposit::get()
+}
+
+/// All root post ids in a given space, sorted, since paginated getters don't keep the order.
+pub(crate) fn post_ids_by_space_id(space_id: SpaceId) -> Vec<PostId> {
+    let mut post_ids = Posts::get_post_ids_by_space_id(space_id, None, u32::MAX);
+    post_ids.sort_unstable();
+    post_ids
+}
+
+/// All direct reply ids of a given post, sorted.
+pub(crate) fn reply_ids_by_post_id(post_id: PostId) -> Vec<PostId> {
+    let mut reply_ids = Posts::get_reply_ids_by_post_id(post_id, None, u32::MAX);
+    reply_ids.sort_unstable();
+    reply_ids
+}
+
+/// All ids of posts that shared a given post, sorted.
+pub(crate) fn shared_post_ids_by_original_post_id(post_id: PostId) -> Vec<PostId> {
+    let mut shared_post_ids =
+        Posts::get_shared_post_ids_by_original_post_id(post_id, None, u32::MAX);
+    shared_post_ids.sort_unstable();
+    shared_post_ids
+}
+
 pub(crate) fn _create_default_post() -> DispatchResult {
     _create_post(None, None, None, None)
 }
@@ -346,6 +423,7 @@ pub(crate) fn _create_post(
         space_id_opt.unwrap_or(Some(SPACE1)),
         extension.unwrap_or_else(extension_regular_post),
         content.unwrap_or_else(post_content_ipfs),
+        None,
     )
 }
 
@@ -382,6 +460,102 @@ pub(crate) fn _move_post(
     )
 }
 
+pub(crate) fn _delete_default_post() -> DispatchResult {
+    _delete_post(None, None)
+}
+
+pub(crate) fn _delete_post(
+    origin: Option<RuntimeOrigin>,
+    post_id: Option<PostId>,
+) -> DispatchResult {
+    Posts::delete_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        post_id.unwrap_or(POST1),
+    )
+}
+
+pub(crate) fn _create_scheduled_post(publish_at: BlockNumber) -> DispatchResult {
+    Posts::create_post(
+        RuntimeOrigin::signed(ACCOUNT1),
+        Some(SPACE1),
+        extension_regular_post(),
+        post_content_ipfs(),
+        Some(publish_at),
+    )
+}
+
+pub(crate) fn _cancel_scheduled_post(
+    origin: Option<RuntimeOrigin>,
+    post_id: Option<PostId>,
+) -> DispatchResult {
+    Posts::cancel_scheduled_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        post_id.unwrap_or(POST1),
+    )
+}
+
+/// Move to a given block and publish the posts scheduled at it.
+pub(crate) fn run_to_block(n: BlockNumber) {
+    System::set_block_number(n);
+    Posts::on_initialize(n);
+}
+
+pub(crate) fn _pin_default_post() -> DispatchResult {
+    _pin_post(None, None)
+}
+
+pub(crate) fn _pin_post(origin: Option<RuntimeOrigin>, post_id: Option<PostId>) -> DispatchResult {
+    Posts::pin_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        post_id.unwrap_or(POST1),
+    )
+}
+
+pub(crate) fn _unpin_post(
+    origin: Option<RuntimeOrigin>,
+    post_id: Option<PostId>,
+) -> DispatchResult {
+    Posts::unpin_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        post_id.unwrap_or(POST1),
+    )
+}
+
+pub(crate) fn poll_options(count: usize) -> Vec<Content> {
+    vec![post_content_ipfs(); count]
+}
+
+/// Create a poll with two options in space 1, that is closed at a given block.
+pub(crate) fn _create_default_poll(closes_at: BlockNumber) -> DispatchResult {
+    _create_poll(None, poll_options(2), closes_at)
+}
+
+pub(crate) fn _create_poll(
+    origin: Option<RuntimeOrigin>,
+    options: Vec<Content>,
+    closes_at: BlockNumber,
+) -> DispatchResult {
+    Posts::create_poll(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        SPACE1,
+        post_content_ipfs(),
+        options,
+        closes_at,
+    )
+}
+
+pub(crate) fn _vote_in_poll(origin: Option<RuntimeOrigin>, option_index: u32) -> DispatchResult {
+    Posts::vote_in_poll(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        POST1,
+        option_index,
+    )
+}
+
+pub(crate) fn _remove_poll_1_votes(limit: u32) -> DispatchResultWithPostInfo {
+    Posts::remove_poll_votes(RuntimeOrigin::signed(ACCOUNT2), POST1, limit)
+}
+
 pub(crate) fn _create_default_comment() -> DispatchResult {
     _create_comment(None, None, None, None)
 }
@@ -3,20 +3,27 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_support::dispatch::DispatchError;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{dispatch::DispatchError, sp_runtime::traits::Bounded, traits::Currency};
 use frame_system::RawOrigin;
 use pallet_posts::{Post, PostExtension};
-use pallet_spaces::types::Space;
+use pallet_spaces::types::{BalanceOf, Space};
 use sp_std::vec;
 use subsocial_support::Content;
 
 fn create_dummy_space<T: Config>(
     origin: RawOrigin<T::AccountId>,
 ) -> Result<Space<T>, DispatchError> {
+    if let RawOrigin::Signed(owner) = &origin {
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            owner,
+            BalanceOf::<T>::max_value(),
+        );
+    }
+
     let space_id = pallet_spaces::NextSpaceId::<T>::get();
 
-    pallet_spaces::Pallet::<T>::create_space(origin.clone().into(), Content::None, None)?;
+    pallet_spaces::Pallet::<T>::create_space(origin.clone().into(), Content::None, None, None)?;
 
     let space = pallet_spaces::SpaceById::<T>::get(space_id)
         .ok_or(DispatchError::Other("Space not found"))?;
@@ -33,6 +40,7 @@ fn create_dummy_post<T: Config>(origin: RawOrigin<T::AccountId>) -> Result<Post<
         Some(space.id),
         PostExtension::RegularPost,
         Content::None,
+        None,
     )?;
 
     let post = PostById::<T>::get(post_id).ok_or(DispatchError::Other("Post not found"))?;
@@ -54,6 +62,20 @@ fn create_dummy_post_reaction<T: Config>(
     Ok((post, reaction))
 }
 
+fn create_dummy_space_reaction<T: Config>(
+    origin: RawOrigin<T::AccountId>,
+) -> Result<(Space<T>, Reaction<T>), DispatchError> {
+    let space = create_dummy_space::<T>(origin.clone())?;
+    let reaction_id = NextReactionId::<T>::get();
+
+    Pallet::<T>::create_space_reaction(origin.clone().into(), space.id, ReactionKind::Upvote)?;
+
+    let reaction = ReactionById::<T>::get(reaction_id)
+        .ok_or(DispatchError::Other("Reaction not found"))?;
+
+    Ok((space, reaction))
+}
+
 benchmarks! {
     create_post_reaction {
         let origin = RawOrigin::Signed(whitelisted_caller());
@@ -61,7 +83,7 @@ benchmarks! {
         let reaction_kind = ReactionKind::Upvote;
         let reaction_id = NextReactionId::<T>::get();
 
-    }: _(origin, post.id, reaction_kind)
+    }: _(origin, post.id, reaction_kind.clone())
     verify {
         ensure!(ReactionIdsByPostId::<T>::get(post.id) == vec![reaction_id], "Incorrect reaction in storage");
         ensure!(
@@ -77,9 +99,9 @@ benchmarks! {
         let (post, reaction) = create_dummy_post_reaction::<T>(origin.clone())?;
         let other_kind = match reaction.kind {
             ReactionKind::Upvote => ReactionKind::Downvote,
-            ReactionKind::Downvote => ReactionKind::Upvote,
+            ReactionKind::Downvote | ReactionKind::Custom(_) => ReactionKind::Upvote,
         };
-    }: _(origin, post.id, reaction.id, other_kind)
+    }: _(origin, post.id, reaction.id, other_kind.clone())
     verify {
         ensure!(
             ReactionById::<T>::get(reaction.id)
@@ -99,4 +121,111 @@ benchmarks! {
         ensure!(ReactionIdsByPostId::<T>::get(post.id).is_empty(), "Reaction was not deleted by post id");
         ensure!(ReactionById::<T>::get(reaction.id) == None, "Reaction was not deleted");
     }
+
+    tip_post {
+        let author: T::AccountId = account("author", 0, 0);
+        let post = create_dummy_post::<T>(RawOrigin::Signed(author.clone()))?;
+        let amount = <T as pallet_spaces::Config>::Currency::minimum_balance()
+            .max(BalanceOf::<T>::from(1u32));
+        // Leave room in the author's balance to receive a tip.
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(&author, amount);
+
+        let tipper: T::AccountId = whitelisted_caller();
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            &tipper,
+            BalanceOf::<T>::max_value(),
+        );
+    }: _(RawOrigin::Signed(tipper), post.id, amount)
+    verify {
+        ensure!(TipsTotalByPostId::<T>::get(post.id) == amount, "Tips total was not updated");
+    }
+
+    set_custom_reaction_kinds {
+        let n in 0 .. T::MaxCustomReactionKinds::get();
+
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let kinds: Vec<Emoji> = (0..n)
+            .map(|i| {
+                vec![b'a' + (i % 26) as u8, b'a' + (i / 26 % 26) as u8]
+                    .try_into()
+                    .expect("qed; emoji is shorter than MAX_EMOJI_LEN")
+            })
+            .collect();
+    }: _(origin, space.id, kinds)
+    verify {
+        ensure!(
+            CustomReactionKindsBySpaceId::<T>::get(space.id).len() == n as usize,
+            "Custom reaction kinds were not updated"
+        );
+    }
+
+    create_space_reaction {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let reaction_kind = ReactionKind::Upvote;
+        let reaction_id = NextReactionId::<T>::get();
+
+    }: _(origin, space.id, reaction_kind.clone())
+    verify {
+        ensure!(ReactionIdsBySpaceId::<T>::contains_key(space.id, reaction_id), "Incorrect reaction in storage");
+        ensure!(
+            ReactionById::<T>::get(reaction_id)
+                .expect("Reaction not found")
+                .kind == reaction_kind,
+            "Reaction kind does not match"
+        );
+    }
+
+    update_space_reaction {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let (space, reaction) = create_dummy_space_reaction::<T>(origin.clone())?;
+        let other_kind = match reaction.kind {
+            ReactionKind::Upvote => ReactionKind::Downvote,
+            ReactionKind::Downvote | ReactionKind::Custom(_) => ReactionKind::Upvote,
+        };
+    }: _(origin, space.id, reaction.id, other_kind.clone())
+    verify {
+        ensure!(
+            ReactionById::<T>::get(reaction.id)
+                .expect("Reaction not found")
+                .kind == other_kind,
+            "Reaction kind does not match"
+        );
+    }
+
+    delete_space_reaction {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let (space, reaction) = create_dummy_space_reaction::<T>(origin.clone())?;
+
+        ensure!(ReactionIdsBySpaceId::<T>::contains_key(space.id, reaction.id), "Incorrect reaction in storage");
+    }: _(origin, space.id, reaction.id)
+    verify {
+        ensure!(!ReactionIdsBySpaceId::<T>::contains_key(space.id, reaction.id), "Reaction was not deleted by space id");
+        ensure!(ReactionById::<T>::get(reaction.id) == None, "Reaction was not deleted");
+    }
+
+    remove_post_reactions {
+        let n in 0 .. 100;
+
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let post = create_dummy_post::<T>(origin.clone())?;
+
+        for i in 0..n {
+            let reactor = account::<T::AccountId>("reactor", i, 0);
+            <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+                &reactor,
+                BalanceOf::<T>::max_value(),
+            );
+            Pallet::<T>::create_post_reaction(
+                RawOrigin::Signed(reactor).into(),
+                post.id,
+                ReactionKind::Upvote,
+            )?;
+        }
+        PostById::<T>::remove(post.id);
+    }: _(origin, post.id, n)
+    verify {
+        ensure!(ReactionIdsByPostId::<T>::get(post.id).is_empty(), "Post reactions should be removed");
+    }
 }
@@ -1,38 +1,68 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get};
+use frame_support::{
+    dispatch::DispatchResult,
+    ensure,
+    traits::{ConstU32, Currency, ExistenceRequirement, Get},
+    BoundedVec,
+};
 use frame_system::ensure_signed;
 use scale_info::TypeInfo;
 
 #[cfg(feature = "std")]
 use serde::Deserialize;
-use sp_runtime::{DispatchError, RuntimeDebug};
-use sp_std::prelude::*;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    DispatchError, RuntimeDebug,
+};
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
 
 use pallet_permissions::SpacePermission;
-use pallet_posts::{Pallet as Posts, PostById};
-use pallet_spaces::Pallet as Spaces;
+use pallet_posts::{Pallet as Posts, Post, PostById};
+use pallet_spaces::{
+    types::{BalanceOf, Space, StorageDepositOf},
+    Pallet as Spaces, SpaceById,
+};
 use subsocial_support::{
-    new_who_and_when, remove_from_vec, traits::IsAccountBlocked, ModerationError, PostId,
-    WhoAndWhenOf,
+    new_who_and_when, refund_storage_deposit, remove_from_vec,
+    traits::{BeforePostDeleted, BeforeSpaceDeleted, IsAccountBlocked},
+    update_storage_deposit, ModerationError, PostId, SpaceId, WhoAndWhenOf,
 };
 
 pub use pallet::*;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 pub mod weights;
 
 // pub mod rpc;
 
 pub type ReactionId = u64;
 
-#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+/// The max length of a custom reaction kind in bytes.
+pub const MAX_EMOJI_LEN: u32 = 32;
+
+/// A custom reaction kind: a short UTF-8 string, usually a single emoji like `❤️` or `🎉`.
+pub type Emoji = BoundedVec<u8, ConstU32<MAX_EMOJI_LEN>>;
+
+pub(crate) type CustomReactionKinds<T> = BoundedVec<Emoji, <T as Config>::MaxCustomReactionKinds>;
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Deserialize))]
 #[cfg_attr(feature = "std", serde(untagged))]
 pub enum ReactionKind {
     Upvote,
     Downvote,
+    /// One of the custom reaction kinds allowed in the space of a post, or in a reacted space.
+    Custom(Emoji),
+}
+
+/// An entity that can be reacted on.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ReactionTarget {
+    Post(PostId),
+    Space(SpaceId),
 }
 
 impl Default for ReactionKind {
@@ -63,16 +93,28 @@ pub mod pallet {
 
     use crate::weights::WeightInfo;
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_posts::Config + pallet_spaces::Config {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// The amount held on deposit per byte of an encoded reaction.
+        #[pallet::constant]
+        type ReactionByteDeposit: Get<BalanceOf<Self>>;
+
+        /// Max number of custom reaction kinds that can be allowed in a space.
+        #[pallet::constant]
+        type MaxCustomReactionKinds: Get<u32>;
+
         type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
@@ -101,6 +143,67 @@ pub mod pallet {
     pub type PostReactionIdByAccount<T: Config> =
         StorageMap<_, Blake2_128Concat, (T::AccountId, PostId), ReactionId, ValueQuery>;
 
+    /// Reactions on a space, by the space id and the reaction id.
+    /// See `get_reaction_ids_by_space_id` to page through them.
+    #[pallet::storage]
+    pub type ReactionIdsBySpaceId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Twox64Concat, ReactionId, ()>;
+
+    /// The number of reactions on a space, by the space id.
+    #[pallet::storage]
+    #[pallet::getter(fn reaction_count_by_space_id)]
+    pub type ReactionCountBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn space_reaction_id_by_account)]
+    pub type SpaceReactionIdByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, (T::AccountId, SpaceId), ReactionId, ValueQuery>;
+
+    /// Reactions made by an account, by the account and the reaction id.
+    /// The value is what was reacted on and the current kind of the reaction.
+    /// See `get_reactions_by_account` to page through them.
+    #[pallet::storage]
+    pub type ReactionIdsByAccount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        ReactionId,
+        (ReactionTarget, ReactionKind),
+    >;
+
+    /// The number of reactions made by an account.
+    #[pallet::storage]
+    #[pallet::getter(fn reaction_count_by_account)]
+    pub type ReactionCountByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// The deposit held for storing a reaction, by the reaction id.
+    #[pallet::storage]
+    #[pallet::getter(fn deposit_by_reaction_id)]
+    pub type DepositByReactionId<T: Config> =
+        StorageMap<_, Twox64Concat, ReactionId, StorageDepositOf<T>>;
+
+    /// Custom reaction kinds allowed in a space, by the space id.
+    #[pallet::storage]
+    #[pallet::getter(fn custom_reaction_kinds_by_space_id)]
+    pub type CustomReactionKindsBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, CustomReactionKinds<T>, ValueQuery>;
+
+    /// The number of custom reactions of each kind on a post or space,
+    /// by the reaction target and the kind.
+    #[pallet::storage]
+    #[pallet::getter(fn custom_reaction_count_by_target)]
+    pub type CustomReactionCountByTarget<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, ReactionTarget, Blake2_128Concat, Emoji, u32, ValueQuery>;
+
+    /// The total amount of tips received by a post author for this post.
+    #[pallet::storage]
+    #[pallet::getter(fn tips_total_by_post_id)]
+    pub type TipsTotalByPostId<T: Config> =
+        StorageMap<_, Twox64Concat, PostId, BalanceOf<T>, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -122,15 +225,51 @@ pub mod pallet {
             reaction_id: ReactionId,
             reaction_kind: ReactionKind,
         },
+        PostTipped {
+            account: T::AccountId,
+            post_id: PostId,
+            space_id: SpaceId,
+            amount: BalanceOf<T>,
+        },
+        CustomReactionKindsUpdated {
+            account: T::AccountId,
+            space_id: SpaceId,
+        },
+        SpaceReactionCreated {
+            account: T::AccountId,
+            space_id: SpaceId,
+            reaction_id: ReactionId,
+            reaction_kind: ReactionKind,
+        },
+        SpaceReactionUpdated {
+            account: T::AccountId,
+            space_id: SpaceId,
+            reaction_id: ReactionId,
+            reaction_kind: ReactionKind,
+        },
+        SpaceReactionDeleted {
+            account: T::AccountId,
+            space_id: SpaceId,
+            reaction_id: ReactionId,
+            reaction_kind: ReactionKind,
+        },
+        PostReactionsRemoved {
+            post_id: PostId,
+            removed_count: u32,
+        },
+        PostReactionsPartiallyRemoved {
+            post_id: PostId,
+            removed_count: u32,
+        },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Reaction was not found by id.
         ReactionNotFound,
-        /// Account has already reacted to this post/comment.
+        /// Account has already reacted to this post/comment or space.
         AccountAlreadyReacted,
-        /// There is no reaction by account on this post/comment.
+        /// There is no reaction by account on this post/comment or space.
         ReactionByAccountNotFound,
         /// Only reaction owner can update their reaction.
         NotReactionOwner,
@@ -151,6 +290,40 @@ pub mod pallet {
         NoPermissionToUpvote,
         /// User has no permission to downvote posts/comments in this space.
         NoPermissionToDownvote,
+        /// Only active subscribers of a space can react on its' subscribers-only posts/comments.
+        OnlySubscribersCanReact,
+        /// User has no permission to react with custom reaction kinds in this space.
+        NoPermissionToReactWithCustomKinds,
+        /// User has no permission to manage custom reaction kinds of this space.
+        NoPermissionToManageCustomReactionKinds,
+
+        /// This custom reaction kind is not allowed in the space of a post/comment.
+        CustomReactionKindNotAllowed,
+        /// Custom reaction kind should be a non-empty UTF-8 string.
+        InvalidCustomReactionKind,
+        /// Custom reaction kinds of a space should not repeat.
+        DuplicateCustomReactionKind,
+        /// There are too many custom reaction kinds for a space.
+        TooManyCustomReactionKinds,
+
+        /// Not allowed to tip a post/comment in a hidden space.
+        CannotTipWhenSpaceHidden,
+        /// Not allowed to tip a post/comment if a root post is hidden.
+        CannotTipWhenPostHidden,
+        /// Post/comment author cannot tip their own post/comment.
+        CannotTipOwnPost,
+        /// Tip amount should be greater than zero.
+        ZeroTipAmount,
+
+        /// Anyone can remove the reactions of a post/comment only after it's deleted.
+        PostIsNotDeleted,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: T::BlockNumber) -> Weight {
+            migration::v1::migrate_reactions::<T>(migration::MIGRATED_REACTIONS_PER_BLOCK)
+        }
     }
 
     #[pallet::call]
@@ -165,13 +338,15 @@ pub mod pallet {
             let owner = ensure_signed(origin)?;
 
             let post = &mut Posts::require_post(post_id)?;
+            Posts::<T>::ensure_post_not_deleted(post_id)?;
+            Posts::<T>::ensure_post_is_published(post_id)?;
             ensure!(
                 !<PostReactionIdByAccount<T>>::contains_key((owner.clone(), post_id)),
                 Error::<T>::AccountAlreadyReacted
             );
 
             let space = post.get_space()?;
-            ensure!(!space.hidden, Error::<T>::CannotReactWhenSpaceHidden);
+            ensure!(!space.is_hidden_in_hierarchy(), Error::<T>::CannotReactWhenSpaceHidden);
             ensure!(
                 Posts::<T>::is_root_post_visible(post_id)?,
                 Error::<T>::CannotReactWhenPostHidden
@@ -181,32 +356,26 @@ pub mod pallet {
                 T::IsAccountBlocked::is_allowed_account(owner.clone(), space.id),
                 ModerationError::AccountIsBlocked
             );
+            Posts::<T>::ensure_account_can_access_post(
+                &owner,
+                post,
+                &space,
+                Error::<T>::OnlySubscribersCanReact.into(),
+            )?;
 
-            match kind {
-                ReactionKind::Upvote => {
-                    Spaces::ensure_account_has_space_permission(
-                        owner.clone(),
-                        &post.get_space()?,
-                        SpacePermission::Upvote,
-                        Error::<T>::NoPermissionToUpvote.into(),
-                    )?;
-                    post.inc_upvotes();
-                },
-                ReactionKind::Downvote => {
-                    Spaces::ensure_account_has_space_permission(
-                        owner.clone(),
-                        &post.get_space()?,
-                        SpacePermission::Downvote,
-                        Error::<T>::NoPermissionToDownvote.into(),
-                    )?;
-                    post.inc_downvotes();
-                },
-            }
+            Self::ensure_can_react_on_post(owner.clone(), post, &space, &kind)?;
+            Self::inc_post_reaction_count(post, &kind);
 
             PostById::<T>::insert(post_id, post.clone());
-            let reaction_id = Self::insert_new_reaction(owner.clone(), kind);
+            let reaction_id = Self::insert_new_reaction(owner.clone(), kind.clone())?;
             ReactionIdsByPostId::<T>::mutate(post.id, |ids| ids.push(reaction_id));
             PostReactionIdByAccount::<T>::insert((owner.clone(), post_id), reaction_id);
+            Self::insert_account_reaction(
+                &owner,
+                reaction_id,
+                ReactionTarget::Post(post_id),
+                kind.clone(),
+            );
 
             Self::deposit_event(Event::PostReactionCreated {
                 account: owner,
@@ -234,6 +403,7 @@ pub mod pallet {
 
             let mut reaction = Self::require_reaction(reaction_id)?;
             let post = &mut Posts::require_post(post_id)?;
+            Posts::<T>::ensure_post_not_deleted(post_id)?;
 
             ensure!(owner == reaction.created.account, Error::<T>::NotReactionOwner);
             ensure!(reaction.kind != new_kind, Error::<T>::SameReaction);
@@ -245,21 +415,22 @@ pub mod pallet {
                 );
             }
 
-            reaction.kind = new_kind;
+            let space = post.get_space()?;
+            Self::ensure_can_react_on_post(owner.clone(), post, &space, &new_kind)?;
 
-            match new_kind {
-                ReactionKind::Upvote => {
-                    post.inc_upvotes();
-                    post.dec_downvotes();
-                },
-                ReactionKind::Downvote => {
-                    post.inc_downvotes();
-                    post.dec_upvotes();
-                },
-            }
+            Self::dec_post_reaction_count(post, &reaction.kind);
+            Self::inc_post_reaction_count(post, &new_kind);
+            reaction.kind = new_kind.clone();
 
+            Self::update_reaction_deposit(&reaction)?;
             ReactionById::<T>::insert(reaction_id, reaction);
             PostById::<T>::insert(post_id, post);
+            Self::insert_account_reaction(
+                &owner,
+                reaction_id,
+                ReactionTarget::Post(post_id),
+                new_kind.clone(),
+            );
 
             Self::deposit_event(Event::PostReactionUpdated {
                 account: owner,
@@ -286,25 +457,36 @@ pub mod pallet {
 
             // TODO extract Self::require_reaction(reaction_id)?;
             let reaction = Self::require_reaction(reaction_id)?;
-            let post = &mut Posts::require_post(post_id)?;
-
             ensure!(owner == reaction.created.account, Error::<T>::NotReactionOwner);
-            if let Some(space_id) = post.try_get_space_id() {
-                ensure!(
-                    T::IsAccountBlocked::is_allowed_account(owner.clone(), space_id),
-                    ModerationError::AccountIsBlocked
-                );
-            }
 
-            match reaction.kind {
-                ReactionKind::Upvote => post.dec_upvotes(),
-                ReactionKind::Downvote => post.dec_downvotes(),
+            Posts::<T>::ensure_post_migrated(post_id)?;
+
+            // Reactions on a deleted post are kept until their owners delete them,
+            // so only the custom reaction counters are left to update in that case.
+            match Posts::<T>::post_by_id(post_id).filter(|_| !Posts::<T>::is_post_deleted(post_id))
+            {
+                Some(mut post) => {
+                    if let Some(space_id) = post.try_get_space_id() {
+                        ensure!(
+                            T::IsAccountBlocked::is_allowed_account(owner.clone(), space_id),
+                            ModerationError::AccountIsBlocked
+                        );
+                    }
+
+                    Self::dec_post_reaction_count(&mut post, &reaction.kind);
+                    PostById::<T>::insert(post_id, post);
+                },
+                None =>
+                    if let ReactionKind::Custom(emoji) = &reaction.kind {
+                        Self::dec_custom_reaction_count(ReactionTarget::Post(post_id), emoji);
+                    },
             }
 
-            PostById::<T>::insert(post_id, post.clone());
             ReactionById::<T>::remove(reaction_id);
-            ReactionIdsByPostId::<T>::mutate(post.id, |ids| remove_from_vec(ids, reaction_id));
+            ReactionIdsByPostId::<T>::mutate(post_id, |ids| remove_from_vec(ids, reaction_id));
             PostReactionIdByAccount::<T>::remove((owner.clone(), post_id));
+            Self::remove_account_reaction(&owner, reaction_id);
+            Self::refund_reaction_deposit(reaction_id);
 
             Self::deposit_event(Event::PostReactionDeleted {
                 account: owner,
@@ -317,7 +499,7 @@ pub mod pallet {
 
         #[pallet::call_index(3)]
         #[pallet::weight((
-            Weight::from_ref_time(100_000) + T::DbWeight::get().reads_writes(2, 3),
+            Weight::from_ref_time(100_000) + T::DbWeight::get().reads_writes(4, 5),
             DispatchClass::Operational,
             Pays::Yes,
         ))]
@@ -337,11 +519,20 @@ pub mod pallet {
             let new_who_and_when =
                 WhoAndWhen { account, block: frame_system::Pallet::<T>::block_number(), time };
 
-            let reaction =
-                Reaction { id: reaction_id, created: new_who_and_when, kind: reaction_kind };
+            let reaction = Reaction {
+                id: reaction_id,
+                created: new_who_and_when,
+                kind: reaction_kind.clone(),
+            };
             ReactionById::<T>::insert(reaction_id, reaction);
             ReactionIdsByPostId::<T>::mutate(post_id, |ids| ids.push(reaction_id));
             PostReactionIdByAccount::<T>::insert((who.clone(), post_id), reaction_id);
+            Self::insert_account_reaction(
+                &who,
+                reaction_id,
+                ReactionTarget::Post(post_id),
+                reaction_kind.clone(),
+            );
 
             Self::deposit_event(Event::PostReactionCreated {
                 account: who,
@@ -355,7 +546,7 @@ pub mod pallet {
 
         #[pallet::call_index(4)]
         #[pallet::weight((
-            Weight::from_ref_time(10_000) + T::DbWeight::get().reads_writes(3, 3),
+            Weight::from_ref_time(10_000) + T::DbWeight::get().reads_writes(7, 7),
             DispatchClass::Operational,
             Pays::Yes,
         ))]
@@ -382,7 +573,9 @@ pub mod pallet {
                 Ok(Pays::No.into())
             })?;
             ReactionById::<T>::remove(reaction_id);
-            PostReactionIdByAccount::<T>::remove((who, post_id));
+            PostReactionIdByAccount::<T>::remove((who.clone(), post_id));
+            Self::remove_account_reaction(&who, reaction_id);
+            Self::refund_reaction_deposit(reaction_id);
 
             Ok(Pays::No.into())
         }
@@ -401,24 +594,562 @@ pub mod pallet {
             NextReactionId::<T>::put(reaction_id);
             Ok(Pays::No.into())
         }
+
+        /// Transfer `amount` of native tokens from the caller to the author of a post/comment.
+        #[pallet::call_index(6)]
+        #[pallet::weight(< T as Config >::WeightInfo::tip_post())]
+        pub fn tip_post(
+            origin: OriginFor<T>,
+            post_id: PostId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let tipper = ensure_signed(origin)?;
+
+            ensure!(!amount.is_zero(), Error::<T>::ZeroTipAmount);
+
+            let post = Posts::<T>::require_post(post_id)?;
+            Posts::<T>::ensure_post_not_deleted(post_id)?;
+            Posts::<T>::ensure_post_is_published(post_id)?;
+            ensure!(tipper != post.owner, Error::<T>::CannotTipOwnPost);
+
+            let space = post.get_space()?;
+            ensure!(!space.is_hidden_in_hierarchy(), Error::<T>::CannotTipWhenSpaceHidden);
+            ensure!(
+                Posts::<T>::is_root_post_visible(post_id)?,
+                Error::<T>::CannotTipWhenPostHidden
+            );
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(tipper.clone(), space.id),
+                ModerationError::AccountIsBlocked
+            );
+
+            <T as pallet_spaces::Config>::Currency::transfer(
+                &tipper,
+                &post.owner,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            TipsTotalByPostId::<T>::mutate(post_id, |total| *total = total.saturating_add(amount));
+
+            Self::deposit_event(Event::PostTipped {
+                account: tipper,
+                post_id,
+                space_id: space.id,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Replace the custom reaction kinds allowed in a space. Existing reactions of kinds,
+        /// that are not allowed anymore, are kept, but no new reactions of these kinds
+        /// can be added.
+        #[pallet::call_index(7)]
+        #[pallet::weight(
+            < T as Config >::WeightInfo::set_custom_reaction_kinds(kinds.len() as u32)
+        )]
+        pub fn set_custom_reaction_kinds(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            kinds: Vec<Emoji>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Spaces::<T>::require_space(space_id)?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Spaces::<T>::ensure_account_has_space_permission(
+                who.clone(),
+                &space,
+                SpacePermission::UpdateSpaceSettings,
+                Error::<T>::NoPermissionToManageCustomReactionKinds.into(),
+            )?;
+
+            let mut unique_kinds = BTreeSet::new();
+            for kind in kinds.iter() {
+                ensure!(
+                    !kind.is_empty() && sp_std::str::from_utf8(kind).is_ok(),
+                    Error::<T>::InvalidCustomReactionKind
+                );
+                ensure!(unique_kinds.insert(kind), Error::<T>::DuplicateCustomReactionKind);
+            }
+
+            let kinds: CustomReactionKinds<T> =
+                kinds.try_into().map_err(|_| Error::<T>::TooManyCustomReactionKinds)?;
+
+            if kinds.is_empty() {
+                CustomReactionKindsBySpaceId::<T>::remove(space_id);
+            } else {
+                CustomReactionKindsBySpaceId::<T>::insert(space_id, kinds);
+            }
+
+            Self::deposit_event(Event::CustomReactionKindsUpdated { account: who, space_id });
+            Ok(())
+        }
+
+        #[pallet::call_index(8)]
+        #[pallet::weight(< T as Config >::WeightInfo::create_space_reaction())]
+        pub fn create_space_reaction(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            kind: ReactionKind,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            let space = &mut Spaces::<T>::require_space(space_id)?;
+            ensure!(
+                !<SpaceReactionIdByAccount<T>>::contains_key((owner.clone(), space_id)),
+                Error::<T>::AccountAlreadyReacted
+            );
+
+            ensure!(!space.is_hidden_in_hierarchy(), Error::<T>::CannotReactWhenSpaceHidden);
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(owner.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Self::ensure_can_react_on_space(owner.clone(), space, &kind)?;
+            Self::inc_space_reaction_count(space, &kind);
+
+            SpaceById::<T>::insert(space_id, space.clone());
+            let reaction_id = Self::insert_new_reaction(owner.clone(), kind.clone())?;
+            Self::add_space_reaction(space_id, reaction_id);
+            SpaceReactionIdByAccount::<T>::insert((owner.clone(), space_id), reaction_id);
+            Self::insert_account_reaction(
+                &owner,
+                reaction_id,
+                ReactionTarget::Space(space_id),
+                kind.clone(),
+            );
+
+            Self::deposit_event(Event::SpaceReactionCreated {
+                account: owner,
+                space_id,
+                reaction_id,
+                reaction_kind: kind,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(9)]
+        #[pallet::weight(< T as Config >::WeightInfo::update_space_reaction())]
+        pub fn update_space_reaction(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            reaction_id: ReactionId,
+            new_kind: ReactionKind,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            ensure!(
+                <SpaceReactionIdByAccount<T>>::contains_key((owner.clone(), space_id)),
+                Error::<T>::ReactionByAccountNotFound
+            );
+
+            let mut reaction = Self::require_reaction(reaction_id)?;
+            let space = &mut Spaces::<T>::require_space(space_id)?;
+
+            ensure!(owner == reaction.created.account, Error::<T>::NotReactionOwner);
+            ensure!(reaction.kind != new_kind, Error::<T>::SameReaction);
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(owner.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Self::ensure_can_react_on_space(owner.clone(), space, &new_kind)?;
+
+            Self::dec_space_reaction_count(space, &reaction.kind);
+            Self::inc_space_reaction_count(space, &new_kind);
+            reaction.kind = new_kind.clone();
+
+            Self::update_reaction_deposit(&reaction)?;
+            ReactionById::<T>::insert(reaction_id, reaction);
+            SpaceById::<T>::insert(space_id, space);
+            Self::insert_account_reaction(
+                &owner,
+                reaction_id,
+                ReactionTarget::Space(space_id),
+                new_kind.clone(),
+            );
+
+            Self::deposit_event(Event::SpaceReactionUpdated {
+                account: owner,
+                space_id,
+                reaction_id,
+                reaction_kind: new_kind,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(10)]
+        #[pallet::weight(< T as Config >::WeightInfo::delete_space_reaction())]
+        pub fn delete_space_reaction(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            reaction_id: ReactionId,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            ensure!(
+                <SpaceReactionIdByAccount<T>>::contains_key((owner.clone(), space_id)),
+                Error::<T>::ReactionByAccountNotFound
+            );
+
+            let reaction = Self::require_reaction(reaction_id)?;
+            ensure!(owner == reaction.created.account, Error::<T>::NotReactionOwner);
+
+            // Reactions on a deleted space are kept until their owners delete them,
+            // so only the custom reaction counters are left to update in that case.
+            if Spaces::<T>::ensure_space_exists(space_id).is_ok() {
+                let space = &mut Spaces::<T>::require_space(space_id)?;
+                ensure!(
+                    T::IsAccountBlocked::is_allowed_account(owner.clone(), space_id),
+                    ModerationError::AccountIsBlocked
+                );
+
+                Self::dec_space_reaction_count(space, &reaction.kind);
+                SpaceById::<T>::insert(space_id, space.clone());
+            } else if let ReactionKind::Custom(emoji) = &reaction.kind {
+                Self::dec_custom_reaction_count(ReactionTarget::Space(space_id), emoji);
+            }
+
+            ReactionById::<T>::remove(reaction_id);
+            Self::remove_space_reaction(space_id, reaction_id);
+            SpaceReactionIdByAccount::<T>::remove((owner.clone(), space_id));
+            Self::remove_account_reaction(&owner, reaction_id);
+            Self::refund_reaction_deposit(reaction_id);
+
+            Self::deposit_event(Event::SpaceReactionDeleted {
+                account: owner,
+                space_id,
+                reaction_id,
+                reaction_kind: reaction.kind,
+            });
+            Ok(())
+        }
+
+        /// Remove the reactions of a deleted post/comment and return their deposits
+        /// to the reaction owners. Anyone can call this dispatch.
+        ///
+        /// At most `limit` reactions are removed in one call. If there are more of them,
+        /// call this again with the same `post_id` to continue where the previous call stopped.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_post_reactions(*limit))]
+        pub fn remove_post_reactions(
+            origin: OriginFor<T>,
+            post_id: PostId,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            Posts::<T>::ensure_post_migrated(post_id)?;
+            ensure!(
+                !PostById::<T>::contains_key(post_id) || Posts::<T>::is_post_deleted(post_id),
+                Error::<T>::PostIsNotDeleted
+            );
+
+            let (removed_count, completed) = Self::do_remove_post_reactions(post_id, limit);
+
+            if completed {
+                Self::deposit_event(Event::PostReactionsRemoved { post_id, removed_count });
+            } else {
+                Self::deposit_event(Event::PostReactionsPartiallyRemoved {
+                    post_id,
+                    removed_count,
+                });
+            }
+
+            let actual_weight = <T as Config>::WeightInfo::remove_post_reactions(removed_count);
+            Ok(Some(actual_weight).into())
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
-    pub fn insert_new_reaction(account: T::AccountId, kind: ReactionKind) -> ReactionId {
+    pub fn insert_new_reaction(
+        account: T::AccountId,
+        kind: ReactionKind,
+    ) -> Result<ReactionId, DispatchError> {
         let id = Self::next_reaction_id();
-        let reaction: Reaction<T> = Reaction { id, created: new_who_and_when::<T>(account), kind };
+        let reaction: Reaction<T> =
+            Reaction { id, created: new_who_and_when::<T>(account.clone()), kind };
+
+        Self::update_reaction_deposit(&reaction)?;
 
         ReactionById::<T>::insert(id, reaction);
         NextReactionId::<T>::mutate(|n| {
             *n += 1;
         });
 
-        id
+        Ok(id)
+    }
+
+    /// List a reaction in the reactions of its owner, or update its kind if it's already listed.
+    pub(crate) fn insert_account_reaction(
+        account: &T::AccountId,
+        reaction_id: ReactionId,
+        target: ReactionTarget,
+        kind: ReactionKind,
+    ) {
+        if !ReactionIdsByAccount::<T>::contains_key(account, reaction_id) {
+            ReactionCountByAccount::<T>::mutate(account, |count| *count = count.saturating_add(1));
+        }
+        ReactionIdsByAccount::<T>::insert(account, reaction_id, (target, kind));
+    }
+
+    pub(crate) fn remove_account_reaction(account: &T::AccountId, reaction_id: ReactionId) {
+        if ReactionIdsByAccount::<T>::take(account, reaction_id).is_some() {
+            ReactionCountByAccount::<T>::mutate(account, |count| *count = count.saturating_sub(1));
+        }
+    }
+
+    pub(crate) fn add_space_reaction(space_id: SpaceId, reaction_id: ReactionId) {
+        if !ReactionIdsBySpaceId::<T>::contains_key(space_id, reaction_id) {
+            ReactionIdsBySpaceId::<T>::insert(space_id, reaction_id, ());
+            ReactionCountBySpaceId::<T>::mutate(space_id, |count| *count = count.saturating_add(1));
+        }
+    }
+
+    pub(crate) fn remove_space_reaction(space_id: SpaceId, reaction_id: ReactionId) {
+        if ReactionIdsBySpaceId::<T>::take(space_id, reaction_id).is_some() {
+            ReactionCountBySpaceId::<T>::mutate(space_id, |count| *count = count.saturating_sub(1));
+        }
+    }
+
+    /// Remove up to `limit` reactions of a post and refund their deposits.
+    /// Returns the number of removed reactions and whether all of them were removed.
+    pub(crate) fn do_remove_post_reactions(post_id: PostId, limit: u32) -> (u32, bool) {
+        let mut reaction_ids = Self::reaction_ids_by_post_id(post_id);
+        let rest = reaction_ids.split_off(reaction_ids.len().min(limit as usize));
+
+        for reaction_id in reaction_ids.iter().copied() {
+            if let Some(reaction) = ReactionById::<T>::take(reaction_id) {
+                let owner = reaction.created.account;
+                PostReactionIdByAccount::<T>::remove((owner.clone(), post_id));
+                Self::remove_account_reaction(&owner, reaction_id);
+
+                if let ReactionKind::Custom(emoji) = &reaction.kind {
+                    Self::dec_custom_reaction_count(ReactionTarget::Post(post_id), emoji);
+                }
+            }
+            Self::refund_reaction_deposit(reaction_id);
+        }
+
+        let completed = rest.is_empty();
+        if completed {
+            ReactionIdsByPostId::<T>::remove(post_id);
+        } else {
+            ReactionIdsByPostId::<T>::insert(post_id, rest);
+        }
+        (reaction_ids.len() as u32, completed)
+    }
+
+    /// Get up to `limit` ids of reactions on a given space, starting after `start_after`.
+    /// Ids are not returned in the order the reactions were made.
+    pub fn get_reaction_ids_by_space_id(
+        space_id: SpaceId,
+        start_after: Option<ReactionId>,
+        limit: u32,
+    ) -> Vec<ReactionId> {
+        let iter = match start_after {
+            Some(reaction_id) => ReactionIdsBySpaceId::<T>::iter_key_prefix_from(
+                space_id,
+                ReactionIdsBySpaceId::<T>::hashed_key_for(space_id, reaction_id),
+            ),
+            None => ReactionIdsBySpaceId::<T>::iter_key_prefix(space_id),
+        };
+        iter.take(limit as usize).collect()
+    }
+
+    /// Get up to `limit` reactions made by a given account, starting after `start_after`.
+    /// Reactions are not returned in the order they were made.
+    /// Until the migration to storage version 1 is finished, older post reactions may be missing.
+    pub fn get_reactions_by_account(
+        account: T::AccountId,
+        start_after: Option<ReactionId>,
+        limit: u32,
+    ) -> Vec<(ReactionId, ReactionTarget, ReactionKind)> {
+        let iter = match start_after {
+            Some(reaction_id) => ReactionIdsByAccount::<T>::iter_prefix_from(
+                account.clone(),
+                ReactionIdsByAccount::<T>::hashed_key_for(account, reaction_id),
+            ),
+            None => ReactionIdsByAccount::<T>::iter_prefix(account),
+        };
+        iter.take(limit as usize)
+            .map(|(id, (target, kind))| (id, target, kind))
+            .collect()
+    }
+
+    /// Check that an account is permitted to react on a post with a given kind,
+    /// taking the post's own permission overrides into account.
+    fn ensure_can_react_on_post(
+        account: T::AccountId,
+        post: &Post<T>,
+        space: &Space<T>,
+        kind: &ReactionKind,
+    ) -> DispatchResult {
+        let (permission, error) = Self::reaction_permission(space.id, kind)?;
+        Posts::<T>::ensure_account_has_post_permission(
+            account,
+            post,
+            space,
+            permission,
+            error.into(),
+        )
+    }
+
+    /// Check that an account is permitted to react on a space with a given kind.
+    fn ensure_can_react_on_space(
+        account: T::AccountId,
+        space: &Space<T>,
+        kind: &ReactionKind,
+    ) -> DispatchResult {
+        let (permission, error) = Self::reaction_permission(space.id, kind)?;
+        Spaces::<T>::ensure_account_has_space_permission(account, space, permission, error.into())
+    }
+
+    /// Get the permission required to react with a given kind and the error to
+    /// return when it is missing. Custom kinds must also be allowed in the space.
+    fn reaction_permission(
+        space_id: SpaceId,
+        kind: &ReactionKind,
+    ) -> Result<(SpacePermission, Error<T>), DispatchError> {
+        Ok(match kind {
+            ReactionKind::Upvote => (SpacePermission::Upvote, Error::<T>::NoPermissionToUpvote),
+            ReactionKind::Downvote =>
+                (SpacePermission::Downvote, Error::<T>::NoPermissionToDownvote),
+            ReactionKind::Custom(emoji) => {
+                Self::ensure_custom_reaction_kind_allowed(space_id, emoji)?;
+                (
+                    SpacePermission::ReactWithCustomKinds,
+                    Error::<T>::NoPermissionToReactWithCustomKinds,
+                )
+            },
+        })
+    }
+
+    /// Check that a custom reaction kind is allowed in a given space.
+    fn ensure_custom_reaction_kind_allowed(space_id: SpaceId, emoji: &Emoji) -> DispatchResult {
+        ensure!(
+            Self::custom_reaction_kinds_by_space_id(space_id).contains(emoji),
+            Error::<T>::CustomReactionKindNotAllowed
+        );
+        Ok(())
+    }
+
+    /// Increase the counter of a given reaction kind on a post.
+    /// Custom reactions are counted in a separate storage, so only upvotes
+    /// and downvotes change the post itself.
+    fn inc_post_reaction_count(post: &mut Post<T>, kind: &ReactionKind) {
+        match kind {
+            ReactionKind::Upvote => post.inc_upvotes(),
+            ReactionKind::Downvote => post.inc_downvotes(),
+            ReactionKind::Custom(emoji) =>
+                Self::inc_custom_reaction_count(ReactionTarget::Post(post.id), emoji),
+        }
+    }
+
+    /// Decrease the counter of a given reaction kind on a post.
+    fn dec_post_reaction_count(post: &mut Post<T>, kind: &ReactionKind) {
+        match kind {
+            ReactionKind::Upvote => post.dec_upvotes(),
+            ReactionKind::Downvote => post.dec_downvotes(),
+            ReactionKind::Custom(emoji) =>
+                Self::dec_custom_reaction_count(ReactionTarget::Post(post.id), emoji),
+        }
+    }
+
+    /// Increase the counter of a given reaction kind on a space.
+    fn inc_space_reaction_count(space: &mut Space<T>, kind: &ReactionKind) {
+        match kind {
+            ReactionKind::Upvote => space.inc_upvotes(),
+            ReactionKind::Downvote => space.inc_downvotes(),
+            ReactionKind::Custom(emoji) =>
+                Self::inc_custom_reaction_count(ReactionTarget::Space(space.id), emoji),
+        }
+    }
+
+    /// Decrease the counter of a given reaction kind on a space.
+    fn dec_space_reaction_count(space: &mut Space<T>, kind: &ReactionKind) {
+        match kind {
+            ReactionKind::Upvote => space.dec_upvotes(),
+            ReactionKind::Downvote => space.dec_downvotes(),
+            ReactionKind::Custom(emoji) =>
+                Self::dec_custom_reaction_count(ReactionTarget::Space(space.id), emoji),
+        }
+    }
+
+    fn inc_custom_reaction_count(target: ReactionTarget, emoji: &Emoji) {
+        CustomReactionCountByTarget::<T>::mutate(target, emoji, |count| {
+            *count = count.saturating_add(1)
+        });
+    }
+
+    fn dec_custom_reaction_count(target: ReactionTarget, emoji: &Emoji) {
+        CustomReactionCountByTarget::<T>::mutate_exists(target, emoji, |count_opt| {
+            let count = count_opt.unwrap_or_default().saturating_sub(1);
+            *count_opt = if count > 0 { Some(count) } else { None };
+        });
     }
 
     /// Get `Reaction` by id from the storage or return `ReactionNotFound` error.
     pub fn require_reaction(reaction_id: ReactionId) -> Result<Reaction<T>, DispatchError> {
         Ok(Self::reaction_by_id(reaction_id).ok_or(Error::<T>::ReactionNotFound)?)
     }
+
+    /// Adjust the deposit held for a given reaction to its' encoded size.
+    ///
+    /// A reaction that has no deposit yet (e.g. created before deposits were introduced)
+    /// gets it reserved from the reaction owner.
+    fn update_reaction_deposit(reaction: &Reaction<T>) -> DispatchResult {
+        let amount = T::ReactionByteDeposit::get()
+            .saturating_mul(BalanceOf::<T>::from(reaction.encoded_size() as u32));
+
+        let old_deposit_opt = Self::deposit_by_reaction_id(reaction.id);
+        let depositor = old_deposit_opt
+            .as_ref()
+            .map_or_else(|| reaction.created.account.clone(), |deposit| deposit.depositor.clone());
+
+        let deposit = update_storage_deposit::<_, <T as pallet_spaces::Config>::Currency>(
+            old_deposit_opt,
+            depositor,
+            amount,
+        )?;
+        DepositByReactionId::<T>::insert(reaction.id, deposit);
+        Ok(())
+    }
+
+    /// Return the deposit held for a given reaction back to its depositor.
+    fn refund_reaction_deposit(reaction_id: ReactionId) {
+        if let Some(deposit) = DepositByReactionId::<T>::take(reaction_id) {
+            refund_storage_deposit::<_, <T as pallet_spaces::Config>::Currency>(deposit);
+        }
+    }
+}
+
+impl<T: Config> BeforePostDeleted for Pallet<T> {
+    /// Reactions on a deleted post are not removed here, since a post can have any number
+    /// of them. Each reaction is kept until its owner deletes it with `delete_post_reaction`,
+    /// or anyone removes the reactions of the post with `remove_post_reactions`.
+    /// Both return the reaction deposits.
+    fn before_post_deleted(post_id: PostId) -> DispatchResult {
+        TipsTotalByPostId::<T>::remove(post_id);
+        Ok(())
+    }
+}
+
+impl<T: Config> BeforeSpaceDeleted for Pallet<T> {
+    /// Reactions on a deleted space are not removed here, since a space can have any number
+    /// of them. Each reaction is kept until its' owner deletes it with `delete_space_reaction`,
+    /// which also returns the reaction deposit and updates the custom reaction counters.
+    fn before_space_deleted(space_id: SpaceId) -> DispatchResult {
+        CustomReactionKindsBySpaceId::<T>::remove(space_id);
+        Ok(())
+    }
 }
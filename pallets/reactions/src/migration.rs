@@ -0,0 +1,119 @@
+use frame_support::{pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use crate::{Config, Pallet, PostReactionIdByAccount, ReactionById, ReactionTarget};
+
+/// Max number of post reactions looked up by the migration in one block.
+pub const MIGRATED_REACTIONS_PER_BLOCK: u32 = 200;
+
+/// Lists every existing reaction in `ReactionIdsByAccount` of its owner.
+///
+/// Only post reactions existed at storage version 0, so they're found by walking
+/// `PostReactionIdByAccount`. Reactions are indexed in bounded steps across several blocks
+/// by `migrate_reactions`, which is called from `on_initialize`, until the storage version
+/// is set to 1. Until then the reactions of an account may be listed only partially.
+pub mod v1 {
+    use super::*;
+
+    /// The raw key of the last entry of `PostReactionIdByAccount` that was looked up.
+    /// An empty key means that no entry was looked up yet.
+    ///
+    /// Reactions created or deleted while the migration is running keep the index
+    /// up to date themselves, so it doesn't matter whether they're walked over or not.
+    #[storage_alias]
+    pub type MigrationCursor<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+            if onchain_version >= 1 || MigrationCursor::<T>::exists() {
+                return T::DbWeight::get().reads(2)
+            }
+
+            MigrationCursor::<T>::put(Vec::<u8>::new());
+            T::DbWeight::get().reads_writes(2, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let reaction_count = PostReactionIdByAccount::<T>::iter_keys().count() as u64;
+            Ok(reaction_count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let reaction_count: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "Cannot decode the reaction count")?;
+
+            // Run the remaining steps at once, to check the indexed reactions right away.
+            while MigrationCursor::<T>::exists() {
+                migrate_reactions::<T>(MIGRATED_REACTIONS_PER_BLOCK);
+            }
+
+            ensure!(Pallet::<T>::on_chain_storage_version() >= 1, "Storage version is not 1");
+            ensure!(
+                crate::ReactionIdsByAccount::<T>::iter_keys().count() as u64 == reaction_count,
+                "Some reactions were not indexed"
+            );
+            Ok(())
+        }
+    }
+
+    /// Whether the migration has started, but not finished yet.
+    pub fn is_migration_in_progress<T: Config>() -> bool {
+        MigrationCursor::<T>::exists()
+    }
+
+    /// Index up to `limit` next post reactions, continuing where the previous call stopped,
+    /// and set the storage version to 1 once all of them are indexed.
+    pub fn migrate_reactions<T: Config>(limit: u32) -> Weight {
+        let last_key = match MigrationCursor::<T>::get() {
+            Some(last_key) => last_key,
+            None => return T::DbWeight::get().reads(1),
+        };
+
+        let mut reads = 1u64;
+        let mut writes = 1u64;
+        let mut looked_up = 0u32;
+
+        let mut iter = if last_key.is_empty() {
+            PostReactionIdByAccount::<T>::iter()
+        } else {
+            PostReactionIdByAccount::<T>::iter_from(last_key)
+        };
+
+        let mut completed = true;
+        for ((account, post_id), reaction_id) in iter.by_ref() {
+            reads += 2;
+            if let Some(reaction) = ReactionById::<T>::get(reaction_id) {
+                reads += 1;
+                writes += 2;
+                Pallet::<T>::insert_account_reaction(
+                    &account,
+                    reaction_id,
+                    ReactionTarget::Post(post_id),
+                    reaction.kind,
+                );
+            }
+
+            looked_up += 1;
+            if looked_up >= limit {
+                completed = false;
+                break
+            }
+        }
+
+        if completed {
+            writes += 1;
+            MigrationCursor::<T>::kill();
+            StorageVersion::new(1).put::<Pallet<T>>();
+        } else {
+            MigrationCursor::<T>::put(iter.last_raw_key().to_vec());
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
@@ -44,6 +44,12 @@ pub trait WeightInfo {
     fn create_post_reaction() -> Weight;
     fn update_post_reaction() -> Weight;
     fn delete_post_reaction() -> Weight;
+    fn tip_post() -> Weight;
+    fn set_custom_reaction_kinds(n: u32, ) -> Weight;
+    fn create_space_reaction() -> Weight;
+    fn update_space_reaction() -> Weight;
+    fn delete_space_reaction() -> Weight;
+    fn remove_post_reactions(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_reactions using the Substrate node and recommended hardware.
@@ -57,30 +63,150 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             // Storage: Timestamp Now (r:1 w:0)
             // Storage: Reactions ReactionIdsByPostId (r:1 w:1)
             // Storage: Reactions ReactionById (r:0 w:1)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Spaces SubscriptionExpiresAtByAccount (r:1 w:0)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:1 w:0)
+            // Storage: Reactions CustomReactionCountByTarget (r:1 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountByAccount (r:1 w:1)
         fn create_post_reaction() -> Weight {
         // Minimum execution time: 73_075 nanoseconds.
         Weight::from_ref_time(74_198_000)
-            .saturating_add(T::DbWeight::get().reads(7))
-            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().reads(15))
+            .saturating_add(T::DbWeight::get().writes(9))
         }
             // Storage: Reactions PostReactionIdByAccount (r:1 w:0)
             // Storage: Reactions ReactionById (r:1 w:1)
             // Storage: Posts PostById (r:1 w:1)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:1 w:0)
+            // Storage: Reactions CustomReactionCountByTarget (r:2 w:2)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Posts DeletedPostById (r:1 w:0)
         fn update_post_reaction() -> Weight {
         // Minimum execution time: 48_899 nanoseconds.
         Weight::from_ref_time(52_268_000)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(7))
         }
             // Storage: Reactions PostReactionIdByAccount (r:1 w:1)
             // Storage: Reactions ReactionById (r:1 w:1)
             // Storage: Posts PostById (r:1 w:1)
             // Storage: Reactions ReactionIdsByPostId (r:1 w:1)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Reactions CustomReactionCountByTarget (r:1 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountByAccount (r:1 w:1)
+            // Storage: Posts DeletedPostById (r:1 w:0)
         fn delete_post_reaction() -> Weight {
         // Minimum execution time: 55_284 nanoseconds.
         Weight::from_ref_time(56_721_000)
+            .saturating_add(T::DbWeight::get().reads(10))
+            .saturating_add(T::DbWeight::get().writes(9))
+        }
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts DeletedPostById (r:1 w:0)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: System Account (r:2 w:2)
+            // Storage: Reactions TipsTotalByPostId (r:1 w:1)
+        fn tip_post() -> Weight {
+        // Minimum execution time: 61_310 nanoseconds.
+        Weight::from_ref_time(62_504_000)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(3))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:0 w:1)
+            /// The range of component `n` is `[0, 10]`.
+        fn set_custom_reaction_kinds(n: u32, ) -> Weight {
+        // Minimum execution time: 22_000 nanoseconds.
+        Weight::from_ref_time(23_415_000)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(412_000).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+        }
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Reactions SpaceReactionIdByAccount (r:1 w:1)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:1 w:0)
+            // Storage: Reactions CustomReactionCountByTarget (r:1 w:1)
+            // Storage: Reactions NextReactionId (r:1 w:1)
+            // Storage: Timestamp Now (r:1 w:0)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Reactions ReactionIdsBySpaceId (r:1 w:1)
+            // Storage: Reactions ReactionById (r:0 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountBySpaceId (r:1 w:1)
+        fn create_space_reaction() -> Weight {
+        // Minimum execution time: 52_874 nanoseconds.
+        Weight::from_ref_time(54_102_000)
+            .saturating_add(T::DbWeight::get().reads(14))
+            .saturating_add(T::DbWeight::get().writes(11))
+        }
+            // Storage: Reactions SpaceReactionIdByAccount (r:1 w:0)
+            // Storage: Reactions ReactionById (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:1 w:0)
+            // Storage: Reactions CustomReactionCountByTarget (r:2 w:2)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+        fn update_space_reaction() -> Weight {
+        // Minimum execution time: 41_533 nanoseconds.
+        Weight::from_ref_time(42_890_000)
+            .saturating_add(T::DbWeight::get().reads(10))
+            .saturating_add(T::DbWeight::get().writes(7))
+        }
+            // Storage: Reactions SpaceReactionIdByAccount (r:1 w:1)
+            // Storage: Reactions ReactionById (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: Reactions CustomReactionCountByTarget (r:1 w:1)
+            // Storage: Reactions ReactionIdsBySpaceId (r:1 w:1)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountBySpaceId (r:1 w:1)
+        fn delete_space_reaction() -> Weight {
+        // Minimum execution time: 44_716 nanoseconds.
+        Weight::from_ref_time(45_938_000)
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(10))
+        }
+            // Storage: Posts MigrationCursor (r:1 w:0)
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts DeletedPostById (r:1 w:0)
+            // Storage: Reactions ReactionIdsByPostId (r:1 w:1)
+            // Storage: Reactions ReactionById (r:1 w:1)
+            // Storage: Reactions PostReactionIdByAccount (r:0 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountByAccount (r:1 w:1)
+            // Storage: Reactions CustomReactionCountByTarget (r:1 w:1)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            /// The range of component `n` is `[0, 100]`.
+        fn remove_post_reactions(n: u32, ) -> Weight {
+        // Minimum execution time: 14_000 nanoseconds.
+        Weight::from_ref_time(15_120_000)
+            // Standard Error: 6_000
+            .saturating_add(Weight::from_ref_time(24_310_000).saturating_mul(n.into()))
             .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
         }
     }
 
@@ -94,29 +220,149 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             // Storage: Timestamp Now (r:1 w:0)
             // Storage: Reactions ReactionIdsByPostId (r:1 w:1)
             // Storage: Reactions ReactionById (r:0 w:1)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Spaces SubscriptionExpiresAtByAccount (r:1 w:0)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:1 w:0)
+            // Storage: Reactions CustomReactionCountByTarget (r:1 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountByAccount (r:1 w:1)
         fn create_post_reaction() -> Weight {
         // Minimum execution time: 73_075 nanoseconds.
         Weight::from_ref_time(74_198_000)
-            .saturating_add(RocksDbWeight::get().reads(7))
-            .saturating_add(RocksDbWeight::get().writes(5))
+            .saturating_add(RocksDbWeight::get().reads(15))
+            .saturating_add(RocksDbWeight::get().writes(9))
         }
             // Storage: Reactions PostReactionIdByAccount (r:1 w:0)
             // Storage: Reactions ReactionById (r:1 w:1)
             // Storage: Posts PostById (r:1 w:1)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:1 w:0)
+            // Storage: Reactions CustomReactionCountByTarget (r:2 w:2)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Posts DeletedPostById (r:1 w:0)
         fn update_post_reaction() -> Weight {
         // Minimum execution time: 48_899 nanoseconds.
         Weight::from_ref_time(52_268_000)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(11))
+            .saturating_add(RocksDbWeight::get().writes(7))
         }
             // Storage: Reactions PostReactionIdByAccount (r:1 w:1)
             // Storage: Reactions ReactionById (r:1 w:1)
             // Storage: Posts PostById (r:1 w:1)
             // Storage: Reactions ReactionIdsByPostId (r:1 w:1)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Reactions CustomReactionCountByTarget (r:1 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountByAccount (r:1 w:1)
+            // Storage: Posts DeletedPostById (r:1 w:0)
         fn delete_post_reaction() -> Weight {
         // Minimum execution time: 55_284 nanoseconds.
         Weight::from_ref_time(56_721_000)
+            .saturating_add(RocksDbWeight::get().reads(10))
+            .saturating_add(RocksDbWeight::get().writes(9))
+        }
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts DeletedPostById (r:1 w:0)
+            // Storage: Posts PublishAtByPostId (r:1 w:0)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: System Account (r:2 w:2)
+            // Storage: Reactions TipsTotalByPostId (r:1 w:1)
+        fn tip_post() -> Weight {
+        // Minimum execution time: 61_310 nanoseconds.
+        Weight::from_ref_time(62_504_000)
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(3))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:0 w:1)
+            /// The range of component `n` is `[0, 10]`.
+        fn set_custom_reaction_kinds(n: u32, ) -> Weight {
+        // Minimum execution time: 22_000 nanoseconds.
+        Weight::from_ref_time(23_415_000)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(412_000).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+        }
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Reactions SpaceReactionIdByAccount (r:1 w:1)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:1 w:0)
+            // Storage: Reactions CustomReactionCountByTarget (r:1 w:1)
+            // Storage: Reactions NextReactionId (r:1 w:1)
+            // Storage: Timestamp Now (r:1 w:0)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Reactions ReactionIdsBySpaceId (r:1 w:1)
+            // Storage: Reactions ReactionById (r:0 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountBySpaceId (r:1 w:1)
+        fn create_space_reaction() -> Weight {
+        // Minimum execution time: 52_874 nanoseconds.
+        Weight::from_ref_time(54_102_000)
+            .saturating_add(RocksDbWeight::get().reads(14))
+            .saturating_add(RocksDbWeight::get().writes(11))
+        }
+            // Storage: Reactions SpaceReactionIdByAccount (r:1 w:0)
+            // Storage: Reactions ReactionById (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:1 w:0)
+            // Storage: Reactions CustomReactionCountByTarget (r:2 w:2)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+        fn update_space_reaction() -> Weight {
+        // Minimum execution time: 41_533 nanoseconds.
+        Weight::from_ref_time(42_890_000)
+            .saturating_add(RocksDbWeight::get().reads(10))
+            .saturating_add(RocksDbWeight::get().writes(7))
+        }
+            // Storage: Reactions SpaceReactionIdByAccount (r:1 w:1)
+            // Storage: Reactions ReactionById (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+            // Storage: Reactions CustomReactionCountByTarget (r:1 w:1)
+            // Storage: Reactions ReactionIdsBySpaceId (r:1 w:1)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountBySpaceId (r:1 w:1)
+        fn delete_space_reaction() -> Weight {
+        // Minimum execution time: 44_716 nanoseconds.
+        Weight::from_ref_time(45_938_000)
+            .saturating_add(RocksDbWeight::get().reads(11))
+            .saturating_add(RocksDbWeight::get().writes(10))
+        }
+            // Storage: Posts MigrationCursor (r:1 w:0)
+            // Storage: Posts PostById (r:1 w:0)
+            // Storage: Posts DeletedPostById (r:1 w:0)
+            // Storage: Reactions ReactionIdsByPostId (r:1 w:1)
+            // Storage: Reactions ReactionById (r:1 w:1)
+            // Storage: Reactions PostReactionIdByAccount (r:0 w:1)
+            // Storage: Reactions ReactionIdsByAccount (r:1 w:1)
+            // Storage: Reactions ReactionCountByAccount (r:1 w:1)
+            // Storage: Reactions CustomReactionCountByTarget (r:1 w:1)
+            // Storage: Reactions DepositByReactionId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            /// The range of component `n` is `[0, 100]`.
+        fn remove_post_reactions(n: u32, ) -> Weight {
+        // Minimum execution time: 14_000 nanoseconds.
+        Weight::from_ref_time(15_120_000)
+            // Standard Error: 6_000
+            .saturating_add(Weight::from_ref_time(24_310_000).saturating_mul(n.into()))
             .saturating_add(RocksDbWeight::get().reads(4))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
         }
     }
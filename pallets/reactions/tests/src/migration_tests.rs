@@ -0,0 +1,76 @@
+use frame_support::{
+    assert_ok,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+
+use pallet_reactions::{
+    migration::v1::{migrate_reactions, MigrateToV1, MigrationCursor},
+    ReactionCountByAccount, ReactionIdsByAccount, ReactionTarget,
+};
+
+use crate::{mock::*, tests_utils::*};
+
+/// Forget the reactions made by the given accounts, as if they were made before
+/// the account index existed, and start the migration.
+fn start_reactions_migration(accounts: &[AccountId]) {
+    for account in accounts {
+        let _ = ReactionIdsByAccount::<Test>::clear_prefix(account, u32::MAX, None);
+        ReactionCountByAccount::<Test>::remove(account);
+    }
+    StorageVersion::new(0).put::<Reactions>();
+    MigrateToV1::<Test>::on_runtime_upgrade();
+}
+
+#[test]
+fn migrate_reactions_should_work_in_bounded_steps() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+        assert_ok!(_create_post_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT3)),
+            None,
+            Some(reaction_downvote())
+        )); // ReactionId 2
+        start_reactions_migration(&[ACCOUNT2, ACCOUNT3]);
+
+        assert!(MigrationCursor::<Test>::exists());
+        assert_eq!(Reactions::reaction_count_by_account(ACCOUNT2), 0);
+
+        migrate_reactions::<Test>(1);
+
+        assert!(MigrationCursor::<Test>::exists());
+        assert_eq!(Reactions::on_chain_storage_version(), 0);
+        assert_eq!(
+            Reactions::reaction_count_by_account(ACCOUNT2) +
+                Reactions::reaction_count_by_account(ACCOUNT3),
+            1
+        );
+
+        migrate_reactions::<Test>(1);
+        migrate_reactions::<Test>(1);
+
+        assert!(MigrationCursor::<Test>::get().is_none());
+        assert_eq!(Reactions::on_chain_storage_version(), 1);
+        assert_eq!(Reactions::reaction_count_by_account(ACCOUNT2), 1);
+        assert_eq!(Reactions::reaction_count_by_account(ACCOUNT3), 1);
+        assert_eq!(
+            ReactionIdsByAccount::<Test>::get(ACCOUNT3, REACTION2),
+            Some((ReactionTarget::Post(POST1), reaction_downvote()))
+        );
+    });
+}
+
+#[test]
+fn migrate_reactions_should_not_count_reactions_made_during_migration_twice() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+        start_reactions_migration(&[ACCOUNT2]);
+
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT3)), None, None)); // ReactionId 2
+
+        migrate_reactions::<Test>(10);
+
+        assert!(MigrationCursor::<Test>::get().is_none());
+        assert_eq!(Reactions::reaction_count_by_account(ACCOUNT2), 1);
+        assert_eq!(Reactions::reaction_count_by_account(ACCOUNT3), 1);
+    });
+}
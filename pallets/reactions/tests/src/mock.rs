@@ -1,4 +1,8 @@
-use frame_support::{pallet_prelude::ConstU32, parameter_types, traits::Everything};
+use frame_support::{
+    pallet_prelude::ConstU32,
+    parameter_types,
+    traits::{ConstU64, Everything},
+};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -98,6 +102,12 @@ impl pallet_posts::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxCommentDepth = MaxCommentDepth;
     type IsPostBlocked = ();
+    type BeforePostDeleted = Reactions;
+    type MaxPostContentHistory = ConstU32<10>;
+    type MaxPinnedPostsPerSpace = ConstU32<10>;
+    type MaxScheduledPostsPerBlock = ConstU32<100>;
+    type MaxPollOptions = ConstU32<10>;
+    type PostByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -116,6 +126,9 @@ impl pallet_roles::Config for Test {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
+    type MaxInlineContentLen = ConstU32<512>;
+    type Currency = Balances;
+    type RoleByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -125,7 +138,17 @@ impl pallet_spaces::Config for Test {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
+    type IsSpaceBlocked = ();
+    type MaxInlineContentLen = ConstU32<512>;
+    type ProfileManager = ();
+    type BeforeSpaceDeleted = (Posts, SpaceFollows, Reactions);
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<5>;
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type MaxSpaceContentHistory = ConstU32<10>;
+    type Currency = Balances;
+    type SpaceByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -134,7 +157,13 @@ impl pallet_space_follows::Config for Test {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub static ReactionByteDeposit: u64 = 0;
+}
+
 impl pallet_reactions::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type ReactionByteDeposit = ReactionByteDeposit;
+    type MaxCustomReactionKinds = ConstU32<3>;
     type WeightInfo = ();
 }
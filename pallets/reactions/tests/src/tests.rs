@@ -1,7 +1,11 @@
 use frame_support::{assert_noop, assert_ok};
 
-use pallet_posts::Error as PostsError;
-use pallet_reactions::Error as ReactionsError;
+use pallet_posts::{Error as PostsError, PostUpdate};
+use pallet_reactions::{
+    CustomReactionCountByTarget, Error as ReactionsError, Event as ReactionsEvent,
+    ReactionIdsByAccount, ReactionTarget, SpaceReactionIdByAccount,
+};
+use pallet_spaces::{types::SpaceUpdate, Error as SpacesError};
 
 use crate::{mock::*, tests_utils::*};
 
@@ -51,6 +55,158 @@ fn create_post_reaction_should_work_downvote() {
     });
 }
 
+#[test]
+fn create_post_reaction_should_reserve_storage_deposit() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        ReactionByteDeposit::set(1);
+
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+        let expected_deposit = expected_reaction_deposit(REACTION1);
+        assert!(expected_deposit > 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), expected_deposit);
+
+        let deposit = Reactions::deposit_by_reaction_id(REACTION1).unwrap();
+        assert_eq!(deposit.depositor, ACCOUNT2);
+        assert_eq!(deposit.amount, expected_deposit);
+    });
+}
+
+#[test]
+fn delete_post_reaction_should_refund_storage_deposit() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        ReactionByteDeposit::set(1);
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+        assert_ok!(_delete_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, REACTION1));
+
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+        assert!(Reactions::deposit_by_reaction_id(REACTION1).is_none());
+    });
+}
+
+#[test]
+fn delete_post_should_keep_its_reactions() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        ReactionByteDeposit::set(1);
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+        let expected_deposit = expected_reaction_deposit(REACTION1);
+
+        assert_ok!(Posts::delete_post(RuntimeOrigin::signed(ACCOUNT1), POST1));
+
+        assert!(Reactions::reaction_by_id(REACTION1).is_some());
+        assert_eq!(Reactions::reaction_ids_by_post_id(POST1), vec![REACTION1]);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), expected_deposit);
+    });
+}
+
+#[test]
+fn delete_post_reaction_should_work_when_post_is_deleted() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        ReactionByteDeposit::set(1);
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+        assert_ok!(Posts::delete_post(RuntimeOrigin::signed(ACCOUNT1), POST1));
+
+        assert_ok!(_delete_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, REACTION1));
+
+        assert!(Reactions::reaction_by_id(REACTION1).is_none());
+        assert!(Reactions::reaction_ids_by_post_id(POST1).is_empty());
+        assert!(!pallet_reactions::PostReactionIdByAccount::<Test>::contains_key((
+            ACCOUNT2, POST1
+        )));
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+    });
+}
+
+#[test]
+fn remove_post_reactions_should_remove_reactions_of_deleted_post_in_parts() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        ReactionByteDeposit::set(1);
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+        assert_ok!(_create_post_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT3)),
+            None,
+            Some(reaction_downvote())
+        )); // ReactionId 2
+        assert_ok!(Posts::delete_post(RuntimeOrigin::signed(ACCOUNT1), POST1));
+
+        assert_ok!(_remove_post_1_reactions(1));
+        System::assert_last_event(
+            ReactionsEvent::<Test>::PostReactionsPartiallyRemoved {
+                post_id: POST1,
+                removed_count: 1,
+            }
+            .into(),
+        );
+        assert!(Reactions::reaction_by_id(REACTION1).is_none());
+        assert_eq!(Reactions::reaction_ids_by_post_id(POST1), vec![REACTION2]);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+
+        assert_ok!(_remove_post_1_reactions(1));
+        System::assert_last_event(
+            ReactionsEvent::<Test>::PostReactionsRemoved { post_id: POST1, removed_count: 1 }
+                .into(),
+        );
+        assert!(Reactions::reaction_by_id(REACTION2).is_none());
+        assert!(Reactions::reaction_ids_by_post_id(POST1).is_empty());
+        assert!(!pallet_reactions::PostReactionIdByAccount::<Test>::contains_key((
+            ACCOUNT3, POST1
+        )));
+        assert_eq!(Reactions::reaction_count_by_account(ACCOUNT3), 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT3), 0);
+    });
+}
+
+#[test]
+fn remove_post_reactions_should_fail_when_post_is_not_deleted() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+        assert_noop!(_remove_post_1_reactions(10), ReactionsError::<Test>::PostIsNotDeleted);
+    });
+}
+
+#[test]
+fn update_post_reaction_should_fail_when_post_is_deleted() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+        // A post with replies is replaced with a tombstone, when deleted
+        assert_ok!(_create_post(None, None, Some(extension_comment(None, POST1)), None)); // PostId 2
+        assert_ok!(Posts::delete_post(RuntimeOrigin::signed(ACCOUNT1), POST1));
+
+        assert_noop!(
+            _update_post_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                REACTION1,
+                Some(reaction_downvote())
+            ),
+            PostsError::<Test>::PostIsDeleted
+        );
+    });
+}
+
+#[test]
+fn force_remove_post_thread_should_keep_reactions_on_replies() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        ReactionByteDeposit::set(1);
+        assert_ok!(_create_post(None, None, Some(extension_comment(None, POST1)), None)); // PostId 2
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), Some(POST2), None)); // ReactionId 1
+
+        assert_ok!(Posts::force_remove_post_thread(RuntimeOrigin::root(), POST1, 10));
+        assert!(Reactions::reaction_by_id(REACTION1).is_some());
+
+        assert_ok!(_delete_post_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            Some(POST2),
+            REACTION1
+        ));
+        assert!(Reactions::reaction_ids_by_post_id(POST2).is_empty());
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+    });
+}
+
 #[test]
 fn create_post_reaction_should_fail_when_account_has_already_reacted() {
     ExtBuilder::build_with_reacted_post_and_two_spaces().execute_with(|| {
@@ -95,3 +251,657 @@ fn create_post_reaction_should_fail_when_trying_to_react_on_hidden_post() {
         );
     });
 }
+
+#[test]
+fn create_post_reaction_should_fail_when_post_permissions_forbid_downvotes() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(PostUpdate {
+                permissions: Some(Some(permissions_where_no_one_can_downvote())),
+                ..Default::default()
+            })
+        ));
+
+        assert_noop!(
+            _create_post_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                Some(reaction_downvote())
+            ),
+            ReactionsError::<Test>::NoPermissionToDownvote
+        );
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None));
+    });
+}
+
+#[test]
+fn create_post_reaction_should_fail_on_subscribers_only_post_when_account_is_not_subscriber() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_make_post_1_subscribers_only());
+
+        assert_noop!(
+            _create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None),
+            ReactionsError::<Test>::OnlySubscribersCanReact
+        );
+
+        assert_ok!(Spaces::subscribe_to_space(RuntimeOrigin::signed(ACCOUNT2), SPACE1, 10));
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None));
+    });
+}
+
+#[test]
+fn tip_post_should_work() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_tip_default_post(10));
+        assert_ok!(_tip_post(Some(RuntimeOrigin::signed(ACCOUNT3)), None, 5));
+
+        assert_eq!(Reactions::tips_total_by_post_id(POST1), 15);
+        assert_eq!(Balances::free_balance(ACCOUNT1), 115);
+        assert_eq!(Balances::free_balance(ACCOUNT2), 90);
+        assert_eq!(Balances::free_balance(ACCOUNT3), 95);
+
+        System::assert_last_event(
+            pallet_reactions::Event::PostTipped {
+                account: ACCOUNT3,
+                post_id: POST1,
+                space_id: SPACE1,
+                amount: 5,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn tip_post_should_fail_when_tipping_own_post() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(_tip_post(None, None, 10), ReactionsError::<Test>::CannotTipOwnPost);
+    });
+}
+
+#[test]
+fn tip_post_should_fail_when_amount_is_zero() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(_tip_default_post(0), ReactionsError::<Test>::ZeroTipAmount);
+    });
+}
+
+#[test]
+fn tip_post_should_fail_when_post_not_found() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_tip_default_post(10), PostsError::<Test>::PostNotFound);
+    });
+}
+
+#[test]
+fn tip_post_should_fail_when_space_is_hidden() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_update_space(None, None, Some(space_update(None, Some(true)))));
+
+        assert_noop!(_tip_default_post(10), ReactionsError::<Test>::CannotTipWhenSpaceHidden);
+    });
+}
+
+#[test]
+fn tip_post_should_fail_when_post_is_hidden() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_update_post(None, None, Some(post_update(None, None, Some(true)))));
+
+        assert_noop!(_tip_default_post(10), ReactionsError::<Test>::CannotTipWhenPostHidden);
+    });
+}
+
+#[test]
+fn set_custom_reaction_kinds_should_work() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_default_custom_reaction_kinds());
+
+        assert_eq!(
+            Reactions::custom_reaction_kinds_by_space_id(SPACE1).into_inner(),
+            default_custom_reaction_kinds()
+        );
+        System::assert_last_event(
+            pallet_reactions::Event::CustomReactionKindsUpdated {
+                account: ACCOUNT1,
+                space_id: SPACE1,
+            }
+            .into(),
+        );
+
+        // Empty list removes all custom reaction kinds
+        assert_ok!(_set_custom_reaction_kinds(None, None, vec![]));
+        assert!(Reactions::custom_reaction_kinds_by_space_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn set_custom_reaction_kinds_should_fail_when_no_permission() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            _set_custom_reaction_kinds(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                default_custom_reaction_kinds()
+            ),
+            ReactionsError::<Test>::NoPermissionToManageCustomReactionKinds
+        );
+    });
+}
+
+#[test]
+fn set_custom_reaction_kinds_should_fail_with_invalid_kinds() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            _set_custom_reaction_kinds(None, None, vec![emoji("")]),
+            ReactionsError::<Test>::InvalidCustomReactionKind
+        );
+        assert_noop!(
+            _set_custom_reaction_kinds(None, None, vec![vec![0xff, 0xfe].try_into().unwrap()]),
+            ReactionsError::<Test>::InvalidCustomReactionKind
+        );
+        assert_noop!(
+            _set_custom_reaction_kinds(None, None, vec![emoji("🎉"), emoji("🎉")]),
+            ReactionsError::<Test>::DuplicateCustomReactionKind
+        );
+        assert_noop!(
+            _set_custom_reaction_kinds(
+                None,
+                None,
+                vec![emoji("❤️"), emoji("🎉"), emoji("🔥"), emoji("👀")]
+            ),
+            ReactionsError::<Test>::TooManyCustomReactionKinds
+        );
+    });
+}
+
+#[test]
+fn create_post_reaction_should_work_with_custom_kind() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_default_custom_reaction_kinds());
+
+        assert_ok!(_create_post_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            None,
+            Some(reaction_custom("🎉"))
+        ));
+        assert_ok!(_create_post_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT3)),
+            None,
+            Some(reaction_custom("🎉"))
+        ));
+
+        assert_eq!(
+            Reactions::custom_reaction_count_by_target(ReactionTarget::Post(POST1), emoji("🎉")),
+            2
+        );
+        assert_eq!(Reactions::reaction_by_id(REACTION1).unwrap().kind, reaction_custom("🎉"));
+
+        // Upvotes and downvotes are not affected by custom reactions
+        let post = Posts::post_by_id(POST1).unwrap();
+        assert_eq!(post.upvotes_count, 0);
+        assert_eq!(post.downvotes_count, 0);
+    });
+}
+
+#[test]
+fn create_post_reaction_should_fail_when_custom_kind_is_not_allowed() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            _create_post_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                Some(reaction_custom("🎉"))
+            ),
+            ReactionsError::<Test>::CustomReactionKindNotAllowed
+        );
+
+        assert_ok!(_set_default_custom_reaction_kinds());
+        assert_noop!(
+            _create_post_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                Some(reaction_custom("🔥"))
+            ),
+            ReactionsError::<Test>::CustomReactionKindNotAllowed
+        );
+    });
+}
+
+#[test]
+fn create_post_reaction_should_fail_when_post_permissions_forbid_custom_kinds() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_default_custom_reaction_kinds());
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(PostUpdate {
+                permissions: Some(Some(permissions_where_no_one_can_react_with_custom_kinds())),
+                ..Default::default()
+            })
+        ));
+
+        assert_noop!(
+            _create_post_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                Some(reaction_custom("🎉"))
+            ),
+            ReactionsError::<Test>::NoPermissionToReactWithCustomKinds
+        );
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None));
+    });
+}
+
+#[test]
+fn update_post_reaction_should_fail_when_post_permissions_forbid_downvotes() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(PostUpdate {
+                permissions: Some(Some(permissions_where_no_one_can_downvote())),
+                ..Default::default()
+            })
+        ));
+
+        assert_noop!(
+            _update_post_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                REACTION1,
+                Some(reaction_downvote())
+            ),
+            ReactionsError::<Test>::NoPermissionToDownvote
+        );
+    });
+}
+
+#[test]
+fn update_post_reaction_should_fail_when_post_permissions_forbid_custom_kinds() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_default_custom_reaction_kinds());
+        assert_ok!(_update_post(
+            None,
+            None,
+            Some(PostUpdate {
+                permissions: Some(Some(permissions_where_no_one_can_react_with_custom_kinds())),
+                ..Default::default()
+            })
+        ));
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+        assert_noop!(
+            _update_post_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                REACTION1,
+                Some(reaction_custom("🎉"))
+            ),
+            ReactionsError::<Test>::NoPermissionToReactWithCustomKinds
+        );
+    });
+}
+
+#[test]
+fn update_post_reaction_should_move_custom_reaction_counters() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_default_custom_reaction_kinds());
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+        assert_ok!(_update_post_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            None,
+            REACTION1,
+            Some(reaction_custom("❤️"))
+        ));
+        assert_eq!(Posts::post_by_id(POST1).unwrap().upvotes_count, 0);
+        assert_eq!(
+            Reactions::custom_reaction_count_by_target(ReactionTarget::Post(POST1), emoji("❤️")),
+            1
+        );
+
+        assert_ok!(_update_post_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            None,
+            REACTION1,
+            Some(reaction_custom("🎉"))
+        ));
+        assert!(!CustomReactionCountByTarget::<Test>::contains_key(
+            ReactionTarget::Post(POST1),
+            emoji("❤️")
+        ));
+        assert_eq!(
+            Reactions::custom_reaction_count_by_target(ReactionTarget::Post(POST1), emoji("🎉")),
+            1
+        );
+
+        assert_noop!(
+            _update_post_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                REACTION1,
+                Some(reaction_custom("🔥"))
+            ),
+            ReactionsError::<Test>::CustomReactionKindNotAllowed
+        );
+    });
+}
+
+#[test]
+fn delete_post_reaction_should_decrease_custom_reaction_counter() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_default_custom_reaction_kinds());
+        assert_ok!(_create_post_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            None,
+            Some(reaction_custom("🎉"))
+        )); // ReactionId 1
+
+        assert_ok!(_delete_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, REACTION1));
+
+        assert!(!CustomReactionCountByTarget::<Test>::contains_key(
+            ReactionTarget::Post(POST1),
+            emoji("🎉")
+        ));
+    });
+}
+
+#[test]
+fn create_space_reaction_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space_reaction()); // ReactionId 1 by ACCOUNT2
+
+        assert_eq!(Reactions::get_reaction_ids_by_space_id(SPACE1, None, 10), vec![REACTION1]);
+        assert_eq!(Reactions::reaction_count_by_space_id(SPACE1), 1);
+        assert_eq!(Reactions::space_reaction_id_by_account((ACCOUNT2, SPACE1)), REACTION1);
+        assert_eq!(Reactions::next_reaction_id(), REACTION2);
+
+        let space = Spaces::space_by_id(SPACE1).unwrap();
+        assert_eq!(space.upvotes_count, 1);
+        assert_eq!(space.downvotes_count, 0);
+
+        let reaction = Reactions::reaction_by_id(REACTION1).unwrap();
+        assert_eq!(reaction.created.account, ACCOUNT2);
+        assert_eq!(reaction.kind, reaction_upvote());
+
+        System::assert_last_event(
+            pallet_reactions::Event::SpaceReactionCreated {
+                account: ACCOUNT2,
+                space_id: SPACE1,
+                reaction_id: REACTION1,
+                reaction_kind: reaction_upvote(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn create_space_reaction_should_work_with_custom_kind() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_custom_reaction_kinds());
+
+        assert_ok!(_create_space_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            None,
+            Some(reaction_custom("❤️"))
+        ));
+
+        assert_eq!(
+            Reactions::custom_reaction_count_by_target(ReactionTarget::Space(SPACE1), emoji("❤️")),
+            1
+        );
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().upvotes_count, 0);
+
+        assert_noop!(
+            _create_space_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT3)),
+                None,
+                Some(reaction_custom("🔥"))
+            ),
+            ReactionsError::<Test>::CustomReactionKindNotAllowed
+        );
+    });
+}
+
+#[test]
+fn create_space_reaction_should_fail_when_account_already_reacted() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space_reaction());
+
+        assert_noop!(
+            _create_space_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                Some(reaction_downvote())
+            ),
+            ReactionsError::<Test>::AccountAlreadyReacted
+        );
+    });
+}
+
+#[test]
+fn create_space_reaction_should_fail_when_space_not_found() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_create_default_space_reaction(), SpacesError::<Test>::SpaceNotFound);
+    });
+}
+
+#[test]
+fn create_space_reaction_should_fail_when_space_is_hidden() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_space(None, None, Some(space_update(None, Some(true)))));
+
+        assert_noop!(
+            _create_default_space_reaction(),
+            ReactionsError::<Test>::CannotReactWhenSpaceHidden
+        );
+    });
+}
+
+#[test]
+fn update_space_reaction_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space_reaction()); // ReactionId 1 by ACCOUNT2
+
+        assert_ok!(_update_space_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            None,
+            REACTION1,
+            Some(reaction_downvote())
+        ));
+
+        let space = Spaces::space_by_id(SPACE1).unwrap();
+        assert_eq!(space.upvotes_count, 0);
+        assert_eq!(space.downvotes_count, 1);
+        assert_eq!(Reactions::reaction_by_id(REACTION1).unwrap().kind, reaction_downvote());
+
+        assert_noop!(
+            _update_space_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                REACTION1,
+                Some(reaction_downvote())
+            ),
+            ReactionsError::<Test>::SameReaction
+        );
+    });
+}
+
+#[test]
+fn update_space_reaction_should_fail_when_account_has_no_reaction_on_space() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space_reaction()); // ReactionId 1 by ACCOUNT2
+
+        assert_noop!(
+            _update_space_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT3)),
+                None,
+                REACTION1,
+                Some(reaction_downvote())
+            ),
+            ReactionsError::<Test>::ReactionByAccountNotFound
+        );
+    });
+}
+
+#[test]
+fn update_space_reaction_should_fail_when_space_permissions_forbid_downvotes() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space_reaction()); // ReactionId 1 by ACCOUNT2
+        assert_ok!(_update_space(
+            None,
+            None,
+            Some(SpaceUpdate {
+                permissions: Some(Some(permissions_where_no_one_can_downvote())),
+                ..space_update(None, None)
+            })
+        ));
+
+        assert_noop!(
+            _update_space_reaction(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                REACTION1,
+                Some(reaction_downvote())
+            ),
+            ReactionsError::<Test>::NoPermissionToDownvote
+        );
+    });
+}
+
+#[test]
+fn delete_space_reaction_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space_reaction()); // ReactionId 1 by ACCOUNT2
+
+        assert_ok!(_delete_space_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, REACTION1));
+
+        assert!(Reactions::get_reaction_ids_by_space_id(SPACE1, None, 10).is_empty());
+        assert_eq!(Reactions::reaction_count_by_space_id(SPACE1), 0);
+        assert!(Reactions::reaction_by_id(REACTION1).is_none());
+        assert!(!SpaceReactionIdByAccount::<Test>::contains_key((ACCOUNT2, SPACE1)));
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().upvotes_count, 0);
+
+        System::assert_last_event(
+            pallet_reactions::Event::SpaceReactionDeleted {
+                account: ACCOUNT2,
+                space_id: SPACE1,
+                reaction_id: REACTION1,
+                reaction_kind: reaction_upvote(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn delete_space_should_keep_space_reactions() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space_reaction()); // ReactionId 1 by ACCOUNT2
+
+        assert_ok!(Spaces::delete_space(RuntimeOrigin::signed(ACCOUNT1), SPACE1));
+
+        assert_eq!(Reactions::get_reaction_ids_by_space_id(SPACE1, None, 10), vec![REACTION1]);
+        assert!(Reactions::reaction_by_id(REACTION1).is_some());
+        assert!(Reactions::custom_reaction_kinds_by_space_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn delete_space_reaction_should_work_when_space_is_deleted() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        ReactionByteDeposit::set(1);
+        assert_ok!(_set_default_custom_reaction_kinds());
+        assert_ok!(_create_space_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            None,
+            Some(reaction_custom("❤️"))
+        )); // ReactionId 1
+        assert_ok!(Spaces::delete_space(RuntimeOrigin::signed(ACCOUNT1), SPACE1));
+
+        assert_ok!(_delete_space_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, REACTION1));
+
+        assert!(Reactions::reaction_by_id(REACTION1).is_none());
+        assert!(Reactions::get_reaction_ids_by_space_id(SPACE1, None, 10).is_empty());
+        assert_eq!(Reactions::reaction_count_by_space_id(SPACE1), 0);
+        assert!(!SpaceReactionIdByAccount::<Test>::contains_key((ACCOUNT2, SPACE1)));
+        assert!(!CustomReactionCountByTarget::<Test>::contains_key(
+            ReactionTarget::Space(SPACE1),
+            emoji("❤️")
+        ));
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+    });
+}
+
+#[test]
+fn reactions_by_account_should_be_kept_in_sync() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+        assert_ok!(_create_default_space_reaction()); // ReactionId 2
+
+        assert_eq!(Reactions::reaction_count_by_account(ACCOUNT2), 2);
+        assert_eq!(
+            ReactionIdsByAccount::<Test>::get(ACCOUNT2, REACTION1),
+            Some((ReactionTarget::Post(POST1), reaction_upvote()))
+        );
+        assert_eq!(
+            ReactionIdsByAccount::<Test>::get(ACCOUNT2, REACTION2),
+            Some((ReactionTarget::Space(SPACE1), reaction_upvote()))
+        );
+
+        assert_ok!(_update_post_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            None,
+            REACTION1,
+            Some(reaction_downvote())
+        ));
+        assert_eq!(Reactions::reaction_count_by_account(ACCOUNT2), 2);
+        assert_eq!(
+            ReactionIdsByAccount::<Test>::get(ACCOUNT2, REACTION1),
+            Some((ReactionTarget::Post(POST1), reaction_downvote()))
+        );
+
+        assert_ok!(_delete_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, REACTION1));
+        assert_ok!(_delete_space_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, REACTION2));
+
+        assert_eq!(Reactions::reaction_count_by_account(ACCOUNT2), 0);
+        assert!(Reactions::get_reactions_by_account(ACCOUNT2, None, 10).is_empty());
+    });
+}
+
+#[test]
+fn get_reactions_by_account_should_page_through_reactions() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post(Some(RuntimeOrigin::signed(ACCOUNT1)), None, None, None)); // PostId 2
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+        assert_ok!(_create_post_reaction(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            Some(POST2),
+            Some(reaction_downvote())
+        )); // ReactionId 2
+        assert_ok!(_create_default_space_reaction()); // ReactionId 3
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT3)), None, None)); // ReactionId 4
+
+        let first_page = Reactions::get_reactions_by_account(ACCOUNT2, None, 2);
+        assert_eq!(first_page.len(), 2);
+
+        let (last_id, _, _) = first_page.last().unwrap();
+        let second_page = Reactions::get_reactions_by_account(ACCOUNT2, Some(*last_id), 2);
+        assert_eq!(second_page.len(), 1);
+
+        let mut all_reactions: Vec<_> = first_page.into_iter().chain(second_page).collect();
+        all_reactions.sort_by_key(|(id, _, _)| *id);
+        assert_eq!(
+            all_reactions,
+            vec![
+                (REACTION1, ReactionTarget::Post(POST1), reaction_upvote()),
+                (REACTION2, ReactionTarget::Post(POST2), reaction_downvote()),
+                (REACTION3, ReactionTarget::Space(SPACE1), reaction_upvote()),
+            ]
+        );
+    });
+}
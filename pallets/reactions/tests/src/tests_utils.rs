@@ -2,10 +2,10 @@ use frame_support::{assert_ok, pallet_prelude::*};
 use sp_core::storage::Storage;
 use sp_io::TestExternalities;
 
-use pallet_permissions::SpacePermissions;
-use pallet_posts::{PostExtension, PostUpdate};
-use pallet_reactions::{ReactionId, ReactionKind};
-use pallet_spaces::types::SpaceUpdate;
+use pallet_permissions::{SpacePermission as SP, SpacePermissions};
+use pallet_posts::{Comment, PostExtension, PostUpdate};
+use pallet_reactions::{Emoji, ReactionId, ReactionKind};
+use pallet_spaces::types::{SpaceUpdate, SubscriptionPlan};
 use subsocial_support::{Content, PostId, SpaceId};
 
 use crate::mock::*;
@@ -59,6 +59,13 @@ impl ExtBuilder {
         ext
     }
 
+    /// Custom ext configuration with SpaceId 1 and BlockNumber 1
+    pub fn build_with_space() -> TestExternalities {
+        let mut ext = Self::build();
+        ext.execute_with(Self::add_default_space);
+        ext
+    }
+
     /// Custom ext configuration with SpaceId 1-2, PostId 1 where BlockNumber 1
     pub fn build_with_post_and_two_spaces() -> TestExternalities {
         let mut ext = Self::build_with_post();
@@ -86,9 +93,11 @@ pub(crate) const ACCOUNT3: AccountId = 3;
 pub(crate) const SPACE1: SpaceId = 1001;
 
 pub(crate) const POST1: PostId = 1;
+pub(crate) const POST2: PostId = 2;
 
 pub(crate) const REACTION1: ReactionId = 1;
 pub(crate) const REACTION2: ReactionId = 2;
+pub(crate) const REACTION3: ReactionId = 3;
 
 ///////////// Space Utils
 
@@ -97,11 +106,11 @@ pub(crate) fn space_content_ipfs() -> Content {
 }
 
 pub(crate) fn another_space_content_ipfs() -> Content {
-    Content::IPFS(b"bafyrelt3cif35x4ribisxgq7unhpun525l54eib3mgbou4xln42qqcgj6q".to_vec())
+    Content::IPFS(b"bafyreia2dve77krat5lsuqdep6xi6ing5hp5xfd6euqehvbvszg27v6he4".to_vec())
 }
 
 pub(crate) fn space_update(content: Option<Content>, hidden: Option<bool>) -> SpaceUpdate {
-    SpaceUpdate { content, hidden, permissions: None }
+    SpaceUpdate { content, handle: None, hidden, permissions: None }
 }
 
 pub(crate) fn _create_default_space() -> DispatchResult {
@@ -117,6 +126,7 @@ pub(crate) fn _create_space(
         origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
         content.unwrap_or_else(space_content_ipfs),
         permissions.unwrap_or_default(),
+        None,
     )
 }
 
@@ -147,13 +157,44 @@ pub(crate) fn post_update(
     content: Option<Content>,
     hidden: Option<bool>,
 ) -> PostUpdate {
-    PostUpdate { space_id, content, hidden }
+    PostUpdate { space_id, content, hidden, permissions: None, subscribers_only: None }
+}
+
+/// Post permission overrides, where no one can downvote a post.
+pub(crate) fn permissions_where_no_one_can_downvote() -> SpacePermissions {
+    SpacePermissions { none: Some(vec![SP::Downvote].into_iter().collect()), ..Default::default() }
+}
+
+/// Post permission overrides, where no one can react with custom reaction kinds.
+pub(crate) fn permissions_where_no_one_can_react_with_custom_kinds() -> SpacePermissions {
+    SpacePermissions {
+        none: Some(vec![SP::ReactWithCustomKinds].into_iter().collect()),
+        ..Default::default()
+    }
+}
+
+/// Make post 1 available only to subscribers of space 1.
+pub(crate) fn _make_post_1_subscribers_only() -> DispatchResult {
+    assert_ok!(Spaces::set_subscription_plan(
+        RuntimeOrigin::signed(ACCOUNT1),
+        SPACE1,
+        Some(SubscriptionPlan { price: 10, period: 10 }),
+    ));
+    _update_post(
+        None,
+        None,
+        Some(PostUpdate { subscribers_only: Some(true), ..Default::default() }),
+    )
 }
 
 pub(crate) fn extension_regular_post() -> PostExtension {
     PostExtension::RegularPost
 }
 
+pub(crate) fn extension_comment(parent_id: Option<PostId>, root_post_id: PostId) -> PostExtension {
+    PostExtension::Comment(Comment { parent_id, root_post_id })
+}
+
 pub(crate) fn _create_default_post() -> DispatchResult {
     _create_post(None, None, None, None)
 }
@@ -169,6 +210,7 @@ pub(crate) fn _create_post(
         space_id_opt.unwrap_or(Some(SPACE1)),
         extension.unwrap_or_else(extension_regular_post),
         content.unwrap_or_else(post_content_ipfs),
+        None,
     )
 }
 
@@ -194,6 +236,24 @@ pub(crate) fn reaction_downvote() -> ReactionKind {
     ReactionKind::Downvote
 }
 
+pub(crate) fn emoji(kind: &str) -> Emoji {
+    kind.as_bytes().to_vec().try_into().unwrap()
+}
+
+pub(crate) fn reaction_custom(kind: &str) -> ReactionKind {
+    ReactionKind::Custom(emoji(kind))
+}
+
+pub(crate) fn default_custom_reaction_kinds() -> Vec<Emoji> {
+    vec![emoji("❤️"), emoji("🎉")]
+}
+
+/// The deposit that should be held for a reaction in its current state.
+pub(crate) fn expected_reaction_deposit(reaction_id: ReactionId) -> Balance {
+    let reaction = Reactions::reaction_by_id(reaction_id).unwrap();
+    reaction.encoded_size() as Balance * ReactionByteDeposit::get()
+}
+
 pub(crate) fn _create_default_post_reaction() -> DispatchResult {
     _create_post_reaction(None, None, None)
 }
@@ -235,3 +295,81 @@ pub(crate) fn _delete_post_reaction(
         reaction_id,
     )
 }
+
+pub(crate) fn _remove_post_1_reactions(limit: u32) -> DispatchResultWithPostInfo {
+    Reactions::remove_post_reactions(RuntimeOrigin::signed(ACCOUNT3), POST1, limit)
+}
+
+pub(crate) fn _create_default_space_reaction() -> DispatchResult {
+    _create_space_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)
+}
+
+pub(crate) fn _create_space_reaction(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    kind: Option<ReactionKind>,
+) -> DispatchResult {
+    Reactions::create_space_reaction(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        kind.unwrap_or_else(reaction_upvote),
+    )
+}
+
+pub(crate) fn _update_space_reaction(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    reaction_id: ReactionId,
+    kind: Option<ReactionKind>,
+) -> DispatchResult {
+    Reactions::update_space_reaction(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        reaction_id,
+        kind.unwrap_or_else(reaction_upvote),
+    )
+}
+
+pub(crate) fn _delete_space_reaction(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    reaction_id: ReactionId,
+) -> DispatchResult {
+    Reactions::delete_space_reaction(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        reaction_id,
+    )
+}
+
+pub(crate) fn _set_default_custom_reaction_kinds() -> DispatchResult {
+    _set_custom_reaction_kinds(None, None, default_custom_reaction_kinds())
+}
+
+pub(crate) fn _set_custom_reaction_kinds(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    kinds: Vec<Emoji>,
+) -> DispatchResult {
+    Reactions::set_custom_reaction_kinds(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        kinds,
+    )
+}
+
+pub(crate) fn _tip_default_post(amount: Balance) -> DispatchResult {
+    _tip_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None, amount)
+}
+
+pub(crate) fn _tip_post(
+    origin: Option<RuntimeOrigin>,
+    post_id: Option<PostId>,
+    amount: Balance,
+) -> DispatchResult {
+    Reactions::tip_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        post_id.unwrap_or(POST1),
+        amount,
+    )
+}
@@ -6,7 +6,7 @@
 
 use super::*;
 use frame_benchmarking::{account, benchmarks};
-use frame_support::dispatch::DispatchError;
+use frame_support::{dispatch::DispatchError, sp_runtime::traits::Bounded, traits::Currency};
 use frame_system::RawOrigin;
 use pallet_permissions::SpacePermission as SP;
 use pallet_spaces::types::Space;
@@ -17,9 +17,17 @@ use subsocial_support::mock_functions::{valid_content_ipfs, another_valid_conten
 fn create_dummy_space<T: Config + pallet_spaces::Config>(
     origin: RawOrigin<T::AccountId>,
 ) -> Result<Space<T>, DispatchError> {
+    if let RawOrigin::Signed(owner) = &origin {
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            owner,
+            pallet_spaces::types::BalanceOf::<T>::max_value(),
+        );
+        <T as Config>::Currency::make_free_balance_be(owner, BalanceOf::<T>::max_value());
+    }
+
     let space_id = pallet_spaces::NextSpaceId::<T>::get();
 
-    pallet_spaces::Pallet::<T>::create_space(origin.into(), Content::None, None)?;
+    pallet_spaces::Pallet::<T>::create_space(origin.into(), Content::None, None, None)?;
 
     let space = pallet_spaces::SpaceById::<T>::get(space_id)
         .ok_or(DispatchError::Other("Space not found"))?;
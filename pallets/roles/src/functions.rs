@@ -2,6 +2,10 @@ use super::*;
 
 use frame_support::dispatch::DispatchError;
 use pallet_permissions::SpacePermissionsContext;
+use sp_runtime::traits::Saturating;
+use subsocial_support::{
+    refund_storage_deposit, traits::BeforeSpaceDeleted, update_storage_deposit,
+};
 
 impl<T: Config> Pallet<T> {
     /// Check that there is a `Role` with such `role_id` in the storage
@@ -109,6 +113,67 @@ impl<T: Config> Pallet<T> {
 
         Err(error)
     }
+
+    /// Adjust the deposit held for a given role to its' encoded size.
+    ///
+    /// The deposit stays with the account that has reserved it, no matter who edits the role.
+    /// A role that has no deposit yet (e.g. created before deposits were introduced)
+    /// gets it reserved from the role creator.
+    pub(crate) fn update_role_deposit(role: &Role<T>) -> DispatchResult {
+        let amount = T::RoleByteDeposit::get()
+            .saturating_mul(BalanceOf::<T>::from(role.encoded_size() as u32));
+
+        let old_deposit_opt = Self::deposit_by_role_id(role.id);
+        let depositor = old_deposit_opt
+            .as_ref()
+            .map_or_else(|| role.created.account.clone(), |deposit| deposit.depositor.clone());
+
+        let deposit = update_storage_deposit::<_, T::Currency>(old_deposit_opt, depositor, amount)?;
+        DepositByRoleId::<T>::insert(role.id, deposit);
+        Ok(())
+    }
+
+    /// Return the deposit held for a given role back to its depositor.
+    pub(crate) fn refund_role_deposit(role_id: RoleId) {
+        if let Some(deposit) = DepositByRoleId::<T>::take(role_id) {
+            refund_storage_deposit::<_, T::Currency>(deposit);
+        }
+    }
+
+    /// Delete the roles of a space in the order they were created, while the number of
+    /// processed roles and their users fits into `budget`.
+    ///
+    /// Roles that don't fit are kept in `RoleIdsBySpaceId` and can be deleted
+    /// by anyone with `delete_role` once the space is deleted.
+    pub(crate) fn remove_space_roles(space_id: SpaceId, budget: usize) {
+        let role_ids = Self::role_ids_by_space_id(space_id);
+        let mut budget = budget;
+        let mut removed_count = 0;
+
+        for role_id in role_ids.iter().copied() {
+            let users = Self::users_by_role_id(role_id);
+            let cost = users.len().saturating_add(1);
+            if cost > budget {
+                break
+            }
+            budget -= cost;
+
+            if let Some(role) = Self::role_by_id(role_id) {
+                role.revoke_from_users(users);
+            }
+
+            <RoleById<T>>::remove(role_id);
+            <UsersByRoleId<T>>::remove(role_id);
+            Self::refund_role_deposit(role_id);
+            removed_count += 1;
+        }
+
+        if removed_count == role_ids.len() {
+            RoleIdsBySpaceId::<T>::remove(space_id);
+        } else if removed_count > 0 {
+            RoleIdsBySpaceId::<T>::insert(space_id, role_ids[removed_count..].to_vec());
+        }
+    }
 }
 
 impl<T: Config> Role<T> {
@@ -185,3 +250,12 @@ impl<T: Config> PermissionChecker for Pallet<T> {
         Self::ensure_user_has_space_permission(user, ctx, permission, error)
     }
 }
+
+impl<T: Config> BeforeSpaceDeleted for Pallet<T> {
+    /// At most `MaxUsersToProcessPerDeleteRole` roles and users are processed here,
+    /// so that deleting a space has a bounded weight. See `remove_space_roles`.
+    fn before_space_deleted(space_id: SpaceId) -> DispatchResult {
+        Self::remove_space_roles(space_id, T::MaxUsersToProcessPerDeleteRole::get().into());
+        Ok(())
+    }
+}
@@ -11,7 +11,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get};
+use frame_support::{
+    dispatch::DispatchResult,
+    ensure,
+    traits::{Currency, Get, ReservableCurrency},
+};
 use frame_system::{self as system, ensure_signed};
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
@@ -23,7 +27,7 @@ use pallet_permissions::{
 use subsocial_support::{
     convert_users_vec_to_btree_set, ensure_content_is_valid, new_who_and_when,
     traits::{IsAccountBlocked, IsContentBlocked, SpaceFollowsProvider, SpacePermissionsProvider},
-    Content, ModerationError, SpaceId, User, WhoAndWhenOf,
+    Content, ModerationError, SpaceId, StorageDeposit, User, WhoAndWhenOf,
 };
 
 pub use pallet::*;
@@ -75,6 +79,17 @@ pub mod pallet {
 
         type IsContentBlocked: IsContentBlocked;
 
+        /// Max length of an inline content, in bytes.
+        #[pallet::constant]
+        type MaxInlineContentLen: Get<u32>;
+
+        /// The currency, in which storage deposits are held.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The amount held on deposit per byte of an encoded role.
+        #[pallet::constant]
+        type RoleByteDeposit: Get<BalanceOf<Self>>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -172,6 +187,11 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// The deposit held for storing a role, by the role id.
+    #[pallet::storage]
+    #[pallet::getter(fn deposit_by_role_id)]
+    pub type DepositByRoleId<T: Config> = StorageMap<_, Twox64Concat, RoleId, StorageDepositOf<T>>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Create a new role, with a list of permissions, within a given space.
@@ -193,7 +213,7 @@ pub mod pallet {
 
             ensure!(!permissions.is_empty(), Error::<T>::NoPermissionsProvided);
 
-            ensure_content_is_valid(content.clone())?;
+            ensure_content_is_valid::<T::MaxInlineContentLen>(content.clone())?;
             ensure!(
                 T::IsContentBlocked::is_allowed_content(content.clone(), space_id),
                 ModerationError::ContentIsBlocked,
@@ -209,6 +229,8 @@ pub mod pallet {
             let next_role_id = new_role.id.checked_add(1).ok_or(Error::<T>::RoleIdOverflow)?;
             NextRoleId::<T>::put(next_role_id);
 
+            Self::update_role_deposit(&new_role)?;
+
             RoleById::<T>::insert(new_role.id, new_role.clone());
             RoleIdsBySpaceId::<T>::mutate(space_id, |role_ids| role_ids.push(new_role.id));
 
@@ -252,7 +274,7 @@ pub mod pallet {
 
             if let Some(content) = update.content {
                 if content != role.content {
-                    ensure_content_is_valid(content.clone())?;
+                    ensure_content_is_valid::<T::MaxInlineContentLen>(content.clone())?;
                     ensure!(
                         T::IsContentBlocked::is_allowed_content(content.clone(), role.space_id),
                         ModerationError::ContentIsBlocked
@@ -276,6 +298,7 @@ pub mod pallet {
             }
 
             if is_update_applied {
+                Self::update_role_deposit(&role)?;
                 <RoleById<T>>::insert(role_id, role);
                 Self::deposit_event(Event::RoleUpdated { account: who, role_id });
             }
@@ -284,6 +307,10 @@ pub mod pallet {
 
         /// Delete a given role and clean all associated storage items.
         /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
+        ///
+        /// Roles that did not fit into the limit of processed roles and users when their space
+        /// was deleted are kept after that. Anyone can delete such roles,
+        /// which returns their deposits to the depositors.
         #[pallet::call_index(2)]
         #[pallet::weight(<T as Config>::WeightInfo::delete_role(*user_count))]
         pub fn delete_role(
@@ -295,7 +322,9 @@ pub mod pallet {
 
             let role = Self::require_role(role_id)?;
 
-            Self::ensure_role_manager(who.clone(), role.space_id)?;
+            if T::SpacePermissionsProvider::space_permissions_info(role.space_id).is_ok() {
+                Self::ensure_role_manager(who.clone(), role.space_id)?;
+            }
 
             let users = Self::users_by_role_id(role_id);
             ensure!(users.len() as u32 == user_count, Error::<T>::IncorrectUserCount);
@@ -315,6 +344,7 @@ pub mod pallet {
 
             <RoleById<T>>::remove(role_id);
             <UsersByRoleId<T>>::remove(role_id);
+            Self::refund_role_deposit(role_id);
 
             Self::deposit_event(Event::RoleDeleted { account: who, role_id });
             Ok(())
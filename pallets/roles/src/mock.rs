@@ -8,7 +8,7 @@ use frame_support::{
     assert_ok,
     dispatch::{DispatchError, DispatchResult},
     parameter_types,
-    traits::{ConstU32, Everything},
+    traits::{ConstU32, ConstU64, Everything},
 };
 use sp_runtime::{
     testing::Header,
@@ -113,6 +113,10 @@ parameter_types! {
   pub const MaxUsersToProcessPerDeleteRole: u16 = 20;
 }
 
+parameter_types! {
+    pub static RoleByteDeposit: u64 = 0;
+}
+
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
@@ -123,6 +127,9 @@ impl Config for Test {
     type SpaceFollows = Roles;
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
+    type MaxInlineContentLen = ConstU32<512>;
+    type Currency = Balances;
+    type RoleByteDeposit = RoleByteDeposit;
     type WeightInfo = ();
 }
 
@@ -132,7 +139,17 @@ impl pallet_spaces::Config for Test {
     type SpaceFollows = Roles;
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
+    type IsSpaceBlocked = ();
+    type MaxInlineContentLen = ConstU32<512>;
+    type ProfileManager = ();
+    type BeforeSpaceDeleted = ();
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<5>;
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type MaxSpaceContentHistory = ConstU32<10>;
+    type Currency = Balances;
+    type SpaceByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -257,6 +274,12 @@ pub(crate) fn role_update(
     RoleUpdate { disabled, content, permissions }
 }
 
+/// The deposit that should be held for a role in its current state.
+pub(crate) fn expected_role_deposit(role_id: RoleId) -> Balance {
+    let role = Roles::role_by_id(role_id).unwrap();
+    role.encoded_size() as Balance * RoleByteDeposit::get()
+}
+
 pub(crate) fn _create_default_role() -> DispatchResult {
     _create_role(None, None, None, None, None)
 }
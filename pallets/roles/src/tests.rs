@@ -1,7 +1,7 @@
 use crate::{mock::*, *};
 
 use frame_support::{assert_noop, assert_ok};
-use subsocial_support::ContentError;
+use subsocial_support::{traits::BeforeSpaceDeleted, ContentError};
 
 #[test]
 fn create_role_should_work() {
@@ -22,6 +22,33 @@ fn create_role_should_work() {
     });
 }
 
+#[test]
+fn create_role_should_reserve_storage_deposit() {
+    ExtBuilder::build().execute_with(|| {
+        RoleByteDeposit::set(1);
+        Balances::make_free_balance_be(&ACCOUNT1, 100);
+
+        assert_ok!(_create_default_role()); // RoleId 1
+
+        let expected_deposit = expected_role_deposit(ROLE1);
+        assert!(expected_deposit > 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), expected_deposit);
+
+        let deposit = Roles::deposit_by_role_id(ROLE1).unwrap();
+        assert_eq!(deposit.depositor, ACCOUNT1);
+        assert_eq!(deposit.amount, expected_deposit);
+    });
+}
+
+#[test]
+fn create_role_should_fail_when_balance_is_too_low_for_deposit() {
+    ExtBuilder::build().execute_with(|| {
+        RoleByteDeposit::set(1);
+
+        assert_noop!(_create_default_role(), pallet_balances::Error::<Test>::InsufficientBalance);
+    });
+}
+
 #[test]
 fn create_role_should_work_with_a_few_roles() {
     ExtBuilder::build_with_a_few_roles_granted_to_account2().execute_with(|| {
@@ -500,6 +527,21 @@ fn delete_role_should_work() {
     });
 }
 
+#[test]
+fn delete_role_should_refund_storage_deposit() {
+    ExtBuilder::build().execute_with(|| {
+        RoleByteDeposit::set(1);
+        Balances::make_free_balance_be(&ACCOUNT1, 100);
+
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_delete_default_role());
+
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        assert_eq!(Balances::free_balance(ACCOUNT1), 100);
+        assert!(Roles::deposit_by_role_id(ROLE1).is_none());
+    });
+}
+
 #[test]
 fn delete_role_should_work_with_a_few_roles() {
     ExtBuilder::build_with_a_few_roles_granted_to_account2().execute_with(|| {
@@ -517,6 +559,44 @@ fn delete_role_should_work_with_a_few_roles() {
     });
 }
 
+#[test]
+fn before_space_deleted_should_remove_space_roles() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2().execute_with(|| {
+        RoleByteDeposit::set(1);
+        Balances::make_free_balance_be(&ACCOUNT1, 100);
+        assert_ok!(_create_default_role()); // RoleId 3
+
+        assert_ok!(Roles::before_space_deleted(SPACE1));
+
+        for role_id in [ROLE1, ROLE2, ROLE3] {
+            assert!(Roles::role_by_id(role_id).is_none());
+            assert!(Roles::users_by_role_id(role_id).is_empty());
+            assert!(Roles::deposit_by_role_id(role_id).is_none());
+        }
+        assert!(Roles::role_ids_by_space_id(SPACE1).is_empty());
+        assert!(Roles::role_ids_by_user_in_space(User::Account(ACCOUNT2), SPACE1).is_empty());
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+    });
+}
+
+#[test]
+fn before_space_deleted_should_keep_roles_over_the_limit_of_users() {
+    ExtBuilder::build().execute_with(|| {
+        let users: Vec<User<AccountId>> = (2..22).map(User::Account).collect();
+
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_create_default_role()); // RoleId 2
+        assert_ok!(_grant_role(None, Some(ROLE2), Some(users))); // Grant RoleId 2 to 20 users
+
+        assert_ok!(Roles::before_space_deleted(SPACE1));
+
+        assert!(Roles::role_by_id(ROLE1).is_none());
+        assert!(Roles::role_by_id(ROLE2).is_some());
+        assert_eq!(Roles::role_ids_by_space_id(SPACE1), vec![ROLE2]);
+        assert_eq!(Roles::users_by_role_id(ROLE2).len(), 20);
+    });
+}
+
 #[test]
 fn delete_role_should_fail_with_role_not_found() {
     ExtBuilder::build().execute_with(|| {
@@ -4,6 +4,11 @@ pub type RoleId = u64;
 
 pub const FIRST_ROLE_ID: u64 = 1;
 
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type StorageDepositOf<T> = StorageDeposit<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
 /// Information about a role's permissions, its' containing space, and its' content.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -56,34 +56,40 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             // Storage: Timestamp Now (r:1 w:0)
             // Storage: Roles RoleIdsBySpaceId (r:1 w:1)
             // Storage: Roles RoleById (r:0 w:1)
+            // Storage: Roles DepositByRoleId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
         fn create_role() -> Weight {
         // Minimum execution time: 52_528 nanoseconds.
         Weight::from_ref_time(53_688_000)
-            .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(5))
         }
             // Storage: Roles RoleById (r:1 w:1)
             // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Roles DepositByRoleId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
         fn update_role() -> Weight {
         // Minimum execution time: 48_647 nanoseconds.
         Weight::from_ref_time(50_219_000)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
         }
             // Storage: Roles RoleById (r:1 w:1)
             // Storage: Spaces SpaceById (r:1 w:0)
             // Storage: Roles UsersByRoleId (r:1 w:1)
             // Storage: Roles RoleIdsBySpaceId (r:1 w:1)
             // Storage: Roles RoleIdsByUserInSpace (r:1 w:1)
+            // Storage: Roles DepositByRoleId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
             /// The range of component `x` is `[0, 40]`.
         fn delete_role(x: u32, ) -> Weight {
         // Minimum execution time: 57_007 nanoseconds.
         Weight::from_ref_time(64_783_236)
             // Standard Error: 26_706
             .saturating_add(Weight::from_ref_time(8_701_651).saturating_mul(x.into()))
-            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().reads(6))
             .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes(5))
             .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
         }
             // Storage: Roles RoleById (r:1 w:0)
@@ -125,34 +131,40 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             // Storage: Timestamp Now (r:1 w:0)
             // Storage: Roles RoleIdsBySpaceId (r:1 w:1)
             // Storage: Roles RoleById (r:0 w:1)
+            // Storage: Roles DepositByRoleId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
         fn create_role() -> Weight {
         // Minimum execution time: 52_528 nanoseconds.
         Weight::from_ref_time(53_688_000)
-            .saturating_add(RocksDbWeight::get().reads(4))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(5))
         }
             // Storage: Roles RoleById (r:1 w:1)
             // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Roles DepositByRoleId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
         fn update_role() -> Weight {
         // Minimum execution time: 48_647 nanoseconds.
         Weight::from_ref_time(50_219_000)
-            .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
         }
             // Storage: Roles RoleById (r:1 w:1)
             // Storage: Spaces SpaceById (r:1 w:0)
             // Storage: Roles UsersByRoleId (r:1 w:1)
             // Storage: Roles RoleIdsBySpaceId (r:1 w:1)
             // Storage: Roles RoleIdsByUserInSpace (r:1 w:1)
+            // Storage: Roles DepositByRoleId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
             /// The range of component `x` is `[0, 40]`.
         fn delete_role(x: u32, ) -> Weight {
         // Minimum execution time: 57_007 nanoseconds.
         Weight::from_ref_time(64_783_236)
             // Standard Error: 26_706
             .saturating_add(Weight::from_ref_time(8_701_651).saturating_mul(x.into()))
-            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads(6))
             .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().writes(5))
             .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
         }
             // Storage: Roles RoleById (r:1 w:0)
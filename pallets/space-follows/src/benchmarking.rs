@@ -4,17 +4,26 @@
 
 use super::*;
 use frame_benchmarking::{account, benchmarks};
-use frame_support::{dispatch::DispatchError, ensure};
+use frame_support::{
+    dispatch::DispatchError, ensure, sp_runtime::traits::Bounded, traits::Currency,
+};
 use frame_system::RawOrigin;
-use pallet_spaces::types::Space;
+use pallet_spaces::types::{BalanceOf, Space};
 use subsocial_support::Content;
 
 fn create_dummy_space<T: Config>(
     origin: RawOrigin<T::AccountId>,
 ) -> Result<Space<T>, DispatchError> {
+    if let RawOrigin::Signed(owner) = &origin {
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            owner,
+            BalanceOf::<T>::max_value(),
+        );
+    }
+
     let space_id = pallet_spaces::NextSpaceId::<T>::get();
 
-    pallet_spaces::Pallet::<T>::create_space(origin.clone().into(), Content::None, None)?;
+    pallet_spaces::Pallet::<T>::create_space(origin.clone().into(), Content::None, None, None)?;
 
     let space = pallet_spaces::SpaceById::<T>::get(space_id)
         .ok_or(DispatchError::Other("Space not found"))?;
@@ -22,7 +22,7 @@ pub mod pallet {
     use sp_std::vec::Vec;
     use subsocial_support::{
         remove_from_vec,
-        traits::{IsAccountBlocked, SpaceFollowsProvider},
+        traits::{BeforeSpaceDeleted, IsAccountBlocked, SpaceFollowsProvider},
         ModerationError, SpaceId,
     };
 
@@ -84,7 +84,7 @@ pub mod pallet {
             );
 
             let space = Spaces::<T>::require_space(space_id)?;
-            ensure!(!space.hidden, Error::<T>::CannotFollowHiddenSpace);
+            ensure!(!space.is_hidden_in_hierarchy(), Error::<T>::CannotFollowHiddenSpace);
 
             ensure!(
                 T::IsAccountBlocked::is_allowed_account(follower.clone(), space.id),
@@ -96,13 +96,12 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Unfollow a given space. A deleted space can be unfollowed too.
         #[pallet::call_index(1)]
         #[pallet::weight(<T as Config>::WeightInfo::unfollow_space())]
         pub fn unfollow_space(origin: OriginFor<T>, space_id: SpaceId) -> DispatchResult {
             let follower = ensure_signed(origin)?;
 
-            Spaces::<T>::ensure_space_exists(space_id)?;
-
             ensure!(
                 Self::space_followed_by_account((follower.clone(), space_id)),
                 Error::<T>::NotSpaceFollower
@@ -150,9 +149,12 @@ pub mod pallet {
             SpacesFollowedByAccount::<T>::mutate(follower.clone(), |space_ids| {
                 remove_from_vec(space_ids, space_id)
             });
-            SpaceFollowers::<T>::mutate(space_id, |account_ids| {
-                remove_from_vec(account_ids, follower.clone())
-            });
+            // The list of followers is already removed, if the space was deleted.
+            if SpaceFollowers::<T>::contains_key(space_id) {
+                SpaceFollowers::<T>::mutate(space_id, |account_ids| {
+                    remove_from_vec(account_ids, follower.clone())
+                });
+            }
             SpaceFollowedByAccount::<T>::remove((follower.clone(), space_id));
 
             Self::deposit_event(Event::SpaceUnfollowed { follower, space_id });
@@ -167,4 +169,13 @@ pub mod pallet {
             Pallet::<T>::space_followed_by_account((account, space_id))
         }
     }
+
+    impl<T: Config> BeforeSpaceDeleted for Pallet<T> {
+        /// Only the list of space followers is removed here. Each follower keeps the deleted
+        /// space among the followed ones, until they unfollow it.
+        fn before_space_deleted(space_id: SpaceId) -> DispatchResult {
+            SpaceFollowers::<T>::remove(space_id);
+            Ok(())
+        }
+    }
 }
@@ -1,4 +1,8 @@
-use frame_support::{pallet_prelude::ConstU32, parameter_types, traits::Everything};
+use frame_support::{
+    pallet_prelude::ConstU32,
+    parameter_types,
+    traits::{ConstU64, Everything},
+};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -103,6 +107,9 @@ impl pallet_roles::Config for Test {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
+    type MaxInlineContentLen = ConstU32<512>;
+    type Currency = Balances;
+    type RoleByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -112,7 +119,17 @@ impl pallet_spaces::Config for Test {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
+    type IsSpaceBlocked = ();
+    type MaxInlineContentLen = ConstU32<512>;
+    type ProfileManager = ();
+    type BeforeSpaceDeleted = SpaceFollows;
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<5>;
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type MaxSpaceContentHistory = ConstU32<10>;
+    type Currency = Balances;
+    type SpaceByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
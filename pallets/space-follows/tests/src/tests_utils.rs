@@ -75,7 +75,7 @@ pub(crate) fn space_content_ipfs() -> Content {
 }
 
 pub(crate) fn space_update(content: Option<Content>, hidden: Option<bool>) -> SpaceUpdate {
-    SpaceUpdate { content, hidden, permissions: None }
+    SpaceUpdate { content, handle: None, hidden, permissions: None }
 }
 
 pub(crate) fn _create_default_space() -> DispatchResult {
@@ -93,6 +93,7 @@ pub(crate) fn _create_space(
         origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
         content.unwrap_or_else(space_content_ipfs),
         permissions.unwrap_or_default(),
+        None,
     )
 }
 
@@ -21,14 +21,12 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
 
-    use subsocial_support::traits::ProfileManager;
+    use subsocial_support::traits::{BeforeSpaceDeleted, ProfileManager};
 
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_spaces::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        type ProfileManager: ProfileManager<Self::AccountId>;
-
         type WeightInfo: WeightInfo;
     }
 
@@ -165,4 +163,11 @@ pub mod pallet {
             Ok(())
         }
     }
+
+    impl<T: Config> BeforeSpaceDeleted for Pallet<T> {
+        fn before_space_deleted(space_id: SpaceId) -> DispatchResult {
+            PendingSpaceOwner::<T>::remove(space_id);
+            Ok(())
+        }
+    }
 }
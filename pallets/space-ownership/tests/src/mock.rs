@@ -1,4 +1,8 @@
-use frame_support::{pallet_prelude::ConstU32, parameter_types, traits::Everything};
+use frame_support::{
+    pallet_prelude::ConstU32,
+    parameter_types,
+    traits::{ConstU64, Everything},
+};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -105,6 +109,9 @@ impl pallet_roles::Config for Test {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
+    type MaxInlineContentLen = ConstU32<512>;
+    type Currency = Balances;
+    type RoleByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -121,7 +128,17 @@ impl pallet_spaces::Config for Test {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
+    type IsSpaceBlocked = ();
+    type MaxInlineContentLen = ConstU32<512>;
+    type ProfileManager = Profiles;
+    type BeforeSpaceDeleted = (SpaceFollows, SpaceOwnership);
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<5>;
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type MaxSpaceContentHistory = ConstU32<10>;
+    type Currency = Balances;
+    type SpaceByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -132,6 +149,5 @@ impl pallet_space_follows::Config for Test {
 
 impl pallet_space_ownership::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type ProfileManager = Profiles;
     type WeightInfo = ();
 }
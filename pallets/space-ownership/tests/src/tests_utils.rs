@@ -86,6 +86,7 @@ pub(crate) fn _create_space(
         origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
         content.unwrap_or_else(space_content_ipfs),
         permissions.unwrap_or_default(),
+        None,
     )
 }
 
@@ -1,7 +1,9 @@
 //! Spaces pallet benchmarking.
 
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_support::{assert_ok, ensure, pallet_prelude::Get};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+    assert_ok, ensure, pallet_prelude::Get, sp_runtime::traits::Bounded, traits::Currency,
+};
 use frame_system::RawOrigin;
 
 use crate::{types::*, Config};
@@ -13,12 +15,25 @@ fn dummy_space_content() -> Content {
 }
 
 fn create_dummy_space<T: Config>(caller: T::AccountId) -> Space<T> {
-    assert_ok!(Pallet::<T>::create_space(RawOrigin::Signed(caller).into(), Content::None, None));
+    T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+    assert_ok!(Pallet::<T>::create_space(
+        RawOrigin::Signed(caller).into(),
+        Content::None,
+        None,
+        None
+    ));
     let id = Pallet::<T>::next_space_id() - 1;
 
     SpaceById::<T>::get(id).expect("qed; space should exist")
 }
 
+fn dummy_subscription_plan<T: Config>() -> SubscriptionPlanOf<T> {
+    SubscriptionPlan {
+        price: T::Currency::minimum_balance().max(BalanceOf::<T>::from(1u32)),
+        period: 10u32.into(),
+    }
+}
+
 benchmarks! {
     create_space {
         let caller = whitelisted_caller::<T::AccountId>();
@@ -28,9 +43,10 @@ benchmarks! {
 
         let content = dummy_space_content();
         let permissions_opt = None;
-    }: _(RawOrigin::Signed(caller), content, permissions_opt)
+    }: _(RawOrigin::Signed(caller), content, permissions_opt, Some(parent_space.id))
     verify {
-        ensure!(SpaceById::<T>::get(new_space_id).is_some(), "Created space should exist");
+        let space = SpaceById::<T>::get(new_space_id).expect("Created space should exist");
+        ensure!(space.parent_id == Some(parent_space.id), "Created space should be a subspace");
     }
 
     update_space {
@@ -44,6 +60,7 @@ benchmarks! {
 
         let space_update = SpaceUpdate {
             content: dummy_space_content().into(),
+            handle: Some(Some(b"space_handle".to_vec())),
             hidden: true.into(),
             permissions: Some(Some(<T as pallet_permissions::Config>::DefaultSpacePermissions::get())),
         };
@@ -51,7 +68,72 @@ benchmarks! {
     verify {
         let space_from_storage = SpaceById::<T>::get(space.id).expect("Updated space should exist");
         assert!(space_from_storage.content.is_some());
+        assert!(space_from_storage.handle.is_some());
         assert!(space_from_storage.edited);
         assert!(space_from_storage.permissions.is_some());
     }
+
+    delete_space {
+        let caller = whitelisted_caller::<T::AccountId>();
+
+        let space = create_dummy_space::<T>(caller.clone());
+    }: _(RawOrigin::Signed(caller), space.id)
+    verify {
+        ensure!(SpaceById::<T>::get(space.id).is_none(), "Deleted space should not exist");
+    }
+
+    set_subscription_plan {
+        let caller = whitelisted_caller::<T::AccountId>();
+
+        let space = create_dummy_space::<T>(caller.clone());
+        let plan = dummy_subscription_plan::<T>();
+    }: _(RawOrigin::Signed(caller), space.id, Some(plan.clone()))
+    verify {
+        ensure!(
+            SubscriptionPlanBySpaceId::<T>::get(space.id) == Some(plan),
+            "Subscription plan was not set"
+        );
+    }
+
+    subscribe_to_space {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let space = create_dummy_space::<T>(owner.clone());
+        let plan = dummy_subscription_plan::<T>();
+        assert_ok!(Pallet::<T>::set_subscription_plan(
+            RawOrigin::Signed(owner.clone()).into(),
+            space.id,
+            Some(plan.clone()),
+        ));
+        // Leave room in the owner's balance to receive a payment.
+        T::Currency::make_free_balance_be(&owner, plan.price);
+
+        let caller = whitelisted_caller::<T::AccountId>();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+    }: _(RawOrigin::Signed(caller.clone()), space.id, plan.price)
+    verify {
+        ensure!(
+            Pallet::<T>::is_active_subscriber(&caller, space.id),
+            "Account should be an active subscriber"
+        );
+    }
+
+    remove_space_subscriptions {
+        let n in 0 .. 100;
+
+        let caller = whitelisted_caller::<T::AccountId>();
+        let space = create_dummy_space::<T>(caller.clone());
+
+        for i in 0..n {
+            let subscriber = account::<T::AccountId>("subscriber", i, 0);
+            let expires_at = T::BlockNumber::from(10u32);
+            SubscriptionExpiresAtByAccount::<T>::insert(space.id, subscriber, expires_at);
+        }
+        SpaceById::<T>::remove(space.id);
+    }: _(RawOrigin::Signed(caller), space.id, n)
+    verify {
+        ensure!(
+            SubscriptionExpiresAtByAccount::<T>::iter_key_prefix(space.id).next().is_none(),
+            "Subscriptions should be removed"
+        );
+    }
 }
@@ -27,24 +27,32 @@ use subsocial_support::{traits::SpaceFollowsProvider, Content, SpaceId};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 pub mod weights;
 
-// pub mod rpc;
 pub mod types;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::pallet_prelude::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ExistenceRequirement, ReservableCurrency},
+    };
     use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Saturating, Zero};
     use sp_std::vec::Vec;
 
     use pallet_permissions::{
         Pallet as Permissions, PermissionChecker, SpacePermissionsContext, SpacePermissionsInfoOf,
     };
     use subsocial_support::{
-        ensure_content_is_valid, remove_from_bounded_vec,
-        traits::{IsAccountBlocked, IsContentBlocked, SpacePermissionsProvider, SpacesInterface},
-        ModerationError, SpacePermissionsInfo, WhoAndWhen, WhoAndWhenOf,
+        ensure_content_is_valid, new_who_and_when, push_evicting_oldest, refund_storage_deposit,
+        remove_from_bounded_vec,
+        traits::{
+            BeforeSpaceDeleted, IsAccountBlocked, IsContentBlocked, IsSpaceBlocked, ProfileManager,
+            SpacePermissionsProvider, SpacesInterface,
+        },
+        update_storage_deposit, ModerationError, SpacePermissionsInfo, WhoAndWhen, WhoAndWhenOf,
     };
     use types::*;
 
@@ -52,6 +60,9 @@ pub mod pallet {
 
     use super::*;
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
     #[pallet::config]
     pub trait Config:
         frame_system::Config + pallet_permissions::Config + pallet_timestamp::Config
@@ -67,15 +78,51 @@ pub mod pallet {
 
         type IsContentBlocked: IsContentBlocked;
 
+        type IsSpaceBlocked: IsSpaceBlocked;
+
+        /// Max length of an inline content, in bytes.
+        #[pallet::constant]
+        type MaxInlineContentLen: Get<u32>;
+
+        type ProfileManager: ProfileManager<Self::AccountId>;
+
+        /// Pallets that should clean up their data (or reject the deletion)
+        /// before a space is deleted.
+        type BeforeSpaceDeleted: BeforeSpaceDeleted;
+
         #[pallet::constant]
         type MaxSpacesPerAccount: Get<u32>;
 
+        /// Max number of nested levels of subspaces.
+        #[pallet::constant]
+        type MaxSubspaceDepth: Get<u32>;
+
+        /// Min length of a space handle.
+        #[pallet::constant]
+        type MinHandleLen: Get<u32>;
+
+        /// Max length of a space handle.
+        #[pallet::constant]
+        type MaxHandleLen: Get<u32>;
+
+        /// Max number of previous contents of a space kept in its' edit history.
+        #[pallet::constant]
+        type MaxSpaceContentHistory: Get<u32>;
+
+        /// The currency, in which storage deposits are held.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The amount held on deposit per byte of an encoded space.
+        #[pallet::constant]
+        type SpaceByteDeposit: Get<BalanceOf<Self>>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
     #[pallet::generate_store(pub (super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -84,6 +131,11 @@ pub mod pallet {
     pub enum Event<T: Config> {
         SpaceCreated { account: T::AccountId, space_id: SpaceId },
         SpaceUpdated { account: T::AccountId, space_id: SpaceId },
+        SpaceDeleted { account: T::AccountId, space_id: SpaceId },
+        SubscriptionPlanUpdated { account: T::AccountId, space_id: SpaceId },
+        SpaceSubscribed { account: T::AccountId, space_id: SpaceId, expires_at: T::BlockNumber },
+        SpaceSubscriptionsPartiallyRemoved { space_id: SpaceId, removed_count: u32 },
+        SpaceSubscriptionsRemoved { space_id: SpaceId, removed_count: u32 },
     }
 
     #[pallet::error]
@@ -96,14 +148,36 @@ pub mod pallet {
         NotASpaceOwner,
         /// User has no permission to update this space.
         NoPermissionToUpdateSpace,
+        /// User has no permission to delete this space.
+        NoPermissionToDeleteSpace,
         /// User has no permission to create subspaces within this space.
         NoPermissionToCreateSubspaces,
         /// Space is at root level, no `parent_id` specified.
         SpaceIsAtRoot,
+        /// Not allowed to create a subspace in a hidden space.
+        CannotCreateInHiddenScope,
+        /// Max depth of nested subspaces reached.
+        MaxSubspaceDepthReached,
+        /// Cannot delete a space that has subspaces. Delete subspaces first.
+        SpaceHasSubspaces,
         /// New spaces' settings don't differ from the old ones.
         NoUpdatesForSpacesSettings,
         /// There are too many spaces created by this account already
         TooManySpacesPerAccount,
+        /// Space handle is not unique: another space already uses it.
+        SpaceHandleIsNotUnique,
+        /// This space has no subscription plan.
+        SubscriptionPlanNotFound,
+        /// Subscription period should be at least one block.
+        ZeroSubscriptionPeriod,
+        /// Space owner cannot subscribe to their own space.
+        CannotSubscribeToOwnSpace,
+        /// User has no permission to hide or unhide this space.
+        NoPermissionToHideSpace,
+        /// The price of the subscription plan is higher than the max price the account agreed to.
+        SubscriptionPriceTooHigh,
+        /// Subscriptions can be removed only after their space is deleted.
+        SpaceIsNotDeleted,
     }
 
     #[pallet::type_value]
@@ -118,7 +192,6 @@ pub mod pallet {
 
     /// Get the details of a space by its' id.
     #[pallet::storage]
-    #[pallet::getter(fn space_by_id)]
     pub type SpaceById<T: Config> = StorageMap<_, Twox64Concat, SpaceId, Space<T>>;
 
     /// Find the ids of all spaces owned, by a given account.
@@ -127,6 +200,47 @@ pub mod pallet {
     pub type SpaceIdsByOwner<T: Config> =
         StorageMap<_, Twox64Concat, T::AccountId, SpacesByAccount<T>, ValueQuery>;
 
+    /// Find a space id by its' lowercased handle.
+    #[pallet::storage]
+    #[pallet::getter(fn space_id_by_handle)]
+    pub type SpaceIdByHandle<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, SpaceId>;
+
+    /// Direct subspaces of a space, by the parent space id and the subspace id.
+    /// See `get_subspace_ids_by_space_id` to page through them.
+    #[pallet::storage]
+    pub type SubspaceIdsBySpaceId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Twox64Concat, SpaceId, ()>;
+
+    /// The number of direct subspaces of a space, by the parent space id.
+    #[pallet::storage]
+    #[pallet::getter(fn subspace_count_by_space_id)]
+    pub type SubspaceCountBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, u32, ValueQuery>;
+
+    /// Previous contents of a space, from the oldest to the latest one, by the space id.
+    #[pallet::storage]
+    #[pallet::getter(fn content_history_by_space_id)]
+    pub type ContentHistoryBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, SpaceContentHistory<T>, ValueQuery>;
+
+    /// The deposit held for storing a space, by the space id.
+    #[pallet::storage]
+    #[pallet::getter(fn deposit_by_space_id)]
+    pub type DepositBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, StorageDepositOf<T>>;
+
+    /// The subscription plan of a space, by the space id.
+    #[pallet::storage]
+    #[pallet::getter(fn subscription_plan_by_space_id)]
+    pub type SubscriptionPlanBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, SubscriptionPlanOf<T>>;
+
+    /// The block until which an account is subscribed to a space, exclusively.
+    #[pallet::storage]
+    #[pallet::getter(fn subscription_expires_at_by_account)]
+    pub type SubscriptionExpiresAtByAccount<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub endowed_account: Option<T::AccountId>,
@@ -146,6 +260,13 @@ pub mod pallet {
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: T::BlockNumber) -> Weight {
+            migration::v4::migrate_spaces::<T>(migration::MIGRATED_SPACES_PER_BLOCK)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -154,10 +275,11 @@ pub mod pallet {
             origin: OriginFor<T>,
             content: Content,
             permissions_opt: Option<SpacePermissions>,
+            parent_id_opt: Option<SpaceId>,
         ) -> DispatchResult {
             let owner = ensure_signed(origin)?;
 
-            Self::do_create_space(&owner, content, permissions_opt)?;
+            Self::do_create_space(&owner, content, permissions_opt, parent_id_opt)?;
             Ok(())
         }
 
@@ -170,8 +292,10 @@ pub mod pallet {
         ) -> DispatchResult {
             let owner = ensure_signed(origin)?;
 
-            let has_updates =
-                update.content.is_some() || update.hidden.is_some() || update.permissions.is_some();
+            let has_updates = update.content.is_some() ||
+                update.handle.is_some() ||
+                update.hidden.is_some() ||
+                update.permissions.is_some();
 
             ensure!(has_updates, Error::<T>::NoUpdatesForSpace);
 
@@ -182,30 +306,55 @@ pub mod pallet {
                 ModerationError::AccountIsBlocked
             );
 
-            Self::ensure_account_has_space_permission(
-                owner.clone(),
-                &space,
-                SpacePermission::UpdateSpace,
-                Error::<T>::NoPermissionToUpdateSpace.into(),
-            )?;
+            match space.parent_id {
+                Some(parent_id) => {
+                    let parent = Self::require_space(parent_id)?;
+                    // Hiding is checked separately, so that moderators of a parent space
+                    // can hide its' subspaces without being able to update them.
+                    if update.content.is_some() ||
+                        update.handle.is_some() ||
+                        update.permissions.is_some()
+                    {
+                        Self::ensure_account_can_update_subspace(owner.clone(), &space, &parent)?;
+                    }
+                    if update.hidden.map_or(false, |hidden| hidden != space.hidden) {
+                        Self::ensure_account_can_hide_subspace(owner.clone(), &space, &parent)?;
+                    }
+                },
+                None => Self::ensure_account_has_space_permission(
+                    owner.clone(),
+                    &space,
+                    SpacePermission::UpdateSpace,
+                    Error::<T>::NoPermissionToUpdateSpace.into(),
+                )?,
+            }
 
             let mut is_update_applied = false;
+            let mut previous_content_opt = None;
 
             if let Some(content) = update.content {
                 if content != space.content {
-                    ensure_content_is_valid(content.clone())?;
+                    ensure_content_is_valid::<T::MaxInlineContentLen>(content.clone())?;
 
                     ensure!(
                         T::IsContentBlocked::is_allowed_content(content.clone(), space.id),
                         ModerationError::ContentIsBlocked
                     );
 
-                    space.content = content;
+                    previous_content_opt = Some(sp_std::mem::replace(&mut space.content, content));
                     space.edited = true;
                     is_update_applied = true;
                 }
             }
 
+            if let Some(handle_opt) = update.handle {
+                if handle_opt != space.handle {
+                    Self::update_space_handle(space_id, space.handle.clone(), handle_opt.clone())?;
+                    space.handle = handle_opt;
+                    is_update_applied = true;
+                }
+            }
+
             if let Some(hidden) = update.hidden {
                 if hidden != space.hidden {
                     space.hidden = hidden;
@@ -227,6 +376,13 @@ pub mod pallet {
 
             // Update this space only if at least one field should be updated:
             if is_update_applied {
+                space.updated = Some(new_who_and_when::<T>(owner.clone()));
+
+                if let Some(previous_content) = previous_content_opt {
+                    Self::add_content_to_history(space_id, previous_content);
+                }
+
+                Self::update_space_deposit(&space)?;
                 SpaceById::<T>::insert(space_id, space);
                 Self::deposit_event(Event::SpaceUpdated { account: owner, space_id });
             }
@@ -261,10 +417,15 @@ pub mod pallet {
                 id: space_id,
                 created: new_who_and_when,
                 edited: false,
+                updated: None,
                 owner: owner.clone(),
+                parent_id: None,
+                handle: None,
                 content,
                 hidden,
                 permissions,
+                upvotes_count: 0,
+                downvotes_count: 0,
             };
 
             let add_new_space_id_by_owner = |owner: &T::AccountId, space_id: SpaceId| {
@@ -307,6 +468,147 @@ pub mod pallet {
             NextSpaceId::<T>::put(space_id);
             Ok(Pays::No.into())
         }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(< T as Config >::WeightInfo::delete_space())]
+        pub fn delete_space(origin: OriginFor<T>, space_id: SpaceId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Self::require_space(space_id)?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space.id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Self::ensure_account_can_delete_space(who.clone(), &space)?;
+
+            ensure!(Self::subspace_count_by_space_id(space_id) == 0, Error::<T>::SpaceHasSubspaces);
+
+            T::BeforeSpaceDeleted::before_space_deleted(space_id)?;
+
+            if let Some(parent_id) = space.parent_id {
+                Self::remove_subspace(parent_id, space_id);
+            }
+
+            if let Some(handle) = space.handle {
+                SpaceIdByHandle::<T>::remove(Self::lowercase_handle(handle));
+            }
+
+            SpaceById::<T>::remove(space_id);
+            ContentHistoryBySpaceId::<T>::remove(space_id);
+            SpaceIdsByOwner::<T>::mutate(&space.owner, |ids| {
+                remove_from_bounded_vec(ids, space_id)
+            });
+            T::ProfileManager::unlink_space_from_profile(&space.owner, space_id);
+            Self::refund_space_deposit(space_id);
+            SubscriptionPlanBySpaceId::<T>::remove(space_id);
+
+            Self::deposit_event(Event::SpaceDeleted { account: who, space_id });
+            Ok(())
+        }
+
+        /// Set or remove (if `plan_opt` is `None`) the subscription plan of a space.
+        /// Existing subscriptions stay active until they expire.
+        #[pallet::call_index(5)]
+        #[pallet::weight(< T as Config >::WeightInfo::set_subscription_plan())]
+        pub fn set_subscription_plan(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            plan_opt: Option<SubscriptionPlanOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Self::require_space(space_id)?;
+            space.ensure_space_owner(who.clone())?;
+
+            match plan_opt {
+                Some(plan) => {
+                    ensure!(!plan.period.is_zero(), Error::<T>::ZeroSubscriptionPeriod);
+                    SubscriptionPlanBySpaceId::<T>::insert(space_id, plan);
+                },
+                None => SubscriptionPlanBySpaceId::<T>::remove(space_id),
+            }
+
+            Self::deposit_event(Event::SubscriptionPlanUpdated { account: who, space_id });
+            Ok(())
+        }
+
+        /// Pay for one period of the subscription plan of a space. The period is added
+        /// to the end of an active subscription, if any.
+        ///
+        /// Fails if the price of the plan is higher than `max_price`, so that the space owner
+        /// can't raise the price right before the subscription is paid.
+        #[pallet::call_index(6)]
+        #[pallet::weight(< T as Config >::WeightInfo::subscribe_to_space())]
+        pub fn subscribe_to_space(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            max_price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Self::require_space(space_id)?;
+            let plan = Self::subscription_plan_by_space_id(space_id)
+                .ok_or(Error::<T>::SubscriptionPlanNotFound)?;
+            ensure!(!space.is_owner(&who), Error::<T>::CannotSubscribeToOwnSpace);
+            ensure!(plan.price <= max_price, Error::<T>::SubscriptionPriceTooHigh);
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            T::Currency::transfer(&who, &space.owner, plan.price, ExistenceRequirement::KeepAlive)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let expires_at = Self::subscription_expires_at_by_account(space_id, &who)
+                .filter(|expires_at| *expires_at > now)
+                .unwrap_or(now)
+                .saturating_add(plan.period);
+            SubscriptionExpiresAtByAccount::<T>::insert(space_id, &who, expires_at);
+
+            Self::deposit_event(Event::SpaceSubscribed { account: who, space_id, expires_at });
+            Ok(())
+        }
+
+        /// Remove the subscriptions to a deleted space. Anyone can call this dispatch.
+        ///
+        /// At most `limit` subscriptions are removed in one call. If there are more of them,
+        /// call this again with the same `space_id` to continue where the previous call stopped.
+        #[pallet::call_index(7)]
+        #[pallet::weight(< T as Config >::WeightInfo::remove_space_subscriptions(*limit))]
+        pub fn remove_space_subscriptions(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            ensure!(Self::ensure_space_exists(space_id).is_err(), Error::<T>::SpaceIsNotDeleted);
+
+            let subscribers: Vec<_> =
+                SubscriptionExpiresAtByAccount::<T>::iter_key_prefix(space_id)
+                    .take(limit as usize)
+                    .collect();
+            let removed_count = subscribers.len() as u32;
+            for subscriber in subscribers {
+                SubscriptionExpiresAtByAccount::<T>::remove(space_id, subscriber);
+            }
+
+            if SubscriptionExpiresAtByAccount::<T>::iter_key_prefix(space_id).next().is_none() {
+                Self::deposit_event(Event::SpaceSubscriptionsRemoved { space_id, removed_count });
+            } else {
+                Self::deposit_event(Event::SpaceSubscriptionsPartiallyRemoved {
+                    space_id,
+                    removed_count,
+                });
+            }
+
+            let actual_weight =
+                <T as Config>::WeightInfo::remove_space_subscriptions(removed_count);
+            Ok(Some(actual_weight).into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -318,7 +620,7 @@ pub mod pallet {
                 for id in FIRST_SPACE_ID..=RESERVED_SPACE_COUNT {
                     spaces.push((
                         id,
-                        Space::<T>::new(id, endowed_account.clone(), Content::None, None),
+                        Space::<T>::new(id, None, endowed_account.clone(), Content::None, None),
                     ));
                 }
                 spaces.iter().for_each(|(space_id, space)| {
@@ -331,20 +633,31 @@ pub mod pallet {
             owner: &T::AccountId,
             content: Content,
             permissions_opt: Option<SpacePermissions>,
+            parent_id_opt: Option<SpaceId>,
         ) -> Result<SpaceId, DispatchError> {
-            ensure_content_is_valid(content.clone())?;
+            ensure_content_is_valid::<T::MaxInlineContentLen>(content.clone())?;
             Self::ensure_space_limit_not_reached(owner)?;
 
+            if let Some(parent_id) = parent_id_opt {
+                Self::ensure_account_can_create_subspace(owner.clone(), parent_id)?;
+            }
+
             let permissions =
                 permissions_opt.map(|perms| Permissions::<T>::override_permissions(perms));
 
             let space_id = Self::next_space_id();
-            let new_space = &mut Space::new(space_id, owner.clone(), content, permissions);
+            let new_space =
+                &mut Space::new(space_id, parent_id_opt, owner.clone(), content, permissions);
+
+            Self::update_space_deposit(new_space)?;
 
             SpaceById::<T>::insert(space_id, new_space);
             SpaceIdsByOwner::<T>::mutate(owner, |ids| {
                 ids.try_push(space_id).expect("qed; too many spaces per account")
             });
+            if let Some(parent_id) = parent_id_opt {
+                Self::add_subspace(parent_id, space_id);
+            }
             NextSpaceId::<T>::mutate(|n| *n += 1);
 
             Self::deposit_event(Event::SpaceCreated { account: owner.clone(), space_id });
@@ -358,6 +671,16 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Get the details of a space by its' id.
+        ///
+        /// A space, that is not migrated to the current storage layout yet, is not returned.
+        pub fn space_by_id(space_id: SpaceId) -> Option<Space<T>> {
+            if migration::v4::is_space_pending::<T>(space_id) {
+                return None
+            }
+            SpaceById::<T>::get(space_id)
+        }
+
         /// Get `Space` by id from the storage or return `SpaceNotFound` error.
         pub fn require_space(space_id: SpaceId) -> Result<Space<T>, DispatchError> {
             Ok(Self::space_by_id(space_id).ok_or(Error::<T>::SpaceNotFound)?)
@@ -382,6 +705,134 @@ pub mod pallet {
             T::Roles::ensure_account_has_space_permission(account, ctx, permission, error)
         }
 
+        /// An account has an active subscription to a space, if its' subscription
+        /// has not expired yet.
+        pub fn is_active_subscriber(account: &T::AccountId, space_id: SpaceId) -> bool {
+            Self::subscription_expires_at_by_account(space_id, account)
+                .map_or(false, |expires_at| expires_at > frame_system::Pallet::<T>::block_number())
+        }
+
+        /// Check that an account either owns a space or is its' active subscriber,
+        /// otherwise return a given `error`.
+        pub fn ensure_account_is_active_subscriber(
+            account: &T::AccountId,
+            space: &Space<T>,
+            error: DispatchError,
+        ) -> DispatchResult {
+            ensure!(
+                space.is_owner(account) || Self::is_active_subscriber(account, space.id),
+                error
+            );
+            Ok(())
+        }
+
+        /// Check that a parent space is visible, an account is allowed to create subspaces in it,
+        /// and a new subspace would not exceed the max depth of nested subspaces.
+        fn ensure_account_can_create_subspace(
+            account: T::AccountId,
+            parent_id: SpaceId,
+        ) -> DispatchResult {
+            let parent = Self::require_space(parent_id)?;
+            ensure!(!parent.is_hidden_in_hierarchy(), Error::<T>::CannotCreateInHiddenScope);
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(account.clone(), parent_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Self::ensure_account_has_space_permission(
+                account,
+                &parent,
+                SpacePermission::CreateSubspaces,
+                Error::<T>::NoPermissionToCreateSubspaces.into(),
+            )?;
+
+            // The depth of a root space is 0, so the depth of a new subspace is 1 at least.
+            let mut depth = 1u32;
+            let mut ancestor_id_opt = parent.parent_id;
+            while let Some(ancestor_id) = ancestor_id_opt {
+                depth = depth.saturating_add(1);
+                ensure!(depth <= T::MaxSubspaceDepth::get(), Error::<T>::MaxSubspaceDepthReached);
+                ancestor_id_opt = Self::require_space(ancestor_id)?.parent_id;
+            }
+            ensure!(depth <= T::MaxSubspaceDepth::get(), Error::<T>::MaxSubspaceDepthReached);
+
+            Ok(())
+        }
+
+        /// An owner of a subspace needs the `UpdateOwnSubspaces` permission in the parent space
+        /// to update it, any other account needs the `UpdateAnySubspace` permission.
+        fn ensure_account_can_update_subspace(
+            account: T::AccountId,
+            subspace: &Space<T>,
+            parent: &Space<T>,
+        ) -> DispatchResult {
+            let permission = if subspace.is_owner(&account) {
+                SpacePermission::UpdateOwnSubspaces
+            } else {
+                SpacePermission::UpdateAnySubspace
+            };
+
+            Self::ensure_account_has_space_permission(
+                account,
+                parent,
+                permission,
+                Error::<T>::NoPermissionToUpdateSpace.into(),
+            )
+        }
+
+        /// An owner of a subspace needs the `HideOwnSubspaces` permission in the parent space
+        /// to hide or unhide it, any other account needs the `HideAnySubspace` permission.
+        fn ensure_account_can_hide_subspace(
+            account: T::AccountId,
+            subspace: &Space<T>,
+            parent: &Space<T>,
+        ) -> DispatchResult {
+            let permission = if subspace.is_owner(&account) {
+                SpacePermission::HideOwnSubspaces
+            } else {
+                SpacePermission::HideAnySubspace
+            };
+
+            Self::ensure_account_has_space_permission(
+                account,
+                parent,
+                permission,
+                Error::<T>::NoPermissionToHideSpace.into(),
+            )
+        }
+
+        /// An owner of a space needs the `DeleteOwnSubspaces` permission to delete it,
+        /// any other account needs the `DeleteAnySubspace` permission.
+        /// Permissions of a subspace are checked in its parent space.
+        fn ensure_account_can_delete_space(
+            account: T::AccountId,
+            space: &Space<T>,
+        ) -> DispatchResult {
+            let permission = if space.is_owner(&account) {
+                SpacePermission::DeleteOwnSubspaces
+            } else {
+                SpacePermission::DeleteAnySubspace
+            };
+
+            // Only the owner can delete a root space, as there is no parent space
+            // to grant `DeleteAnySubspace` permission within.
+            let scope = match space.parent_id {
+                Some(parent_id) => Self::require_space(parent_id)?,
+                None => {
+                    ensure!(space.is_owner(&account), Error::<T>::NoPermissionToDeleteSpace);
+                    space.clone()
+                },
+            };
+
+            Self::ensure_account_has_space_permission(
+                account,
+                &scope,
+                permission,
+                Error::<T>::NoPermissionToDeleteSpace.into(),
+            )
+        }
+
         pub fn mutate_space_by_id<F: FnOnce(&mut Space<T>)>(
             space_id: SpaceId,
             f: F,
@@ -398,6 +849,142 @@ pub mod pallet {
             })
         }
 
+        /// Adjust the deposit held for a given space to the encoded size of the space
+        /// and its' content history.
+        ///
+        /// The deposit stays with the account that has reserved it, no matter who edits the space.
+        /// A space that has no deposit yet (e.g. created before deposits were introduced)
+        /// gets it reserved from the space owner.
+        pub(crate) fn update_space_deposit(space: &Space<T>) -> DispatchResult {
+            let size = space
+                .encoded_size()
+                .saturating_add(Self::content_history_by_space_id(space.id).encoded_size());
+            let amount =
+                T::SpaceByteDeposit::get().saturating_mul(BalanceOf::<T>::from(size as u32));
+
+            let old_deposit_opt = Self::deposit_by_space_id(space.id);
+            let depositor = old_deposit_opt
+                .as_ref()
+                .map_or_else(|| space.owner.clone(), |deposit| deposit.depositor.clone());
+
+            let deposit =
+                update_storage_deposit::<_, T::Currency>(old_deposit_opt, depositor, amount)?;
+            DepositBySpaceId::<T>::insert(space.id, deposit);
+            Ok(())
+        }
+
+        /// Return the deposit held for a given space back to its depositor.
+        pub fn refund_space_deposit(space_id: SpaceId) {
+            if let Some(deposit) = DepositBySpaceId::<T>::take(space_id) {
+                refund_storage_deposit::<_, T::Currency>(deposit);
+            }
+        }
+
+        /// Keep the previous content of a space in its' bounded edit history.
+        /// Empty content is not kept.
+        fn add_content_to_history(space_id: SpaceId, previous_content: Content) {
+            if previous_content.is_none() {
+                return
+            }
+
+            ContentHistoryBySpaceId::<T>::mutate(space_id, |history| {
+                push_evicting_oldest(history, previous_content)
+            });
+        }
+
+        /// Lowercase a handle, so that handles are unique regardless of their case.
+        pub fn lowercase_handle(mut handle: Vec<u8>) -> Vec<u8> {
+            handle.make_ascii_lowercase();
+            handle
+        }
+
+        /// Check that a handle has a valid length and contains only ASCII letters, digits
+        /// and underscores.
+        pub fn ensure_handle_is_valid(handle: &[u8]) -> DispatchResult {
+            ensure!(
+                handle.len() >= T::MinHandleLen::get() as usize,
+                ModerationError::HandleIsTooShort
+            );
+            ensure!(
+                handle.len() <= T::MaxHandleLen::get() as usize,
+                ModerationError::HandleIsTooLong
+            );
+            ensure!(
+                handle.iter().all(|c| c.is_ascii_alphanumeric() || *c == b'_'),
+                ModerationError::HandleContainsInvalidChars
+            );
+            Ok(())
+        }
+
+        /// Find a space id by its' handle in any case.
+        pub fn find_space_id_by_handle(handle: Vec<u8>) -> Option<SpaceId> {
+            Self::space_id_by_handle(Self::lowercase_handle(handle))
+        }
+
+        /// Reserve a new handle for a given space and release its' old handle, if any.
+        fn update_space_handle(
+            space_id: SpaceId,
+            old_handle_opt: Option<Vec<u8>>,
+            new_handle_opt: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let old_key_opt = old_handle_opt.map(Self::lowercase_handle);
+
+            if let Some(new_handle) = new_handle_opt {
+                Self::ensure_handle_is_valid(&new_handle)?;
+
+                let new_key = Self::lowercase_handle(new_handle);
+                if old_key_opt.as_ref() == Some(&new_key) {
+                    // Only the case of a handle has changed, it's still reserved by this space.
+                    return Ok(())
+                }
+
+                ensure!(
+                    !SpaceIdByHandle::<T>::contains_key(&new_key),
+                    Error::<T>::SpaceHandleIsNotUnique
+                );
+                SpaceIdByHandle::<T>::insert(new_key, space_id);
+            }
+
+            if let Some(old_key) = old_key_opt {
+                SpaceIdByHandle::<T>::remove(old_key);
+            }
+            Ok(())
+        }
+
+        pub(crate) fn add_subspace(parent_id: SpaceId, subspace_id: SpaceId) {
+            if !SubspaceIdsBySpaceId::<T>::contains_key(parent_id, subspace_id) {
+                SubspaceIdsBySpaceId::<T>::insert(parent_id, subspace_id, ());
+                SubspaceCountBySpaceId::<T>::mutate(parent_id, |count| {
+                    *count = count.saturating_add(1)
+                });
+            }
+        }
+
+        pub(crate) fn remove_subspace(parent_id: SpaceId, subspace_id: SpaceId) {
+            if SubspaceIdsBySpaceId::<T>::take(parent_id, subspace_id).is_some() {
+                SubspaceCountBySpaceId::<T>::mutate(parent_id, |count| {
+                    *count = count.saturating_sub(1)
+                });
+            }
+        }
+
+        /// Get up to `limit` ids of direct subspaces of a given space, starting after
+        /// `start_after`. Ids are not returned in the order the subspaces were created.
+        pub fn get_subspace_ids_by_space_id(
+            space_id: SpaceId,
+            start_after: Option<SpaceId>,
+            limit: u32,
+        ) -> Vec<SpaceId> {
+            let iter = match start_after {
+                Some(subspace_id) => SubspaceIdsBySpaceId::<T>::iter_key_prefix_from(
+                    space_id,
+                    SubspaceIdsBySpaceId::<T>::hashed_key_for(space_id, subspace_id),
+                ),
+                None => SubspaceIdsBySpaceId::<T>::iter_key_prefix(space_id),
+            };
+            iter.take(limit as usize).collect()
+        }
+
         pub fn ensure_space_limit_not_reached(owner: &T::AccountId) -> DispatchResult {
             ensure!(
                 Self::space_ids_by_owner(&owner).len() < T::MaxSpacesPerAccount::get() as usize,
@@ -428,7 +1015,7 @@ pub mod pallet {
         }
 
         fn create_space(owner: &T::AccountId, content: Content) -> Result<SpaceId, DispatchError> {
-            Self::do_create_space(owner, content, None)
+            Self::do_create_space(owner, content, None, None)
         }
     }
 }
@@ -0,0 +1,144 @@
+use frame_support::{pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use subsocial_support::WhoAndWhenOf;
+
+use crate::{
+    types::{Space, FIRST_SPACE_ID},
+    Config, Content, Pallet, SpaceById, SpaceId, SpacePermissions,
+};
+
+/// Max number of space ids looked up by the migration in one block.
+pub const MIGRATED_SPACES_PER_BLOCK: u32 = 200;
+
+/// Adds an optional `parent_id`, an optional unique `handle`, an optional `updated` record
+/// and upvote and downvote counters to every space.
+///
+/// Each migrated space also gets a storage deposit reserved from its owner.
+///
+/// Storage versions 1 to 3 were never released, so spaces are migrated from version 0 only.
+/// Spaces are migrated in bounded steps across several blocks by `migrate_spaces`,
+/// which is called from `on_initialize`, until the storage version is set to 4.
+/// A space, that is not migrated yet, can not be found.
+pub mod v4 {
+    use super::*;
+
+    /// A space as it's stored at storage version 0.
+    #[derive(Encode, Decode)]
+    pub struct OldSpace<T: Config> {
+        pub id: SpaceId,
+        pub created: WhoAndWhenOf<T>,
+        pub edited: bool,
+        pub owner: T::AccountId,
+        pub content: Content,
+        pub hidden: bool,
+        pub permissions: Option<SpacePermissions>,
+    }
+
+    #[storage_alias]
+    pub type OldSpaceById<T: Config> = StorageMap<Pallet<T>, Twox64Concat, SpaceId, OldSpace<T>>;
+
+    /// The id of the next space to migrate and the id to stop at. Spaces created after
+    /// the migration has started have greater ids, so they're left as is.
+    #[storage_alias]
+    pub type MigrationCursor<T: Config> = StorageValue<Pallet<T>, (SpaceId, SpaceId)>;
+
+    pub struct MigrateToV4<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+            if onchain_version >= 4 || MigrationCursor::<T>::exists() {
+                return T::DbWeight::get().reads(2)
+            }
+
+            MigrationCursor::<T>::put((FIRST_SPACE_ID, Pallet::<T>::next_space_id()));
+            T::DbWeight::get().reads_writes(3, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let space_count = SpaceById::<T>::iter_keys().count() as u64;
+            Ok(space_count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let space_count: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "Cannot decode the space count")?;
+
+            // Run the remaining steps at once, to check the migrated spaces right away.
+            while MigrationCursor::<T>::exists() {
+                migrate_spaces::<T>(MIGRATED_SPACES_PER_BLOCK);
+            }
+
+            ensure!(Pallet::<T>::on_chain_storage_version() >= 4, "Storage version is not 4");
+            ensure!(
+                SpaceById::<T>::iter_values().count() as u64 == space_count,
+                "Some spaces were not migrated"
+            );
+            Ok(())
+        }
+    }
+
+    /// Whether a space with a given id is still stored in the old layout.
+    pub fn is_space_pending<T: Config>(space_id: SpaceId) -> bool {
+        MigrationCursor::<T>::get()
+            .map_or(false, |(next_id, end_id)| space_id >= next_id && space_id < end_id)
+    }
+
+    /// Migrate the spaces with up to `limit` next ids, continuing where the previous call
+    /// stopped, and set the storage version to 4 once all of them are migrated.
+    pub fn migrate_spaces<T: Config>(limit: u32) -> Weight {
+        let (mut next_id, end_id) = match MigrationCursor::<T>::get() {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+
+        let mut reads = 1u64;
+        let mut writes = 1u64;
+        let mut looked_up = 0u32;
+
+        while next_id < end_id && looked_up < limit {
+            reads += 1;
+            if let Some(old) = OldSpaceById::<T>::get(next_id) {
+                let space = Space {
+                    id: old.id,
+                    created: old.created,
+                    edited: old.edited,
+                    updated: None,
+                    owner: old.owner,
+                    parent_id: None,
+                    handle: None,
+                    content: old.content,
+                    hidden: old.hidden,
+                    permissions: old.permissions,
+                    upvotes_count: 0,
+                    downvotes_count: 0,
+                };
+
+                // An owner, that can't afford the deposit, keeps the space without it,
+                // until the deposit is reserved on the next update of the space.
+                reads += 3;
+                writes += 3;
+                let _ = Pallet::<T>::update_space_deposit(&space);
+                SpaceById::<T>::insert(next_id, space);
+            }
+            next_id = next_id.saturating_add(1);
+            looked_up += 1;
+        }
+
+        if next_id < end_id {
+            MigrationCursor::<T>::put((next_id, end_id));
+        } else {
+            writes += 1;
+            MigrationCursor::<T>::kill();
+            StorageVersion::new(4).put::<Pallet<T>>();
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
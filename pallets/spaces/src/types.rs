@@ -1,6 +1,8 @@
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::Currency};
+use sp_runtime::traits::Saturating;
+use sp_std::vec::Vec;
 
-use subsocial_support::{new_who_and_when, WhoAndWhenOf};
+use subsocial_support::{new_who_and_when, StorageDeposit, WhoAndWhenOf};
 
 use super::*;
 
@@ -9,6 +11,13 @@ pub const RESERVED_SPACE_COUNT: u64 = 1000;
 
 pub(crate) type SpacesByAccount<T> = BoundedVec<SpaceId, <T as Config>::MaxSpacesPerAccount>;
 
+pub(crate) type SpaceContentHistory<T> = BoundedVec<Content, <T as Config>::MaxSpaceContentHistory>;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type StorageDepositOf<T> = StorageDeposit<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
 /// Information about a space's owner, its' content, visibility and custom permissions.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -19,11 +28,19 @@ pub struct Space<T: Config> {
     pub created: WhoAndWhenOf<T>,
     /// True, if the content of this space was edited.
     pub edited: bool,
+    /// Who and when updated this space last time.
+    pub updated: Option<WhoAndWhenOf<T>>,
 
     /// The current owner of a given space.
     pub owner: T::AccountId,
 
+    /// An id of a parent space, if a given space is a subspace.
+    pub parent_id: Option<SpaceId>,
+
     // The next fields can be updated by the owner:
+    /// A unique, case-insensitive handle of this space, e.g. `subsocial`.
+    pub handle: Option<Vec<u8>>,
+
     pub content: Content,
 
     /// Hidden field is used to recommend to end clients (web and mobile apps) that a particular
@@ -33,11 +50,27 @@ pub struct Space<T: Config> {
     /// This allows you to override Subsocial's default permissions by enabling or disabling role
     /// permissions.
     pub permissions: Option<SpacePermissions>,
+
+    pub upvotes_count: u32,
+    pub downvotes_count: u32,
 }
 
+/// A recurring paid membership in a space: each period of subscription costs `price`,
+/// which is paid to the space owner.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SubscriptionPlan<Balance, BlockNumber> {
+    pub price: Balance,
+    /// For how many blocks a subscription is active after it's paid.
+    pub period: BlockNumber,
+}
+
+pub type SubscriptionPlanOf<T> =
+    SubscriptionPlan<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
 pub struct SpaceUpdate {
     pub content: Option<Content>,
+    pub handle: Option<Option<Vec<u8>>>,
     pub hidden: Option<bool>,
     pub permissions: Option<Option<SpacePermissions>>,
 }
@@ -45,6 +78,7 @@ pub struct SpaceUpdate {
 impl<T: Config> Space<T> {
     pub fn new(
         id: SpaceId,
+        parent_id: Option<SpaceId>,
         created_by: T::AccountId,
         content: Content,
         permissions: Option<SpacePermissions>,
@@ -53,10 +87,15 @@ impl<T: Config> Space<T> {
             id,
             created: new_who_and_when::<T>(created_by.clone()),
             edited: false,
+            updated: None,
             owner: created_by,
+            parent_id,
+            handle: None,
             content,
             hidden: false,
             permissions,
+            upvotes_count: 0,
+            downvotes_count: 0,
         }
     }
 
@@ -73,6 +112,40 @@ impl<T: Config> Space<T> {
         Ok(())
     }
 
+    /// A space is hidden if either it or any of its parent spaces is hidden.
+    pub fn is_hidden_in_hierarchy(&self) -> bool {
+        if self.hidden {
+            return true
+        }
+
+        let mut parent_id_opt = self.parent_id;
+        while let Some(parent_id) = parent_id_opt {
+            match Pallet::<T>::space_by_id(parent_id) {
+                Some(parent) if parent.hidden => return true,
+                Some(parent) => parent_id_opt = parent.parent_id,
+                None => break,
+            }
+        }
+
+        false
+    }
+
+    pub fn inc_upvotes(&mut self) {
+        self.upvotes_count.saturating_inc();
+    }
+
+    pub fn dec_upvotes(&mut self) {
+        self.upvotes_count.saturating_dec();
+    }
+
+    pub fn inc_downvotes(&mut self) {
+        self.downvotes_count.saturating_inc();
+    }
+
+    pub fn dec_downvotes(&mut self) {
+        self.downvotes_count.saturating_dec();
+    }
+
     pub fn is_public(&self) -> bool {
         !self.hidden && self.content.is_some()
     }
@@ -43,6 +43,10 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
     fn create_space() -> Weight;
     fn update_space() -> Weight;
+    fn delete_space() -> Weight;
+    fn set_subscription_plan() -> Weight;
+    fn subscribe_to_space() -> Weight;
+    fn remove_space_subscriptions(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_spaces using the Substrate node and recommended hardware.
@@ -51,21 +55,86 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             // Storage: Spaces SpaceIdsByOwner (r:1 w:1)
             // Storage: Spaces NextSpaceId (r:1 w:1)
             // Storage: Timestamp Now (r:1 w:0)
-            // Storage: Spaces SpaceById (r:0 w:1)
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Spaces SubspaceIdsBySpaceId (r:1 w:1)
+            // Storage: Spaces SubspaceCountBySpaceId (r:1 w:1)
+            // Storage: Spaces DepositBySpaceId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
         fn create_space() -> Weight {
         // Minimum execution time: 45_683 nanoseconds.
         Weight::from_ref_time(46_598_000)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(9))
+            .saturating_add(T::DbWeight::get().writes(7))
         }
-            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Spaces SpaceById (r:2 w:1)
             // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Spaces DepositBySpaceId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Spaces SpaceIdByHandle (r:1 w:2)
+            // Storage: Spaces ContentHistoryBySpaceId (r:1 w:1)
         fn update_space() -> Weight {
         // Minimum execution time: 52_466 nanoseconds.
         Weight::from_ref_time(53_333_000)
-            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(6))
+        }
+            // Storage: Spaces SpaceById (r:2 w:1)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Spaces SubspaceIdsBySpaceId (r:1 w:1)
+            // Storage: Spaces SubspaceCountBySpaceId (r:2 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowers (r:0 w:1)
+            // Storage: SpaceOwnership PendingSpaceOwner (r:0 w:1)
+            // Storage: Spaces SpaceIdsByOwner (r:1 w:1)
+            // Storage: Profiles ProfileSpaceIdByAccount (r:1 w:1)
+            // Storage: Spaces DepositBySpaceId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Spaces SpaceIdByHandle (r:0 w:1)
+            // Storage: Spaces ContentHistoryBySpaceId (r:0 w:1)
+            // Storage: Spaces SubscriptionPlanBySpaceId (r:0 w:1)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:0 w:1)
+            // Storage: Roles RoleIdsBySpaceId (r:1 w:1)
+            // Storage: Roles UsersByRoleId (r:40 w:40)
+            // Storage: Roles RoleById (r:40 w:40)
+            // Storage: Roles RoleIdsByUserInSpace (r:40 w:40)
+            // Storage: Roles DepositByRoleId (r:40 w:40)
+            // Storage: System Account (r:40 w:40)
+        fn delete_space() -> Weight {
+        // Minimum execution time: 61_204 nanoseconds.
+        Weight::from_ref_time(62_127_000)
+            .saturating_add(T::DbWeight::get().reads(212))
+            .saturating_add(T::DbWeight::get().writes(214))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Spaces SubscriptionPlanBySpaceId (r:0 w:1)
+        fn set_subscription_plan() -> Weight {
+        // Minimum execution time: 24_817 nanoseconds.
+        Weight::from_ref_time(25_402_000)
+            .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
         }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Spaces SubscriptionPlanBySpaceId (r:1 w:0)
+            // Storage: System Account (r:2 w:2)
+            // Storage: Spaces SubscriptionExpiresAtByAccount (r:1 w:1)
+        fn subscribe_to_space() -> Weight {
+        // Minimum execution time: 58_146 nanoseconds.
+        Weight::from_ref_time(59_230_000)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(3))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Spaces SubscriptionExpiresAtByAccount (r:2 w:1)
+            /// The range of component `n` is `[0, 100]`.
+        fn remove_space_subscriptions(n: u32, ) -> Weight {
+        // Minimum execution time: 11_000 nanoseconds.
+        Weight::from_ref_time(12_873_000)
+            // Standard Error: 3_000
+            .saturating_add(Weight::from_ref_time(3_146_000).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+        }
     }
 
     // For backwards compatibility and tests
@@ -73,19 +142,84 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             // Storage: Spaces SpaceIdsByOwner (r:1 w:1)
             // Storage: Spaces NextSpaceId (r:1 w:1)
             // Storage: Timestamp Now (r:1 w:0)
-            // Storage: Spaces SpaceById (r:0 w:1)
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Spaces SubspaceIdsBySpaceId (r:1 w:1)
+            // Storage: Spaces SubspaceCountBySpaceId (r:1 w:1)
+            // Storage: Spaces DepositBySpaceId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
         fn create_space() -> Weight {
         // Minimum execution time: 45_683 nanoseconds.
         Weight::from_ref_time(46_598_000)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().reads(9))
+            .saturating_add(RocksDbWeight::get().writes(7))
         }
-            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Spaces SpaceById (r:2 w:1)
             // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Spaces DepositBySpaceId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Spaces SpaceIdByHandle (r:1 w:2)
+            // Storage: Spaces ContentHistoryBySpaceId (r:1 w:1)
         fn update_space() -> Weight {
         // Minimum execution time: 52_466 nanoseconds.
         Weight::from_ref_time(53_333_000)
-            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(6))
+        }
+            // Storage: Spaces SpaceById (r:2 w:1)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Spaces SubspaceIdsBySpaceId (r:1 w:1)
+            // Storage: Spaces SubspaceCountBySpaceId (r:2 w:1)
+            // Storage: Posts PostCountBySpaceId (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowers (r:0 w:1)
+            // Storage: SpaceOwnership PendingSpaceOwner (r:0 w:1)
+            // Storage: Spaces SpaceIdsByOwner (r:1 w:1)
+            // Storage: Profiles ProfileSpaceIdByAccount (r:1 w:1)
+            // Storage: Spaces DepositBySpaceId (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: Spaces SpaceIdByHandle (r:0 w:1)
+            // Storage: Spaces ContentHistoryBySpaceId (r:0 w:1)
+            // Storage: Spaces SubscriptionPlanBySpaceId (r:0 w:1)
+            // Storage: Reactions CustomReactionKindsBySpaceId (r:0 w:1)
+            // Storage: Roles RoleIdsBySpaceId (r:1 w:1)
+            // Storage: Roles UsersByRoleId (r:40 w:40)
+            // Storage: Roles RoleById (r:40 w:40)
+            // Storage: Roles RoleIdsByUserInSpace (r:40 w:40)
+            // Storage: Roles DepositByRoleId (r:40 w:40)
+            // Storage: System Account (r:40 w:40)
+        fn delete_space() -> Weight {
+        // Minimum execution time: 61_204 nanoseconds.
+        Weight::from_ref_time(62_127_000)
+            .saturating_add(RocksDbWeight::get().reads(212))
+            .saturating_add(RocksDbWeight::get().writes(214))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Spaces SubscriptionPlanBySpaceId (r:0 w:1)
+        fn set_subscription_plan() -> Weight {
+        // Minimum execution time: 24_817 nanoseconds.
+        Weight::from_ref_time(25_402_000)
+            .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
         }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Spaces SubscriptionPlanBySpaceId (r:1 w:0)
+            // Storage: System Account (r:2 w:2)
+            // Storage: Spaces SubscriptionExpiresAtByAccount (r:1 w:1)
+        fn subscribe_to_space() -> Weight {
+        // Minimum execution time: 58_146 nanoseconds.
+        Weight::from_ref_time(59_230_000)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Spaces SubscriptionExpiresAtByAccount (r:2 w:1)
+            /// The range of component `n` is `[0, 100]`.
+        fn remove_space_subscriptions(n: u32, ) -> Weight {
+        // Minimum execution time: 11_000 nanoseconds.
+        Weight::from_ref_time(12_873_000)
+            // Standard Error: 3_000
+            .saturating_add(Weight::from_ref_time(3_146_000).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+        }
     }
@@ -1,4 +1,6 @@
 #[cfg(test)]
+mod migration_tests;
+#[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,131 @@
+use codec::Encode;
+use frame_support::{
+    assert_ok,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+
+use pallet_spaces::{
+    migration::v4::{migrate_spaces, MigrateToV4, MigrationCursor, OldSpace, OldSpaceById},
+    NextSpaceId, SpaceById,
+};
+use subsocial_support::{new_who_and_when, SpaceId};
+
+use crate::{mock::*, tests_utils::*};
+
+const OLD_SPACE1: SpaceId = 1;
+const OLD_SPACE3: SpaceId = 3;
+
+fn insert_old_space(space_id: SpaceId) {
+    let old_space = OldSpace::<Test> {
+        id: space_id,
+        created: new_who_and_when::<Test>(ACCOUNT1),
+        edited: false,
+        owner: ACCOUNT1,
+        content: space_content_ipfs(),
+        hidden: false,
+        permissions: None,
+    };
+    OldSpaceById::<Test>::insert(space_id, old_space);
+}
+
+fn start_spaces_migration(next_space_id: SpaceId) {
+    NextSpaceId::<Test>::put(next_space_id);
+    StorageVersion::new(0).put::<Spaces>();
+    MigrateToV4::<Test>::on_runtime_upgrade();
+}
+
+#[test]
+fn migrate_spaces_should_work_in_bounded_steps() {
+    ExtBuilder::build().execute_with(|| {
+        insert_old_space(OLD_SPACE1);
+        insert_old_space(OLD_SPACE3);
+        start_spaces_migration(OLD_SPACE3 + 1);
+
+        assert_eq!(MigrationCursor::<Test>::get(), Some((OLD_SPACE1, OLD_SPACE3 + 1)));
+        assert!(Spaces::space_by_id(OLD_SPACE1).is_none());
+
+        migrate_spaces::<Test>(2);
+
+        assert_eq!(MigrationCursor::<Test>::get(), Some((OLD_SPACE3, OLD_SPACE3 + 1)));
+        assert_eq!(Spaces::on_chain_storage_version(), 0);
+
+        let space = Spaces::space_by_id(OLD_SPACE1).unwrap();
+        assert_eq!(space.owner, ACCOUNT1);
+        assert_eq!(space.content, space_content_ipfs());
+        assert_eq!(space.parent_id, None);
+        assert_eq!(space.handle, None);
+        assert_eq!(space.updated, None);
+        assert_eq!(space.upvotes_count, 0);
+        assert!(Spaces::space_by_id(OLD_SPACE3).is_none());
+
+        migrate_spaces::<Test>(2);
+
+        assert!(MigrationCursor::<Test>::get().is_none());
+        assert_eq!(Spaces::on_chain_storage_version(), 4);
+        assert_eq!(Spaces::space_by_id(OLD_SPACE3).unwrap().id, OLD_SPACE3);
+        assert!(Spaces::space_by_id(OLD_SPACE1 + 1).is_none());
+    });
+}
+
+#[test]
+fn migrate_spaces_should_reserve_deposits() {
+    ExtBuilder::build().execute_with(|| {
+        SpaceByteDeposit::set(1);
+        insert_old_space(OLD_SPACE1);
+        start_spaces_migration(OLD_SPACE1 + 1);
+
+        migrate_spaces::<Test>(2);
+
+        let space = Spaces::space_by_id(OLD_SPACE1).unwrap();
+        let expected_deposit = (space.encoded_size() +
+            Spaces::content_history_by_space_id(OLD_SPACE1).encoded_size())
+            as Balance;
+        let deposit = Spaces::deposit_by_space_id(OLD_SPACE1).unwrap();
+        assert_eq!(deposit.depositor, ACCOUNT1);
+        assert_eq!(deposit.amount, expected_deposit);
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), expected_deposit);
+    });
+}
+
+#[test]
+fn migrate_spaces_should_keep_space_without_deposit_when_owner_cannot_afford_it() {
+    ExtBuilder::build().execute_with(|| {
+        SpaceByteDeposit::set(1000);
+        insert_old_space(OLD_SPACE1);
+        start_spaces_migration(OLD_SPACE1 + 1);
+
+        migrate_spaces::<Test>(2);
+
+        assert!(Spaces::space_by_id(OLD_SPACE1).is_some());
+        assert!(Spaces::deposit_by_space_id(OLD_SPACE1).is_none());
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+    });
+}
+
+#[test]
+fn migrate_spaces_should_not_touch_spaces_created_after_start() {
+    ExtBuilder::build().execute_with(|| {
+        insert_old_space(OLD_SPACE1);
+        start_spaces_migration(SPACE1);
+
+        assert_ok!(_create_default_space()); // SpaceId 1001
+        let new_space = SpaceById::<Test>::get(SPACE1).unwrap();
+
+        migrate_spaces::<Test>(1000);
+
+        assert!(MigrationCursor::<Test>::get().is_none());
+        assert_eq!(Spaces::space_by_id(OLD_SPACE1).unwrap().id, OLD_SPACE1);
+        assert_eq!(Spaces::space_by_id(SPACE1), Some(new_space));
+    });
+}
+
+#[test]
+fn migrate_to_v4_should_not_start_when_already_migrated() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        StorageVersion::new(4).put::<Spaces>();
+        MigrateToV4::<Test>::on_runtime_upgrade();
+
+        assert!(MigrationCursor::<Test>::get().is_none());
+        assert!(Spaces::space_by_id(SPACE1).is_some());
+    });
+}
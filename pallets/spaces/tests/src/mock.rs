@@ -1,4 +1,8 @@
-use frame_support::{pallet_prelude::ConstU32, parameter_types, traits::Everything};
+use frame_support::{
+    pallet_prelude::ConstU32,
+    parameter_types,
+    traits::{ConstU64, Everything},
+};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -100,6 +104,12 @@ impl pallet_posts::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxCommentDepth = MaxCommentDepth;
     type IsPostBlocked = MockModeration;
+    type BeforePostDeleted = ();
+    type MaxPostContentHistory = ConstU32<10>;
+    type MaxPinnedPostsPerSpace = ConstU32<10>;
+    type MaxScheduledPostsPerBlock = ConstU32<100>;
+    type MaxPollOptions = ConstU32<10>;
+    type PostByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -118,6 +128,9 @@ impl pallet_roles::Config for Test {
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = MockModeration;
     type IsContentBlocked = MockModeration;
+    type MaxInlineContentLen = ConstU32<512>;
+    type Currency = Balances;
+    type RoleByteDeposit = ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -128,13 +141,27 @@ impl pallet_profiles::Config for Test {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub static SpaceByteDeposit: u64 = 0;
+}
+
 impl pallet_spaces::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Roles = Roles;
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = MockModeration;
     type IsContentBlocked = MockModeration;
+    type IsSpaceBlocked = MockModeration;
+    type MaxInlineContentLen = ConstU32<512>;
+    type ProfileManager = Profiles;
+    type BeforeSpaceDeleted = (Posts, SpaceFollows);
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<2>;
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type MaxSpaceContentHistory = ConstU32<2>;
+    type Currency = Balances;
+    type SpaceByteDeposit = SpaceByteDeposit;
     type WeightInfo = ();
 }
 
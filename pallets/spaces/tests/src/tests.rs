@@ -1,8 +1,8 @@
 use frame_support::{assert_noop, assert_ok};
 
 use pallet_permissions::SpacePermission as SP;
-use pallet_spaces::Error as SpacesError;
-use subsocial_support::{mock_functions::*, ContentError, ModerationError};
+use pallet_spaces::{types::SubscriptionPlan, Error as SpacesError};
+use subsocial_support::{mock_functions::*, ContentError, ModerationError, User};
 
 use crate::{mock::*, tests_utils::*};
 
@@ -190,6 +190,758 @@ fn update_space_should_fail_when_no_right_permission_in_account_roles() {
     );
 }
 
+#[test]
+fn create_space_should_reserve_storage_deposit() {
+    ExtBuilder::build().execute_with(|| {
+        SpaceByteDeposit::set(1);
+
+        assert_ok!(_create_default_space()); // SpaceId 1
+
+        let expected_deposit = expected_space_deposit(SPACE1);
+        assert!(expected_deposit > 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), expected_deposit);
+
+        let deposit = Spaces::deposit_by_space_id(SPACE1).unwrap();
+        assert_eq!(deposit.depositor, ACCOUNT1);
+        assert_eq!(deposit.amount, expected_deposit);
+    });
+}
+
+#[test]
+fn create_space_should_fail_when_balance_is_too_low_for_deposit() {
+    ExtBuilder::build().execute_with(|| {
+        SpaceByteDeposit::set(100);
+
+        assert_noop!(_create_default_space(), pallet_balances::Error::<Test>::InsufficientBalance);
+    });
+}
+
+#[test]
+fn update_space_should_adjust_storage_deposit() {
+    ExtBuilder::build().execute_with(|| {
+        SpaceByteDeposit::set(1);
+        assert_ok!(_create_default_space()); // SpaceId 1
+        let initial_deposit = expected_space_deposit(SPACE1);
+
+        // Replace CIDv1 with a shorter CIDv0. The encoded space gets smaller,
+        // but the previous content is kept in its' history, which is paid for too.
+        assert_ok!(_update_space(
+            None,
+            None,
+            Some(update_for_space_content(updated_space_content()))
+        ));
+
+        let new_deposit = expected_space_deposit(SPACE1);
+        assert!(new_deposit > initial_deposit);
+        assert!(!Spaces::content_history_by_space_id(SPACE1).is_empty());
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), new_deposit);
+        assert_eq!(Spaces::deposit_by_space_id(SPACE1).unwrap().amount, new_deposit);
+    });
+}
+
+#[test]
+fn update_space_should_keep_storage_deposit_with_depositor() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateSpace]).execute_with(
+        || {
+            SpaceByteDeposit::set(1);
+
+            assert_ok!(_update_space(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                Some(SPACE1),
+                Some(update_for_space_content(updated_space_content()))
+            ));
+
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), expected_space_deposit(SPACE1));
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+            assert_eq!(Spaces::deposit_by_space_id(SPACE1).unwrap().depositor, ACCOUNT1);
+        },
+    );
+}
+
+#[test]
+fn update_space_should_reserve_missing_storage_deposit_from_owner() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateSpace]).execute_with(
+        || {
+            // A space created before storage deposits were introduced.
+            pallet_spaces::DepositBySpaceId::<Test>::remove(SPACE1);
+            SpaceByteDeposit::set(1);
+
+            assert_ok!(_update_space(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                Some(SPACE1),
+                Some(update_for_space_content(updated_space_content()))
+            ));
+
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), expected_space_deposit(SPACE1));
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+            assert_eq!(Spaces::deposit_by_space_id(SPACE1).unwrap().depositor, ACCOUNT1);
+        },
+    );
+}
+
+#[test]
+fn update_space_should_work_with_handle() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_space(None, None, Some(update_for_space_handle(Some(space_handle1())))));
+
+        let space = Spaces::space_by_id(SPACE1).unwrap();
+        assert_eq!(space.handle, Some(space_handle1()));
+        assert_eq!(Spaces::space_id_by_handle(b"space_handle".to_vec()), Some(SPACE1));
+        assert_eq!(Spaces::find_space_id_by_handle(b"SPACE_HANDLE".to_vec()), Some(SPACE1));
+    });
+}
+
+#[test]
+fn update_space_should_work_when_only_handle_case_changed() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_space(None, None, Some(update_for_space_handle(Some(space_handle1())))));
+        assert_ok!(_update_space(
+            None,
+            None,
+            Some(update_for_space_handle(Some(b"space_handle".to_vec())))
+        ));
+
+        let space = Spaces::space_by_id(SPACE1).unwrap();
+        assert_eq!(space.handle, Some(b"space_handle".to_vec()));
+        assert_eq!(Spaces::find_space_id_by_handle(space_handle1()), Some(SPACE1));
+    });
+}
+
+#[test]
+fn update_space_should_release_old_handle() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_space(None, None, Some(update_for_space_handle(Some(space_handle1())))));
+        assert_ok!(_update_space(
+            None,
+            None,
+            Some(update_for_space_handle(Some(b"new_handle".to_vec())))
+        ));
+
+        assert!(Spaces::find_space_id_by_handle(space_handle1()).is_none());
+        assert_eq!(Spaces::find_space_id_by_handle(b"new_handle".to_vec()), Some(SPACE1));
+
+        assert_ok!(_update_space(None, None, Some(update_for_space_handle(None))));
+
+        assert!(Spaces::space_by_id(SPACE1).unwrap().handle.is_none());
+        assert!(Spaces::find_space_id_by_handle(b"new_handle".to_vec()).is_none());
+    });
+}
+
+#[test]
+fn update_space_should_work_with_handle_released_by_another_space() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space()); // SpaceId 2
+
+        assert_ok!(_update_space(None, None, Some(update_for_space_handle(Some(space_handle1())))));
+        assert_ok!(_update_space(None, None, Some(update_for_space_handle(None))));
+        assert_ok!(_update_space(
+            None,
+            Some(SPACE2),
+            Some(update_for_space_handle(Some(space_handle1())))
+        ));
+
+        assert_eq!(Spaces::find_space_id_by_handle(space_handle1()), Some(SPACE2));
+    });
+}
+
+#[test]
+fn update_space_should_fail_when_handle_is_not_unique() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space()); // SpaceId 2
+        assert_ok!(_update_space(None, None, Some(update_for_space_handle(Some(space_handle1())))));
+
+        // Handles are case-insensitive.
+        assert_noop!(
+            _update_space(
+                None,
+                Some(SPACE2),
+                Some(update_for_space_handle(Some(b"SPACE_handle".to_vec())))
+            ),
+            SpacesError::<Test>::SpaceHandleIsNotUnique
+        );
+    });
+}
+
+#[test]
+fn update_space_should_fail_when_handle_is_invalid() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let update_handle = |handle: &[u8]| {
+            _update_space(None, None, Some(update_for_space_handle(Some(handle.to_vec()))))
+        };
+
+        assert_noop!(update_handle(b"abcd"), ModerationError::HandleIsTooShort);
+        assert_noop!(update_handle(&[b'a'; 51]), ModerationError::HandleIsTooLong);
+        assert_noop!(update_handle(b"space-handle"), ModerationError::HandleContainsInvalidChars);
+        assert_noop!(update_handle(b"space handle"), ModerationError::HandleContainsInvalidChars);
+    });
+}
+
+#[test]
+fn update_space_should_record_who_updated_it_and_previous_content() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateSpace]).execute_with(
+        || {
+            assert_ok!(_update_space(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                Some(SPACE1),
+                Some(update_for_space_content(updated_space_content()))
+            ));
+
+            let space = Spaces::space_by_id(SPACE1).unwrap();
+            assert_eq!(space.updated.unwrap().account, ACCOUNT2);
+            assert_eq!(
+                Spaces::content_history_by_space_id(SPACE1).into_inner(),
+                vec![space_content_ipfs()]
+            );
+        },
+    );
+}
+
+#[test]
+fn update_space_should_evict_oldest_content_when_history_is_full() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        // MaxSpaceContentHistory is 2 in the mock.
+        assert_ok!(_update_space(None, None, Some(update_for_space_content(valid_content_ipfs()))));
+        assert_ok!(_update_space(
+            None,
+            None,
+            Some(update_for_space_content(another_valid_content_ipfs()))
+        ));
+        assert_ok!(_update_space(
+            None,
+            None,
+            Some(update_for_space_content(updated_space_content()))
+        ));
+
+        assert_eq!(
+            Spaces::content_history_by_space_id(SPACE1).into_inner(),
+            vec![valid_content_ipfs(), another_valid_content_ipfs()]
+        );
+    });
+}
+
+#[test]
+fn update_space_should_not_record_history_when_only_visibility_changed() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_space(None, None, Some(space_update(None, Some(true)))));
+
+        assert!(Spaces::space_by_id(SPACE1).unwrap().updated.is_some());
+        assert!(Spaces::content_history_by_space_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn delete_space_should_work() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateSpace]).execute_with(
+        || {
+            assert_ok!(_default_follow_space());
+            assert_ok!(Profiles::set_profile(RuntimeOrigin::signed(ACCOUNT1), SPACE1));
+
+            // Make the space owner hold a deposit for it.
+            SpaceByteDeposit::set(1);
+            assert_ok!(_update_space(None, None, Some(space_update(None, Some(true)))));
+            assert!(Balances::reserved_balance(ACCOUNT1) > 0);
+
+            assert_ok!(_delete_default_space());
+
+            assert!(Spaces::space_by_id(SPACE1).is_none());
+            assert!(Spaces::space_ids_by_owner(ACCOUNT1).is_empty());
+            assert!(Spaces::deposit_by_space_id(SPACE1).is_none());
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+            assert!(Profiles::profile_space_id_by_account(ACCOUNT1).is_none());
+
+            assert!(SpaceFollows::space_followers(SPACE1).is_empty());
+
+            System::assert_last_event(
+                pallet_spaces::Event::SpaceDeleted { account: ACCOUNT1, space_id: SPACE1 }.into(),
+            );
+        },
+    );
+}
+
+#[test]
+fn delete_space_should_let_followers_unfollow_it() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_default_follow_space());
+        assert_ok!(_delete_default_space());
+
+        assert!(SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)));
+        assert_ok!(SpaceFollows::unfollow_space(RuntimeOrigin::signed(ACCOUNT2), SPACE1));
+
+        assert!(!SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)));
+        assert!(SpaceFollows::spaces_followed_by_account(ACCOUNT2).is_empty());
+    });
+}
+
+#[test]
+fn delete_space_should_let_anyone_delete_its_roles() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateSpace]).execute_with(
+        || {
+            assert_ok!(_delete_default_space());
+            assert!(Roles::role_by_id(ROLE1).is_some());
+
+            assert_ok!(_delete_role(Some(RuntimeOrigin::signed(ACCOUNT3)), Some(ROLE1)));
+
+            assert!(Roles::role_by_id(ROLE1).is_none());
+            assert!(Roles::users_by_role_id(ROLE1).is_empty());
+            assert_eq!(Roles::role_ids_by_space_id(SPACE1), vec![ROLE2]);
+            assert_eq!(
+                Roles::role_ids_by_user_in_space(User::Account(ACCOUNT2), SPACE1),
+                vec![ROLE2]
+            );
+        },
+    );
+}
+
+#[test]
+fn delete_space_should_release_its_handle() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_space(None, None, Some(update_for_space_handle(Some(space_handle1())))));
+        assert_ok!(_delete_default_space());
+
+        assert!(Spaces::find_space_id_by_handle(space_handle1()).is_none());
+    });
+}
+
+#[test]
+fn delete_space_should_remove_its_content_history() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_space(
+            None,
+            None,
+            Some(update_for_space_content(updated_space_content()))
+        ));
+        assert_ok!(_delete_default_space());
+
+        assert!(Spaces::content_history_by_space_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn delete_subspace_should_work_when_one_of_roles_is_permitted() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::DeleteAnySubspace])
+        .execute_with(|| {
+            assert_ok!(_create_subspace(None, None)); // SpaceId 2
+            assert_ok!(_delete_space(Some(RuntimeOrigin::signed(ACCOUNT2)), Some(SPACE2)));
+            assert!(Spaces::space_by_id(SPACE2).is_none());
+        });
+}
+
+#[test]
+fn delete_space_should_fail_when_root_space_is_deleted_by_role_holder() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::DeleteAnySubspace])
+        .execute_with(|| {
+            assert_noop!(
+                _delete_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+                SpacesError::<Test>::NoPermissionToDeleteSpace
+            );
+        });
+}
+
+#[test]
+fn delete_space_should_fail_when_space_not_found() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_delete_default_space(), SpacesError::<Test>::SpaceNotFound);
+    });
+}
+
+#[test]
+fn delete_space_should_fail_when_account_has_no_permission_to_delete_space() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _delete_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            SpacesError::<Test>::NoPermissionToDeleteSpace
+        );
+    });
+}
+
+#[test]
+fn delete_space_should_fail_when_account_is_blocked() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        block_account_in_space_1();
+        assert_noop!(_delete_default_space(), ModerationError::AccountIsBlocked);
+    });
+}
+
+#[test]
+fn delete_space_should_fail_when_space_has_posts() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_post());
+        assert_noop!(_delete_default_space(), pallet_posts::Error::<Test>::SpaceHasPosts);
+    });
+}
+
+#[test]
+fn delete_space_should_fail_when_space_has_scheduled_posts() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(Posts::create_post(
+            RuntimeOrigin::signed(ACCOUNT1),
+            Some(SPACE1),
+            extension_regular_post(),
+            post_content_ipfs(),
+            Some(3),
+        ));
+        assert_noop!(_delete_default_space(), pallet_posts::Error::<Test>::SpaceHasPosts);
+    });
+}
+
+#[test]
+fn create_subspace_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_subspace(None, None)); // SpaceId 2
+
+        let subspace = Spaces::space_by_id(SPACE2).unwrap();
+        assert_eq!(subspace.parent_id, Some(SPACE1));
+        assert_eq!(Spaces::get_subspace_ids_by_space_id(SPACE1, None, 10), vec![SPACE2]);
+        assert_eq!(Spaces::subspace_count_by_space_id(SPACE1), 1);
+        assert!(Spaces::space_by_id(SPACE1).unwrap().parent_id.is_none());
+    });
+}
+
+#[test]
+fn get_subspace_ids_by_space_id_should_page_through_subspaces() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        for _ in 0..3 {
+            assert_ok!(_create_subspace(None, None));
+        }
+
+        let first_page = Spaces::get_subspace_ids_by_space_id(SPACE1, None, 2);
+        assert_eq!(first_page.len(), 2);
+
+        let second_page =
+            Spaces::get_subspace_ids_by_space_id(SPACE1, first_page.last().copied(), 2);
+        assert_eq!(second_page.len(), 1);
+
+        let mut subspace_ids = [first_page, second_page].concat();
+        subspace_ids.sort();
+        assert_eq!(subspace_ids, vec![SPACE2, SPACE2 + 1, SPACE2 + 2]);
+    });
+}
+
+#[test]
+fn create_subspace_should_work_when_one_of_roles_is_permitted() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreateSubspaces])
+        .execute_with(|| {
+            assert_ok!(_create_subspace(Some(RuntimeOrigin::signed(ACCOUNT2)), None));
+
+            let subspace = Spaces::space_by_id(SPACE2).unwrap();
+            assert_eq!(subspace.owner, ACCOUNT2);
+            assert_eq!(subspace.parent_id, Some(SPACE1));
+        });
+}
+
+#[test]
+fn create_subspace_should_fail_when_parent_space_not_found() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_create_subspace(None, None), SpacesError::<Test>::SpaceNotFound);
+    });
+}
+
+#[test]
+fn create_subspace_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _create_subspace(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            SpacesError::<Test>::NoPermissionToCreateSubspaces
+        );
+    });
+}
+
+#[test]
+fn create_subspace_should_fail_when_account_is_blocked() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        block_account_in_space_1();
+        assert_noop!(_create_subspace(None, None), ModerationError::AccountIsBlocked);
+    });
+}
+
+#[test]
+fn create_subspace_should_fail_when_parent_space_is_hidden() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_space(None, None, Some(space_update(None, Some(true)))));
+        assert_noop!(_create_subspace(None, None), SpacesError::<Test>::CannotCreateInHiddenScope);
+    });
+}
+
+#[test]
+fn create_subspace_should_fail_when_max_depth_reached() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        // MaxSubspaceDepth is 2 in the mock runtime.
+        assert_ok!(_create_subspace(None, Some(SPACE1))); // SpaceId 2
+        assert_ok!(_create_subspace(None, Some(SPACE2))); // SpaceId 3
+
+        assert_noop!(
+            _create_subspace(None, Some(SPACE2 + 1)),
+            SpacesError::<Test>::MaxSubspaceDepthReached
+        );
+    });
+}
+
+#[test]
+fn hidden_parent_space_should_hide_its_subspaces() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_subspace(None, None)); // SpaceId 2
+        assert_ok!(_update_space(None, None, Some(space_update(None, Some(true)))));
+
+        let subspace = Spaces::space_by_id(SPACE2).unwrap();
+        assert!(!subspace.hidden);
+        assert!(subspace.is_hidden_in_hierarchy());
+
+        assert_noop!(
+            _follow_space(None, Some(SPACE2)),
+            pallet_space_follows::Error::<Test>::CannotFollowHiddenSpace
+        );
+        assert_noop!(
+            _create_post(None, Some(Some(SPACE2)), None, None),
+            pallet_posts::Error::<Test>::CannotCreateInHiddenScope
+        );
+    });
+}
+
+#[test]
+fn delete_space_should_fail_when_space_has_subspaces() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_subspace(None, None));
+        assert_noop!(_delete_default_space(), SpacesError::<Test>::SpaceHasSubspaces);
+    });
+}
+
+#[test]
+fn delete_subspace_should_remove_it_from_parent_space() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_subspace(None, None));
+        assert_ok!(_delete_space(None, Some(SPACE2)));
+
+        assert!(Spaces::space_by_id(SPACE2).is_none());
+        assert!(Spaces::get_subspace_ids_by_space_id(SPACE1, None, 10).is_empty());
+        assert_eq!(Spaces::subspace_count_by_space_id(SPACE1), 0);
+        assert_ok!(_delete_default_space());
+    });
+}
+
+#[test]
+fn update_subspace_should_work_when_one_of_roles_is_permitted() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateAnySubspace])
+        .execute_with(|| {
+            assert_ok!(_create_subspace(None, None)); // SpaceId 2
+            assert_ok!(_update_space(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                Some(SPACE2),
+                Some(update_for_space_content(updated_space_content()))
+            ));
+
+            assert_eq!(Spaces::space_by_id(SPACE2).unwrap().content, updated_space_content());
+        });
+}
+
+#[test]
+fn update_subspace_should_fail_when_account_has_no_permission_in_parent_space() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateSpace])
+        .execute_with(|| {
+            assert_ok!(_create_subspace(None, None)); // SpaceId 2
+            assert_noop!(
+                _update_space(
+                    Some(RuntimeOrigin::signed(ACCOUNT2)),
+                    Some(SPACE2),
+                    Some(update_for_space_content(updated_space_content()))
+                ),
+                SpacesError::<Test>::NoPermissionToUpdateSpace
+            );
+        });
+}
+
+#[test]
+fn hide_subspace_should_work_when_one_of_roles_is_permitted() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::HideAnySubspace])
+        .execute_with(|| {
+            assert_ok!(_create_subspace(None, None)); // SpaceId 2
+            assert_ok!(_update_space(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                Some(SPACE2),
+                Some(space_update(None, Some(true)))
+            ));
+            assert!(Spaces::space_by_id(SPACE2).unwrap().hidden);
+
+            // Hiding a subspace does not let an account update it.
+            assert_noop!(
+                _update_space(
+                    Some(RuntimeOrigin::signed(ACCOUNT2)),
+                    Some(SPACE2),
+                    Some(update_for_space_content(updated_space_content()))
+                ),
+                SpacesError::<Test>::NoPermissionToUpdateSpace
+            );
+        });
+}
+
+#[test]
+fn hide_subspace_should_fail_when_account_has_no_permission_in_parent_space() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateAnySubspace])
+        .execute_with(|| {
+            assert_ok!(_create_subspace(None, None)); // SpaceId 2
+            assert_noop!(
+                _update_space(
+                    Some(RuntimeOrigin::signed(ACCOUNT2)),
+                    Some(SPACE2),
+                    Some(space_update(None, Some(true)))
+                ),
+                SpacesError::<Test>::NoPermissionToHideSpace
+            );
+        });
+}
+
+#[test]
+fn set_subscription_plan_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_subscription_plan());
+        assert_eq!(Spaces::subscription_plan_by_space_id(SPACE1), Some(subscription_plan()));
+
+        assert_ok!(_set_subscription_plan(None, None, None));
+        assert!(Spaces::subscription_plan_by_space_id(SPACE1).is_none());
+    });
+}
+
+#[test]
+fn set_subscription_plan_should_fail_when_account_is_not_space_owner() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _set_subscription_plan(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                Some(subscription_plan())
+            ),
+            SpacesError::<Test>::NotASpaceOwner
+        );
+    });
+}
+
+#[test]
+fn set_subscription_plan_should_fail_when_period_is_zero() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let plan = SubscriptionPlan { period: 0, ..subscription_plan() };
+        assert_noop!(
+            _set_subscription_plan(None, None, Some(plan)),
+            SpacesError::<Test>::ZeroSubscriptionPeriod
+        );
+    });
+}
+
+#[test]
+fn subscribe_to_space_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_subscription_plan());
+        let owner_balance = Balances::free_balance(ACCOUNT1);
+
+        assert_ok!(_subscribe_to_default_space());
+
+        assert_eq!(Spaces::subscription_expires_at_by_account(SPACE1, ACCOUNT2), Some(11));
+        assert!(Spaces::is_active_subscriber(&ACCOUNT2, SPACE1));
+        assert_eq!(Balances::free_balance(ACCOUNT1), owner_balance + 50);
+        assert_eq!(Balances::free_balance(ACCOUNT2), 950);
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceSubscribed {
+                account: ACCOUNT2,
+                space_id: SPACE1,
+                expires_at: 11,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn subscribe_to_space_should_extend_active_subscription() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_subscription_plan());
+        assert_ok!(_subscribe_to_default_space());
+
+        System::set_block_number(5);
+        assert_ok!(_subscribe_to_default_space());
+        assert_eq!(Spaces::subscription_expires_at_by_account(SPACE1, ACCOUNT2), Some(21));
+
+        // An expired subscription starts over from the current block.
+        System::set_block_number(21);
+        assert!(!Spaces::is_active_subscriber(&ACCOUNT2, SPACE1));
+        assert_ok!(_subscribe_to_default_space());
+        assert_eq!(Spaces::subscription_expires_at_by_account(SPACE1, ACCOUNT2), Some(31));
+    });
+}
+
+#[test]
+fn subscribe_to_space_should_fail_when_space_has_no_plan() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(_subscribe_to_default_space(), SpacesError::<Test>::SubscriptionPlanNotFound);
+    });
+}
+
+#[test]
+fn subscribe_to_space_should_fail_when_subscribing_to_own_space() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_subscription_plan());
+        assert_noop!(
+            _subscribe_to_space(None, None, None),
+            SpacesError::<Test>::CannotSubscribeToOwnSpace
+        );
+    });
+}
+
+#[test]
+fn subscribe_to_space_should_fail_when_price_is_higher_than_max_price() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_subscription_plan());
+        assert_noop!(
+            _subscribe_to_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None, Some(49)),
+            SpacesError::<Test>::SubscriptionPriceTooHigh
+        );
+    });
+}
+
+#[test]
+fn delete_space_should_remove_subscription_plan_and_keep_subscriptions() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_subscription_plan());
+        assert_ok!(_subscribe_to_default_space());
+
+        assert_ok!(_delete_default_space());
+
+        assert!(Spaces::subscription_plan_by_space_id(SPACE1).is_none());
+        assert!(Spaces::subscription_expires_at_by_account(SPACE1, ACCOUNT2).is_some());
+    });
+}
+
+#[test]
+fn remove_space_subscriptions_should_work_in_parts() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_subscription_plan());
+        assert_ok!(_subscribe_to_default_space());
+        assert_ok!(_subscribe_to_space(Some(RuntimeOrigin::signed(ACCOUNT3)), None, None));
+        assert_ok!(_delete_default_space());
+
+        assert_ok!(_remove_space_1_subscriptions(1));
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceSubscriptionsPartiallyRemoved {
+                space_id: SPACE1,
+                removed_count: 1,
+            }
+            .into(),
+        );
+
+        assert_ok!(_remove_space_1_subscriptions(10));
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceSubscriptionsRemoved { space_id: SPACE1, removed_count: 1 }
+                .into(),
+        );
+        assert!(Spaces::subscription_expires_at_by_account(SPACE1, ACCOUNT2).is_none());
+        assert!(Spaces::subscription_expires_at_by_account(SPACE1, ACCOUNT3).is_none());
+    });
+}
+
+#[test]
+fn remove_space_subscriptions_should_fail_when_space_is_not_deleted() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(_remove_space_1_subscriptions(10), SpacesError::<Test>::SpaceIsNotDeleted);
+    });
+}
+
 // TODO: refactor or remove. Deprecated tests
 // Find public space ids tests
 // --------------------------------------------------------------------------------------------
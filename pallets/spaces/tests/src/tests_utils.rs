@@ -13,7 +13,7 @@ use pallet_permissions::{
     SpacePermissions,
 };
 use pallet_posts::PostExtension;
-use pallet_spaces::types::SpaceUpdate;
+use pallet_spaces::types::{SpaceUpdate, SubscriptionPlan, SubscriptionPlanOf};
 use subsocial_support::{
     mock_functions::valid_content_ipfs,
     traits::{IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked},
@@ -34,7 +34,7 @@ impl ExtBuilder {
         }
 
         let _ = pallet_balances::GenesisConfig::<Test> {
-            balances: accounts.iter().cloned().map(|k| (k, 100)).collect(),
+            balances: accounts.iter().cloned().map(|k| (k, 1000)).collect(),
         }
         .assimilate_storage(storage);
     }
@@ -128,6 +128,8 @@ impl Hash for EntityId {
                 Content::None => 0.hash(state),
                 Content::Other(content) => content.hash(state),
                 Content::IPFS(content) => content.hash(state),
+                Content::Arweave(content) => content.hash(state),
+                Content::Inline(content) => content.hash(state),
             },
             EntityId::Account(account) => account.hash(state),
             EntityId::Space(space) => space.hash(state),
@@ -238,8 +240,23 @@ pub(crate) fn update_for_space_content(new_content: Content) -> SpaceUpdate {
     space_update(Some(new_content), None)
 }
 
+pub(crate) fn update_for_space_handle(handle: Option<Vec<u8>>) -> SpaceUpdate {
+    SpaceUpdate { handle: Some(handle), ..Default::default() }
+}
+
+pub(crate) fn space_handle1() -> Vec<u8> {
+    b"Space_Handle".to_vec()
+}
+
 pub(crate) fn space_update(content: Option<Content>, hidden: Option<bool>) -> SpaceUpdate {
-    SpaceUpdate { content, hidden, permissions: None }
+    SpaceUpdate { content, handle: None, hidden, permissions: None }
+}
+
+/// The deposit that should be held for a space in its current state.
+pub(crate) fn expected_space_deposit(space_id: SpaceId) -> Balance {
+    let space = Spaces::space_by_id(space_id).unwrap();
+    let history = Spaces::content_history_by_space_id(space_id);
+    (space.encoded_size() + history.encoded_size()) as Balance * SpaceByteDeposit::get()
 }
 
 pub(crate) fn _create_default_space() -> DispatchResult {
@@ -255,6 +272,7 @@ pub(crate) fn _create_space(
         origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
         content.unwrap_or_else(space_content_ipfs),
         permissions.unwrap_or_default(),
+        None,
     )
 }
 
@@ -262,6 +280,18 @@ pub(crate) fn _create_space_with_content(content: Content) -> DispatchResult {
     _create_space(None, Some(content), None)
 }
 
+pub(crate) fn _create_subspace(
+    origin: Option<RuntimeOrigin>,
+    parent_id: Option<SpaceId>,
+) -> DispatchResult {
+    Spaces::create_space(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_content_ipfs(),
+        None,
+        Some(parent_id.unwrap_or(SPACE1)),
+    )
+}
+
 pub(crate) fn _update_space(
     origin: Option<RuntimeOrigin>,
     space_id: Option<SpaceId>,
@@ -274,6 +304,60 @@ pub(crate) fn _update_space(
     )
 }
 
+pub(crate) fn _delete_default_space() -> DispatchResult {
+    _delete_space(None, None)
+}
+
+pub(crate) fn _delete_space(origin: Option<RuntimeOrigin>, space_id: Option<SpaceId>) -> DispatchResult {
+    Spaces::delete_space(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+    )
+}
+
+///////////// Subscription Utils
+
+/// A plan, where a subscription for 10 blocks costs 50.
+pub(crate) fn subscription_plan() -> SubscriptionPlanOf<Test> {
+    SubscriptionPlan { price: 50, period: 10 }
+}
+
+pub(crate) fn _set_default_subscription_plan() -> DispatchResult {
+    _set_subscription_plan(None, None, Some(subscription_plan()))
+}
+
+pub(crate) fn _set_subscription_plan(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    plan_opt: Option<SubscriptionPlanOf<Test>>,
+) -> DispatchResult {
+    Spaces::set_subscription_plan(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        plan_opt,
+    )
+}
+
+pub(crate) fn _subscribe_to_default_space() -> DispatchResult {
+    _subscribe_to_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)
+}
+
+pub(crate) fn _subscribe_to_space(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    max_price: Option<Balance>,
+) -> DispatchResult {
+    Spaces::subscribe_to_space(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        max_price.unwrap_or(subscription_plan().price),
+    )
+}
+
+pub(crate) fn _remove_space_1_subscriptions(limit: u32) -> DispatchResultWithPostInfo {
+    Spaces::remove_space_subscriptions(RuntimeOrigin::signed(ACCOUNT3), SPACE1, limit)
+}
+
 ///////////// Post Utils
 
 pub(crate) fn post_content_ipfs() -> Content {
@@ -299,6 +383,7 @@ pub(crate) fn _create_post(
         space_id_opt.unwrap_or(Some(SPACE1)),
         extension.unwrap_or_else(extension_regular_post),
         content.unwrap_or_else(post_content_ipfs),
+        None,
     )
 }
 
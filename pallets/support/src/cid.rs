@@ -0,0 +1,314 @@
+//! A minimal no_std parser of IPFS content identifiers (CIDs).
+//!
+//! See the [CID specification](https://github.com/multiformats/cid) for the format details.
+
+use frame_support::{ensure, pallet_prelude::RuntimeDebug};
+use sp_std::vec::Vec;
+
+use crate::ContentError;
+
+/// Multicodec of content, referenced by any CIDv0.
+const DAG_PB: u64 = 0x70;
+
+/// Multicodecs of content that can be referenced by a CIDv1.
+const SUPPORTED_MULTICODECS: [u64; 5] = [
+    0x55,   // raw
+    DAG_PB, // dag-pb
+    0x71,   // dag-cbor
+    0x0129, // dag-json
+    0x0200, // json
+];
+
+/// Codes of supported hash functions along with the lengths of their digests in bytes.
+const SUPPORTED_MULTIHASHES: [(u64, usize); 7] = [
+    (0x12, 32),   // sha2-256
+    (0x13, 64),   // sha2-512
+    (0x14, 64),   // sha3-512
+    (0x16, 32),   // sha3-256
+    (0x1b, 32),   // keccak-256
+    (0x1e, 32),   // blake3
+    (0xb220, 32), // blake2b-256
+];
+
+const BASE58_BTC_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_LOWER_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+const BASE32_UPPER_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE16_LOWER_ALPHABET: &[u8] = b"0123456789abcdef";
+const BASE16_UPPER_ALPHABET: &[u8] = b"0123456789ABCDEF";
+const BASE64_URL_ALPHABET: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Length of CIDv0, which is a base58btc-encoded sha2-256 multihash.
+const CID_V0_LEN: usize = 46;
+
+/// Max length of a supported CID: a base16-encoded CIDv1 with a two-byte multicodec
+/// and a 64-byte digest. Longer input is rejected before decoding,
+/// as base58 decoding takes quadratic time.
+const MAX_CID_LEN: usize = 139;
+
+/// Multiformats limit the length of unsigned varints to 9 bytes.
+const MAX_VARINT_LEN: usize = 9;
+
+/// A parsed IPFS CID.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct Cid {
+    pub version: u64,
+    /// Multicodec of the referenced content.
+    pub codec: u64,
+    /// Code of the hash function, which produced the `digest`.
+    pub hash_code: u64,
+    pub digest: Vec<u8>,
+}
+
+/// Parse a string representation of IPFS CID v0 or v1.
+pub fn parse_cid(cid: &[u8]) -> Result<Cid, ContentError> {
+    ensure!(cid.len() <= MAX_CID_LEN, ContentError::InvalidIpfsCid);
+
+    // CIDv0 is a bare base58btc-encoded sha2-256 multihash, that always starts with "Qm".
+    if cid.starts_with(b"Qm") {
+        ensure!(cid.len() == CID_V0_LEN, ContentError::InvalidIpfsCid);
+
+        let bytes = decode_base58_btc(cid)?;
+        let (hash_code, digest) = parse_multihash(&bytes)?;
+        return Ok(Cid { version: 0, codec: DAG_PB, hash_code, digest })
+    }
+
+    let (multibase, encoded) = cid.split_first().ok_or(ContentError::InvalidIpfsCid)?;
+    let bytes = match multibase {
+        b'z' => decode_base58_btc(encoded)?,
+        b'b' => decode_rfc4648(encoded, BASE32_LOWER_ALPHABET, 5)?,
+        b'B' => decode_rfc4648(encoded, BASE32_UPPER_ALPHABET, 5)?,
+        b'f' => decode_rfc4648(encoded, BASE16_LOWER_ALPHABET, 4)?,
+        b'F' => decode_rfc4648(encoded, BASE16_UPPER_ALPHABET, 4)?,
+        b'u' => decode_rfc4648(encoded, BASE64_URL_ALPHABET, 6)?,
+        _ => return Err(ContentError::UnsupportedMultibase),
+    };
+
+    let (version, rest) = read_varint(&bytes)?;
+    ensure!(version == 1, ContentError::UnsupportedCidVersion);
+
+    let (codec, rest) = read_varint(rest)?;
+    ensure!(SUPPORTED_MULTICODECS.contains(&codec), ContentError::UnsupportedMulticodec);
+
+    let (hash_code, digest) = parse_multihash(rest)?;
+    Ok(Cid { version, codec, hash_code, digest })
+}
+
+/// Parse a multihash, ensuring that its digest has the length expected by its hash function.
+fn parse_multihash(bytes: &[u8]) -> Result<(u64, Vec<u8>), ContentError> {
+    let (hash_code, rest) = read_varint(bytes)?;
+    let expected_len = SUPPORTED_MULTIHASHES
+        .iter()
+        .find(|(code, _)| *code == hash_code)
+        .map(|(_, len)| *len)
+        .ok_or(ContentError::UnsupportedMultihash)?;
+
+    let (digest_len, digest) = read_varint(rest)?;
+    ensure!(
+        digest_len == expected_len as u64 && digest.len() == expected_len,
+        ContentError::InvalidMultihashLength
+    );
+
+    Ok((hash_code, digest.to_vec()))
+}
+
+/// Read an unsigned LEB128 varint, returning its value and the remaining bytes.
+fn read_varint(bytes: &[u8]) -> Result<(u64, &[u8]), ContentError> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(MAX_VARINT_LEN) {
+        value |= ((byte & 0x7f) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((value, &bytes[i + 1..]))
+        }
+    }
+
+    Err(ContentError::InvalidIpfsCid)
+}
+
+fn decode_base58_btc(encoded: &[u8]) -> Result<Vec<u8>, ContentError> {
+    // A little-endian big number, which is multiplied by 58 and increased by each next digit.
+    let mut bytes: Vec<u8> = Vec::with_capacity(encoded.len());
+    for c in encoded {
+        let mut carry = BASE58_BTC_ALPHABET
+            .iter()
+            .position(|a| a == c)
+            .ok_or(ContentError::InvalidMultibaseEncoding)? as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    // Each leading '1' stands for a leading zero byte.
+    let leading_zeros = encoded.iter().take_while(|c| **c == b'1').count();
+    bytes.resize(bytes.len() + leading_zeros, 0);
+    bytes.reverse();
+
+    Ok(bytes)
+}
+
+/// Decode an unpadded RFC 4648 string, where each character encodes `bits` bits.
+fn decode_rfc4648(encoded: &[u8], alphabet: &[u8], bits: u32) -> Result<Vec<u8>, ContentError> {
+    let mut bytes = Vec::with_capacity(encoded.len() * bits as usize / 8);
+    let mut buffer = 0u32;
+    let mut buffered_bits = 0u32;
+
+    for c in encoded {
+        let value = alphabet
+            .iter()
+            .position(|a| a == c)
+            .ok_or(ContentError::InvalidMultibaseEncoding)? as u32;
+
+        buffer = (buffer << bits) | value;
+        buffered_bits += bits;
+        if buffered_bits >= 8 {
+            buffered_bits -= 8;
+            bytes.push((buffer >> buffered_bits) as u8);
+            buffer &= (1 << buffered_bits) - 1;
+        }
+    }
+
+    // The remaining bits are padding: there must be less than one character of them,
+    // and all of them must be zero.
+    ensure!(buffered_bits < bits && buffer == 0, ContentError::InvalidMultibaseEncoding);
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(cid: &str) -> Result<Cid, ContentError> {
+        parse_cid(cid.as_bytes())
+    }
+
+    #[test]
+    fn parse_cid_should_work_with_cid_v0() {
+        let cid = parse("QmRAQB6YaCaidP37UdDnjFY5aQuiBrbqdyoW1CaDgwxkD4").unwrap();
+
+        assert_eq!(cid.version, 0);
+        assert_eq!(cid.codec, DAG_PB);
+        assert_eq!(cid.hash_code, 0x12);
+        assert_eq!(cid.digest.len(), 32);
+    }
+
+    #[test]
+    fn parse_cid_should_work_with_cid_v1() {
+        let cid = parse("bafyreib3mgbou4xln42qqcgj6qlt3cif35x4ribisxgq7unhpun525l54e").unwrap();
+
+        assert_eq!(cid.version, 1);
+        assert_eq!(cid.codec, 0x71);
+        assert_eq!(cid.hash_code, 0x12);
+        assert_eq!(cid.digest.len(), 32);
+
+        let cid = parse("bafykbzacebrepi5ogyyzw46cy2pucr3cwpfu37prqb52ihomqoz65fr7mn36k").unwrap();
+        assert_eq!(cid.codec, DAG_PB);
+        assert_eq!(cid.hash_code, 0xb220);
+    }
+
+    #[test]
+    fn parse_cid_should_work_with_all_supported_multibases() {
+        let expected = parse("BAFKREIDCI6R24NRRTNZ4FRU7IFDWFM6LJX67DAD3UQO4ZA5T52LD6Y3X4U").unwrap();
+
+        for cid in [
+            "bafkreidci6r24nrrtnz4fru7ifdwfm6ljx67dad3uqo4za5t52ld6y3x4u",
+            "zb2rhdFvWHxxZWm9N5rBd8q3E7BiWmAZSSKpkMXrKfBWqvmHn",
+            "f015512206247a3ae36319b73c2c69f414762b3cb4dfdf1807ba41dcc83b3ee963f6377e5",
+            "F015512206247A3AE36319B73C2C69F414762B3CB4DFDF1807BA41DCC83B3EE963F6377E5",
+            "uAVUSIGJHo642MZtzwsafQUdis8tN_fGAe6QdzIOz7pY_Y3fl",
+        ] {
+            assert_eq!(parse(cid), Ok(expected.clone()));
+        }
+    }
+
+    #[test]
+    fn parse_cid_should_fail_when_cid_is_malformed() {
+        assert_eq!(parse(""), Err(ContentError::InvalidIpfsCid));
+        assert_eq!(parse("QmRAQB6DaazhR8"), Err(ContentError::InvalidIpfsCid));
+        assert_eq!(parse("b"), Err(ContentError::InvalidIpfsCid));
+    }
+
+    #[test]
+    fn parse_cid_should_work_with_cid_of_max_length() {
+        // CIDv1 of dag-json content with a sha2-512 digest.
+        let cid = ["f01a9021340", &"00".repeat(64)].concat();
+        assert_eq!(cid.len(), MAX_CID_LEN);
+
+        let cid = parse(&cid).unwrap();
+        assert_eq!(cid.codec, 0x0129);
+        assert_eq!(cid.hash_code, 0x13);
+    }
+
+    #[test]
+    fn parse_cid_should_fail_when_cid_is_too_long() {
+        assert_eq!(parse(&"z".repeat(MAX_CID_LEN + 1)), Err(ContentError::InvalidIpfsCid));
+        assert_eq!(parse(&["z", &"1".repeat(100_000)].concat()), Err(ContentError::InvalidIpfsCid));
+    }
+
+    #[test]
+    fn parse_cid_should_fail_when_multibase_is_not_supported() {
+        assert_eq!(
+            parse("mAVUSIGJHo642MZtzwsafQUdis8tN/fGAe6QdzIOz7pY/Y3fl"),
+            Err(ContentError::UnsupportedMultibase)
+        );
+    }
+
+    #[test]
+    fn parse_cid_should_fail_when_multibase_encoding_is_invalid() {
+        assert_eq!(
+            parse("QmRAQB6YaCaidP37UdDnjFY5aQuiBrbqdyoW1CaDgwxk0l"),
+            Err(ContentError::InvalidMultibaseEncoding)
+        );
+        assert_eq!(
+            parse("bafyreib3mgbou4xln42qqcgj6qlt3cif35x4ribisxgq7unhpun525l5018"),
+            Err(ContentError::InvalidMultibaseEncoding)
+        );
+        assert_eq!(
+            parse("f015512206247a3ae36319b73c2c69f414762b3cb4dfdf1807ba41dcc83b3ee963f6377e"),
+            Err(ContentError::InvalidMultibaseEncoding)
+        );
+    }
+
+    #[test]
+    fn parse_cid_should_fail_when_version_is_not_supported() {
+        assert_eq!(
+            parse("bajyreidci6r24nrrtnz4fru7ifdwfm6ljx67dad3uqo4za5t52ld6y3x4u"),
+            Err(ContentError::UnsupportedCidVersion)
+        );
+    }
+
+    #[test]
+    fn parse_cid_should_fail_when_multicodec_is_not_supported() {
+        assert_eq!(
+            parse("bafzbeidci6r24nrrtnz4fru7ifdwfm6ljx67dad3uqo4za5t52ld6y3x4u"),
+            Err(ContentError::UnsupportedMulticodec)
+        );
+    }
+
+    #[test]
+    fn parse_cid_should_fail_when_multihash_is_not_supported() {
+        assert_eq!(
+            parse("bafy5kaiqmjd2hlrwggnxhqwgt5auoyvtzm"),
+            Err(ContentError::UnsupportedMultihash)
+        );
+    }
+
+    #[test]
+    fn parse_cid_should_fail_when_multihash_length_is_invalid() {
+        assert_eq!(
+            parse("bafyrefdci6r24nrrtnz4fru7ifdwfm6ljx67daa"),
+            Err(ContentError::InvalidMultihashLength)
+        );
+        assert_eq!(
+            parse("bafyrelt3cif35x4ribisxgq7unhpun525l54eib3mgbou4xln42qqcgj6q"),
+            Err(ContentError::InvalidMultihashLength)
+        );
+    }
+}
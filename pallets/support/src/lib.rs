@@ -6,14 +6,18 @@
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
-use frame_support::pallet_prelude::*;
-use sp_std::{collections::btree_set::BTreeSet, vec, vec::Vec};
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Zero, traits::ReservableCurrency};
+use sp_std::{cmp::Ordering, collections::btree_set::BTreeSet, vec, vec::Vec};
 
+pub mod cid;
 pub mod traits;
 
 pub type SpaceId = u64;
 pub type PostId = u64;
 
+/// Length of an Arweave transaction id: 32 bytes encoded with base64url without padding.
+pub const ARWEAVE_TX_ID_LEN: usize = 43;
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct WhoAndWhen<AccountId, BlockNumber, Moment> {
     pub account: AccountId,
@@ -40,6 +44,7 @@ where
     }
 }
 
+// New variants must only be appended to keep the SCALE encoding of existing ones unchanged.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub enum Content {
     /// No content.
@@ -48,6 +53,10 @@ pub enum Content {
     Other(Vec<u8>),
     /// IPFS CID v0 of content.
     IPFS(Vec<u8>),
+    /// Arweave transaction id of content.
+    Arweave(Vec<u8>),
+    /// A small UTF-8 content stored on-chain as is.
+    Inline(Vec<u8>),
 }
 
 impl From<Content> for Vec<u8> {
@@ -56,6 +65,8 @@ impl From<Content> for Vec<u8> {
             Content::None => vec![],
             Content::Other(vec_u8) => vec_u8,
             Content::IPFS(vec_u8) => vec_u8,
+            Content::Arweave(vec_u8) => vec_u8,
+            Content::Inline(vec_u8) => vec_u8,
         }
     }
 }
@@ -78,6 +89,14 @@ impl Content {
     pub fn is_ipfs(&self) -> bool {
         matches!(self, Self::IPFS(_))
     }
+
+    pub fn is_arweave(&self) -> bool {
+        matches!(self, Self::Arweave(_))
+    }
+
+    pub fn is_inline(&self) -> bool {
+        matches!(self, Self::Inline(_))
+    }
 }
 
 #[derive(Encode, Decode, Ord, PartialOrd, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -124,6 +143,8 @@ pub enum ModerationError {
     ContentIsBlocked,
     /// Post is blocked in a given space.
     PostIsBlocked,
+    /// Space is blocked in a given space.
+    SpaceIsBlocked,
     /// Space handle is too short.
     HandleIsTooShort,
     /// Space handle is too long.
@@ -138,14 +159,32 @@ impl From<ModerationError> for DispatchError {
     }
 }
 
-#[derive(Encode, Decode, RuntimeDebug, strum::IntoStaticStr)]
+#[derive(Encode, Decode, Eq, PartialEq, RuntimeDebug, strum::IntoStaticStr)]
 pub enum ContentError {
-    /// IPFS CID is invalid.
+    /// IPFS CID is malformed: it's empty, truncated or has an invalid length.
     InvalidIpfsCid,
+    /// IPFS CID is encoded with an unsupported multibase.
+    UnsupportedMultibase,
+    /// IPFS CID contains characters that are invalid for its multibase.
+    InvalidMultibaseEncoding,
+    /// IPFS CID version is not supported.
+    UnsupportedCidVersion,
+    /// IPFS CID references content of an unsupported multicodec.
+    UnsupportedMulticodec,
+    /// IPFS CID is produced by an unsupported hash function.
+    UnsupportedMultihash,
+    /// IPFS CID digest length doesn't match its hash function.
+    InvalidMultihashLength,
     /// `Other` content type is not yet supported.
     OtherContentTypeNotSupported,
     /// Content type is `None`.
     ContentIsEmpty,
+    /// Arweave transaction id is not a string of 43 base64url characters.
+    InvalidArweaveTxId,
+    /// Inline content is empty or is not a valid UTF-8 string.
+    InvalidInlineContent,
+    /// Inline content is longer than allowed.
+    InlineContentIsTooLong,
 }
 
 impl From<ContentError> for DispatchError {
@@ -160,15 +199,36 @@ pub struct SpacePermissionsInfo<AccountId, SpacePermissions> {
     pub permissions: Option<SpacePermissions>,
 }
 
-pub fn ensure_content_is_valid(content: Content) -> DispatchResult {
+/// Ensure that a given content is valid, checking the length of an inline content
+/// against `MaxInlineContentLen`.
+pub fn ensure_content_is_valid<MaxInlineContentLen: Get<u32>>(content: Content) -> DispatchResult {
     match content {
         Content::None => Ok(()),
         Content::Other(_) => Err(ContentError::OtherContentTypeNotSupported.into()),
         Content::IPFS(ipfs_cid) => {
-            let len = ipfs_cid.len();
-            // IPFS CID v0 is 46 bytes.
-            // IPFS CID v1 is 59 bytes.
-            ensure!(len == 46 || len == 59, ContentError::InvalidIpfsCid);
+            cid::parse_cid(&ipfs_cid)?;
+            Ok(())
+        },
+        Content::Arweave(tx_id) => {
+            // Arweave transaction id is a base64url-encoded string without padding.
+            let is_base64url = |c: &u8| c.is_ascii_alphanumeric() || *c == b'-' || *c == b'_';
+
+            ensure!(
+                tx_id.len() == ARWEAVE_TX_ID_LEN && tx_id.iter().all(is_base64url),
+                ContentError::InvalidArweaveTxId
+            );
+            Ok(())
+        },
+        Content::Inline(text) => {
+            // Check the length first, so that a long text is not scanned as UTF-8.
+            ensure!(
+                text.len() <= MaxInlineContentLen::get() as usize,
+                ContentError::InlineContentIsTooLong
+            );
+            ensure!(
+                !text.is_empty() && core::str::from_utf8(&text).is_ok(),
+                ContentError::InvalidInlineContent
+            );
             Ok(())
         },
     }
@@ -180,6 +240,60 @@ pub fn ensure_content_is_some(content: &Content) -> DispatchResult {
     Ok(())
 }
 
+/// An amount held on deposit for storing an entity, along with the account it was reserved from.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct StorageDeposit<AccountId, Balance> {
+    pub depositor: AccountId,
+    pub amount: Balance,
+}
+
+/// Reserve a deposit of `new_amount` from `depositor`, taking into account the deposit,
+/// which is already held for the same entity.
+///
+/// If the depositor is the same, only the difference is reserved or unreserved.
+/// Otherwise the previous depositor gets the whole deposit back.
+pub fn update_storage_deposit<AccountId, Currency>(
+    old_deposit_opt: Option<StorageDeposit<AccountId, Currency::Balance>>,
+    depositor: AccountId,
+    new_amount: Currency::Balance,
+) -> Result<StorageDeposit<AccountId, Currency::Balance>, DispatchError>
+where
+    AccountId: PartialEq,
+    Currency: ReservableCurrency<AccountId>,
+{
+    match old_deposit_opt {
+        Some(old_deposit) if old_deposit.depositor == depositor => {
+            match old_deposit.amount.cmp(&new_amount) {
+                Ordering::Less => Currency::reserve(&depositor, new_amount - old_deposit.amount)?,
+                Ordering::Greater => {
+                    let err_amount =
+                        Currency::unreserve(&depositor, old_deposit.amount - new_amount);
+                    debug_assert!(err_amount.is_zero());
+                },
+                Ordering::Equal => (),
+            }
+        },
+        old_deposit_opt => {
+            if let Some(old_deposit) = old_deposit_opt {
+                refund_storage_deposit::<AccountId, Currency>(old_deposit);
+            }
+            Currency::reserve(&depositor, new_amount)?;
+        },
+    }
+
+    Ok(StorageDeposit { depositor, amount: new_amount })
+}
+
+/// Return a deposit, held for storing an entity, back to its depositor.
+pub fn refund_storage_deposit<AccountId, Currency>(
+    deposit: StorageDeposit<AccountId, Currency::Balance>,
+) where
+    Currency: ReservableCurrency<AccountId>,
+{
+    let err_amount = Currency::unreserve(&deposit.depositor, deposit.amount);
+    debug_assert!(err_amount.is_zero());
+}
+
 pub fn remove_from_vec<F: PartialEq>(vector: &mut Vec<F>, element: F) {
     if let Some(index) = vector.iter().position(|x| *x == element) {
         vector.swap_remove(index);
@@ -192,6 +306,19 @@ pub fn remove_from_bounded_vec<F: PartialEq, S>(vector: &mut BoundedVec<F, S>, e
     }
 }
 
+/// Push an element to the end of a bounded vector. If the vector is full,
+/// its first (the oldest) element is removed to make room for the new one.
+pub fn push_evicting_oldest<F, S: Get<u32>>(vector: &mut BoundedVec<F, S>, element: F) {
+    if S::get() == 0 {
+        return
+    }
+
+    if vector.len() >= S::get() as usize {
+        vector.remove(0);
+    }
+    let _ = vector.try_push(element);
+}
+
 pub fn bool_to_option(value: bool) -> Option<bool> {
     if value {
         Some(value)
@@ -215,11 +342,82 @@ pub mod mock_functions {
     pub fn invalid_content_ipfs() -> Content {
         Content::IPFS(b"QmRAQB6DaazhR8".to_vec())
     }
+
+    pub fn valid_content_arweave() -> Content {
+        Content::Arweave(b"bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U".to_vec())
+    }
+
+    pub fn valid_content_inline() -> Content {
+        Content::Inline("Hello, Subsocial! 👋".as_bytes().to_vec())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::remove_from_vec;
+    use frame_support::{traits::ConstU32, BoundedVec};
+
+    use super::{
+        ensure_content_is_valid, mock_functions::*, push_evicting_oldest, remove_from_vec, Content,
+        ContentError,
+    };
+
+    #[test]
+    fn ensure_content_is_valid_should_work_with_arweave_tx_id() {
+        assert!(ensure_content_is_valid::<ConstU32<512>>(valid_content_arweave()).is_ok());
+    }
+
+    #[test]
+    fn ensure_content_is_valid_should_fail_with_invalid_arweave_tx_id() {
+        assert_eq!(
+            ensure_content_is_valid::<ConstU32<512>>(Content::Arweave(b"not/base64url+".to_vec())),
+            Err(ContentError::InvalidArweaveTxId.into())
+        );
+        assert_eq!(
+            ensure_content_is_valid::<ConstU32<512>>(Content::Arweave(vec![])),
+            Err(ContentError::InvalidArweaveTxId.into())
+        );
+        assert_eq!(
+            ensure_content_is_valid::<ConstU32<512>>(Content::Arweave(vec![b'a'; 42])),
+            Err(ContentError::InvalidArweaveTxId.into())
+        );
+        assert_eq!(
+            ensure_content_is_valid::<ConstU32<512>>(Content::Arweave(vec![b'a'; 44])),
+            Err(ContentError::InvalidArweaveTxId.into())
+        );
+    }
+
+    #[test]
+    fn ensure_content_is_valid_should_work_with_inline_content() {
+        assert!(ensure_content_is_valid::<ConstU32<512>>(valid_content_inline()).is_ok());
+    }
+
+    #[test]
+    fn ensure_content_is_valid_should_fail_with_invalid_inline_content() {
+        assert_eq!(
+            ensure_content_is_valid::<ConstU32<512>>(Content::Inline(vec![0xff, 0xfe])),
+            Err(ContentError::InvalidInlineContent.into())
+        );
+        assert_eq!(
+            ensure_content_is_valid::<ConstU32<512>>(Content::Inline(vec![])),
+            Err(ContentError::InvalidInlineContent.into())
+        );
+        assert_eq!(
+            ensure_content_is_valid::<ConstU32<512>>(Content::Inline(vec![b'a'; 513])),
+            Err(ContentError::InlineContentIsTooLong.into())
+        );
+        assert_eq!(
+            ensure_content_is_valid::<ConstU32<512>>(Content::Inline(vec![0xff; 513])),
+            Err(ContentError::InlineContentIsTooLong.into())
+        );
+    }
+
+    #[test]
+    fn content_ipfs_encoding_should_not_change() {
+        use codec::Encode;
+
+        // `Content::IPFS` must keep its variant index, so already stored values decode as before.
+        assert_eq!(Content::IPFS(vec![1]).encode(), vec![2, 4, 1]);
+    }
 
     #[test]
     fn remove_from_vec_should_work_with_zero_elements() {
@@ -253,4 +451,16 @@ mod tests {
         remove_from_vec(vector, element);
         assert_eq!(vector, &mut vec![7]);
     }
+
+    #[test]
+    fn push_evicting_oldest_should_remove_first_element_when_full() {
+        let mut vector = BoundedVec::<u16, ConstU32<2>>::default();
+
+        push_evicting_oldest(&mut vector, 1);
+        push_evicting_oldest(&mut vector, 2);
+        assert_eq!(vector.to_vec(), vec![1, 2]);
+
+        push_evicting_oldest(&mut vector, 3);
+        assert_eq!(vector.to_vec(), vec![2, 3]);
+    }
 }
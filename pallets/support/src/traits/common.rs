@@ -26,6 +26,38 @@ pub trait ProfileManager<AccountId> {
     fn unlink_space_from_profile(account: &AccountId, space_id: SpaceId);
 }
 
+impl<AccountId> ProfileManager<AccountId> for () {
+    fn unlink_space_from_profile(_account: &AccountId, _space_id: SpaceId) {}
+}
+
+/// Called before a space is removed from the storage,
+/// so that other pallets can clean up or reject the deletion.
+pub trait BeforeSpaceDeleted {
+    fn before_space_deleted(space_id: SpaceId) -> DispatchResult;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl BeforeSpaceDeleted for Tuple {
+    fn before_space_deleted(space_id: SpaceId) -> DispatchResult {
+        for_tuples!( #( Tuple::before_space_deleted(space_id)?; )* );
+        Ok(())
+    }
+}
+
+/// Called before a post is deleted or replaced with a tombstone,
+/// so that other pallets can clean up the data related to this post.
+pub trait BeforePostDeleted {
+    fn before_post_deleted(post_id: PostId) -> DispatchResult;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl BeforePostDeleted for Tuple {
+    fn before_post_deleted(post_id: PostId) -> DispatchResult {
+        for_tuples!( #( Tuple::before_post_deleted(post_id)?; )* );
+        Ok(())
+    }
+}
+
 pub trait SpacesInterface<AccountId, SpaceId> {
     fn get_space_owner(space_id: SpaceId) -> Result<AccountId, DispatchError>;
 
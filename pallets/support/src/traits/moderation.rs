@@ -20,6 +20,16 @@ pub trait IsSpaceBlocked {
     fn is_allowed_space(space_id: SpaceId, scope: SpaceId) -> bool;
 }
 
+impl IsSpaceBlocked for () {
+    fn is_blocked_space(_space_id: SpaceId, _scope: SpaceId) -> bool {
+        false
+    }
+
+    fn is_allowed_space(_space_id: SpaceId, _scope: SpaceId) -> bool {
+        true
+    }
+}
+
 // TODO: reuse `type PostId` from pallet_utils in future updates
 pub trait IsPostBlocked<PostId> {
     fn is_blocked_post(post_id: PostId, scope: SpaceId) -> bool;
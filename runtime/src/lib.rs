@@ -110,8 +110,16 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations that should be applied on the next runtime upgrade.
+pub type Migrations = (
+	pallet_spaces::migration::v4::MigrateToV4<Runtime>,
+	pallet_posts::migration::v6::MigrateToV6<Runtime>,
+	pallet_reactions::migration::v1::MigrateToV1<Runtime>,
+);
+
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
 /// node's balance type.
 ///
@@ -604,6 +612,10 @@ impl pallet_utility::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxInlineContentLen: u32 = 512;
+}
+
 parameter_types! {
     pub const MinDomainLength: u32 = 6;
     pub const MaxDomainLength: u32 = 63;
@@ -634,6 +646,7 @@ impl pallet_domains::Config for Runtime {
 	type MaxOuterValueLength = MaxOuterValueLength;
 	type BaseDomainDeposit = BaseDomainDeposit;
 	type OuterValueByteDeposit = OuterValueByteDeposit;
+	type MaxInlineContentLen = MaxInlineContentLen;
 	type WeightInfo = pallet_domains::weights::SubstrateWeight<Runtime>;
 }
 
@@ -645,17 +658,35 @@ impl pallet_permissions::Config for Runtime {
 
 parameter_types! {
   pub const MaxCommentDepth: u32 = 10;
+  pub const MaxPostContentHistory: u32 = 10;
+  pub const MaxPinnedPostsPerSpace: u32 = 10;
+  pub const MaxScheduledPostsPerBlock: u32 = 100;
+  pub const MaxPollOptions: u32 = 10;
+  pub const PostByteDeposit: Balance = deposit(0, 1);
 }
 
 impl pallet_posts::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxCommentDepth = MaxCommentDepth;
-	type IsPostBlocked = ()/*Moderation*/;
+	type IsPostBlocked = Moderation;
+	type BeforePostDeleted = Reactions;
+	type MaxPostContentHistory = MaxPostContentHistory;
+	type MaxPinnedPostsPerSpace = MaxPinnedPostsPerSpace;
+	type MaxScheduledPostsPerBlock = MaxScheduledPostsPerBlock;
+	type MaxPollOptions = MaxPollOptions;
+	type PostByteDeposit = PostByteDeposit;
 	type WeightInfo = pallet_posts::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const ReactionByteDeposit: Balance = deposit(0, 1);
+	pub const MaxCustomReactionKinds: u32 = 10;
+}
+
 impl pallet_reactions::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type ReactionByteDeposit = ReactionByteDeposit;
+	type MaxCustomReactionKinds = MaxCustomReactionKinds;
 	type WeightInfo = pallet_reactions::weights::SubstrateWeight<Runtime>;
 }
 
@@ -668,6 +699,7 @@ impl pallet_profiles::Config for Runtime {
 
 parameter_types! {
   pub const MaxUsersToProcessPerDeleteRole: u16 = 40;
+  pub const RoleByteDeposit: Balance = deposit(0, 1);
 }
 
 impl pallet_roles::Config for Runtime {
@@ -675,8 +707,11 @@ impl pallet_roles::Config for Runtime {
 	type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
 	type SpacePermissionsProvider = Spaces;
 	type SpaceFollows = SpaceFollows;
-	type IsAccountBlocked = ()/*Moderation*/;
-	type IsContentBlocked = ()/*Moderation*/;
+	type IsAccountBlocked = Moderation;
+	type IsContentBlocked = Moderation;
+	type MaxInlineContentLen = MaxInlineContentLen;
+	type Currency = Balances;
+	type RoleByteDeposit = RoleByteDeposit;
 	type WeightInfo = pallet_roles::weights::SubstrateWeight<Runtime>;
 }
 
@@ -687,21 +722,35 @@ impl pallet_space_follows::Config for Runtime {
 
 parameter_types! {
 	pub const MaxSpacesPerAccount: u32 = 4096;
+	pub const MaxSubspaceDepth: u32 = 5;
+	pub const MinHandleLen: u32 = 5;
+	pub const MaxHandleLen: u32 = 50;
+	pub const MaxSpaceContentHistory: u32 = 10;
+	pub const SpaceByteDeposit: Balance = deposit(0, 1);
 }
 
 impl pallet_spaces::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Roles = Roles;
 	type SpaceFollows = SpaceFollows;
-	type IsAccountBlocked = ()/*Moderation*/;
-	type IsContentBlocked = ()/*Moderation*/;
+	type IsAccountBlocked = Moderation;
+	type IsContentBlocked = Moderation;
+	type IsSpaceBlocked = Moderation;
+	type MaxInlineContentLen = MaxInlineContentLen;
+	type ProfileManager = Profiles;
+	type BeforeSpaceDeleted = (Posts, SpaceFollows, SpaceOwnership, Reactions, Roles);
 	type MaxSpacesPerAccount = MaxSpacesPerAccount;
+	type MaxSubspaceDepth = MaxSubspaceDepth;
+	type MinHandleLen = MinHandleLen;
+	type MaxHandleLen = MaxHandleLen;
+	type MaxSpaceContentHistory = MaxSpaceContentHistory;
+	type Currency = Balances;
+	type SpaceByteDeposit = SpaceByteDeposit;
 	type WeightInfo = pallet_spaces::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_space_ownership::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type ProfileManager = Profiles;
 	type WeightInfo = pallet_space_ownership::weights::SubstrateWeight<Runtime>;
 }
 
@@ -709,6 +758,20 @@ impl pallet_account_follows::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+parameter_types! {
+	pub const MaxReportsPerAccount: u32 = 100;
+	pub const MaxSuggestedStatusesPerEntity: u32 = 20;
+	pub const ReportByteDeposit: Balance = deposit(0, 1);
+}
+
+impl pallet_moderation::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxReportsPerAccount = MaxReportsPerAccount;
+	type MaxSuggestedStatusesPerEntity = MaxSuggestedStatusesPerEntity;
+	type ReportByteDeposit = ReportByteDeposit;
+	type WeightInfo = pallet_moderation::weights::SubstrateWeight<Runtime>;
+}
+
 
 parameter_types! {
 	pub DefaultValueCoefficient: FixedI64 = FixedI64::checked_from_rational(1_25, 100).unwrap();
@@ -776,6 +839,7 @@ construct_runtime!(
 		Spaces: pallet_spaces = 76,
 		Posts: pallet_posts = 77,
 		Reactions: pallet_reactions = 78,
+		Moderation: pallet_moderation = 79,
 
 		// Temporary
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 255,
@@ -808,6 +872,7 @@ mod benches {
 		[pallet_spaces, Spaces]
 		[pallet_posts, Posts]
 		[pallet_free_proxy, FreeProxy]
+		[pallet_moderation, Moderation]
 	);
 }
 
@@ -929,6 +994,26 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl reactions_runtime_api::ReactionsApi<Block, AccountId> for Runtime {
+		fn get_reactions_by_account(
+			account: AccountId,
+			start_after: Option<pallet_reactions::ReactionId>,
+			limit: u32,
+		) -> Vec<(pallet_reactions::ReactionId, pallet_reactions::ReactionTarget, pallet_reactions::ReactionKind)> {
+			Reactions::get_reactions_by_account(account, start_after, limit)
+		}
+	}
+
+	impl spaces_runtime_api::SpacesApi<Block, pallet_spaces::types::Space<Runtime>> for Runtime {
+		fn get_space_id_by_handle(handle: Vec<u8>) -> Option<spaces_runtime_api::SpaceId> {
+			Spaces::find_space_id_by_handle(handle)
+		}
+
+		fn get_space_by_handle(handle: Vec<u8>) -> Option<pallet_spaces::types::Space<Runtime>> {
+			Spaces::find_space_id_by_handle(handle).and_then(Spaces::space_by_id)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)